@@ -22,6 +22,20 @@ mod build_tesseract {
     const TESSERACT_URL: &str =
         "https://github.com/tesseract-ocr/tesseract/archive/refs/tags/5.5.2.zip";
 
+    // Image codecs for Leptonica, only fetched with the `image-codecs` feature.
+    #[cfg(feature = "image-codecs")]
+    const ZLIB_URL: &str = "https://github.com/madler/zlib/archive/refs/tags/v1.3.1.zip";
+    #[cfg(feature = "image-codecs")]
+    const LIBPNG_URL: &str = "https://github.com/pnggroup/libpng/archive/refs/tags/v1.6.44.zip";
+    #[cfg(feature = "image-codecs")]
+    const LIBJPEG_URL: &str =
+        "https://github.com/libjpeg-turbo/libjpeg-turbo/archive/refs/tags/3.0.4.zip";
+    #[cfg(feature = "image-codecs")]
+    const LIBTIFF_URL: &str =
+        "https://gitlab.com/libtiff/libtiff/-/archive/v4.7.0/libtiff-v4.7.0.zip";
+    #[cfg(feature = "image-codecs")]
+    const LIBWEBP_URL: &str = "https://github.com/webmproject/libwebp/archive/refs/tags/v1.4.0.zip";
+
     pub fn get_custom_out_dir() -> PathBuf {
         if cfg!(target_os = "macos") {
             let home_dir = env::var("HOME").unwrap_or_else(|_| {
@@ -94,8 +108,19 @@ mod build_tesseract {
 
         let (cmake_cxx_flags, additional_defines) = get_os_specific_config();
 
+        #[cfg(feature = "image-codecs")]
+        let codecs_install_dir = build_codecs(&third_party_dir, &cache_dir, &out_dir);
+
         let leptonica_install_dir = out_dir.join("leptonica");
-        let leptonica_cache_dir = cache_dir.join("leptonica");
+        // Leptonica built with codecs links against different libraries, so it
+        // must not be served from the cache of a codec-less build (and vice versa).
+        // The "-warn" suffix keeps builds that compiled messages out from
+        // being reused.
+        let leptonica_cache_dir = if cfg!(feature = "image-codecs") {
+            cache_dir.join("leptonica-codecs-warn")
+        } else {
+            cache_dir.join("leptonica-warn")
+        };
 
         build_or_use_cached(
             "leptonica",
@@ -109,23 +134,27 @@ mod build_tesseract {
 
                 // Only modify environ.h if it exists
                 if environ_h_path.exists() {
-                    let environ_h = std::fs::read_to_string(&environ_h_path)
+                    // Older builds patched console output away; a reused
+                    // source tree may still carry that.
+                    let mut environ_h = std::fs::read_to_string(&environ_h_path)
                         .expect("Failed to read environ.h")
                         .replace(
+                            "#define NO_CONSOLE_IO\n#ifdef  NO_CONSOLE_IO",
+                            "#ifdef  NO_CONSOLE_IO",
+                        );
+                    if !cfg!(feature = "image-codecs") {
+                        environ_h = environ_h.replace(
                             "#define  HAVE_LIBZ          1",
                             "#define  HAVE_LIBZ          0",
-                        )
-                        .replace(
-                            "#ifdef  NO_CONSOLE_IO",
-                            "#define NO_CONSOLE_IO\n#ifdef  NO_CONSOLE_IO",
                         );
+                    }
                     std::fs::write(environ_h_path, environ_h).expect("Failed to write environ.h");
                 }
 
                 let makefile_static_path = leptonica_dir.join("prog").join("makefile.static");
 
                 // Only modify makefile.static if it exists
-                if makefile_static_path.exists() {
+                if makefile_static_path.exists() && !cfg!(feature = "image-codecs") {
                     let makefile_static = std::fs::read_to_string(&makefile_static_path)
                         .expect("Failed to read makefile.static")
                         .replace(
@@ -144,6 +173,12 @@ mod build_tesseract {
                     }
                 }
 
+                let codecs = if cfg!(feature = "image-codecs") {
+                    "ON"
+                } else {
+                    "OFF"
+                };
+
                 // Only use sccache if not in CI
                 if env::var("CI").is_err()
                     && env::var("RUSTC_WRAPPER").unwrap_or_default() == "sccache"
@@ -157,18 +192,29 @@ mod build_tesseract {
                     .define("CMAKE_BUILD_TYPE", "Release")
                     .define("BUILD_PROG", "OFF")
                     .define("BUILD_SHARED_LIBS", "OFF")
-                    .define("ENABLE_ZLIB", "OFF")
-                    .define("ENABLE_PNG", "OFF")
-                    .define("ENABLE_JPEG", "OFF")
-                    .define("ENABLE_TIFF", "OFF")
-                    .define("ENABLE_WEBP", "OFF")
+                    .define("ENABLE_ZLIB", codecs)
+                    .define("ENABLE_PNG", codecs)
+                    .define("ENABLE_JPEG", codecs)
+                    .define("ENABLE_TIFF", codecs)
+                    .define("ENABLE_WEBP", codecs)
                     .define("ENABLE_OPENJPEG", "OFF")
                     .define("ENABLE_GIF", "OFF")
-                    .define("NO_CONSOLE_IO", "ON")
+                    .define("NO_CONSOLE_IO", "OFF")
                     .define("CMAKE_CXX_FLAGS", &cmake_cxx_flags)
-                    .define("MINIMUM_SEVERITY", "L_SEVERITY_NONE")
+                    // Keep warnings and errors, which reach the diagnostic
+                    // handler once one is installed, but print nothing until
+                    // then.
+                    .cflag("-DMINIMUM_SEVERITY=L_SEVERITY_WARNING")
+                    .cflag("-DDEFAULT_SEVERITY=L_SEVERITY_NONE")
                     .define("SW_BUILD", "OFF")
-                    .define("HAVE_LIBZ", "0")
+                    .define(
+                        "HAVE_LIBZ",
+                        if cfg!(feature = "image-codecs") {
+                            "1"
+                        } else {
+                            "0"
+                        },
+                    )
                     .define("ENABLE_LTO", "OFF")
                     .define("CMAKE_INSTALL_PREFIX", &leptonica_install_dir);
 
@@ -179,6 +225,11 @@ mod build_tesseract {
                         .define("CMAKE_C_FLAGS_DEBUG", "/MDd /Od");
                 }
 
+                #[cfg(feature = "image-codecs")]
+                leptonica_config
+                    .define("ZLIB_USE_STATIC_LIBS", "ON")
+                    .define("CMAKE_PREFIX_PATH", &codecs_install_dir);
+
                 for (key, value) in &additional_defines {
                     leptonica_config.define(key, value);
                 }
@@ -190,7 +241,13 @@ mod build_tesseract {
         let leptonica_include_dir = leptonica_install_dir.join("include");
         let leptonica_lib_dir = leptonica_install_dir.join("lib");
         let tesseract_install_dir = out_dir.join("tesseract");
-        let tesseract_cache_dir = cache_dir.join("tesseract");
+        // Tesseract reads TIFF input itself when libtiff is available, so a
+        // build with codecs must not share the cache of a build without them.
+        let tesseract_cache_dir = if cfg!(feature = "image-codecs") {
+            cache_dir.join("tesseract-codecs")
+        } else {
+            cache_dir.join("tesseract")
+        };
         let tessdata_prefix = project_dir.join("tessdata");
 
         build_or_use_cached(
@@ -198,12 +255,22 @@ mod build_tesseract {
             &tesseract_cache_dir,
             &tesseract_install_dir,
             || {
-                let cmakelists_path = tesseract_dir.join("CMakeLists.txt");
-                let cmakelists = std::fs::read_to_string(&cmakelists_path)
-                    .expect("Failed to read CMakeLists.txt")
-                    .replace("set(HAVE_TIFFIO_H ON)", "");
-                std::fs::write(&cmakelists_path, cmakelists)
-                    .expect("Failed to write CMakeLists.txt");
+                // Without codecs there is no libtiff to find, so keep Tesseract
+                // from assuming one. With codecs the line is left as is.
+                if !cfg!(feature = "image-codecs") {
+                    let cmakelists_path = tesseract_dir.join("CMakeLists.txt");
+                    let cmakelists = std::fs::read_to_string(&cmakelists_path)
+                        .expect("Failed to read CMakeLists.txt")
+                        .replace("set(HAVE_TIFFIO_H ON)", "");
+                    std::fs::write(&cmakelists_path, cmakelists)
+                        .expect("Failed to write CMakeLists.txt");
+                }
+
+                let (disable_codecs, have_codecs) = if cfg!(feature = "image-codecs") {
+                    ("OFF", "ON")
+                } else {
+                    ("ON", "OFF")
+                };
 
                 let mut tesseract_config = Config::new(&tesseract_dir);
                 // Configure build tools
@@ -233,15 +300,14 @@ mod build_tesseract {
                     .define("Leptonica_DIR", &leptonica_install_dir)
                     .define("LEPTONICA_INCLUDE_DIR", &leptonica_include_dir)
                     .define("LEPTONICA_LIBRARY", &leptonica_lib_dir)
-                    .define("CMAKE_PREFIX_PATH", &leptonica_install_dir)
                     .define("CMAKE_INSTALL_PREFIX", &tesseract_install_dir)
                     .define("TESSDATA_PREFIX", &tessdata_prefix)
-                    .define("DISABLE_TIFF", "ON")
-                    .define("DISABLE_PNG", "ON")
-                    .define("DISABLE_JPEG", "ON")
-                    .define("DISABLE_WEBP", "ON")
+                    .define("DISABLE_TIFF", disable_codecs)
+                    .define("DISABLE_PNG", disable_codecs)
+                    .define("DISABLE_JPEG", disable_codecs)
+                    .define("DISABLE_WEBP", disable_codecs)
                     .define("DISABLE_OPENJPEG", "ON")
-                    .define("DISABLE_ZLIB", "ON")
+                    .define("DISABLE_ZLIB", disable_codecs)
                     .define("DISABLE_LIBXML2", "ON")
                     .define("DISABLE_LIBICU", "ON")
                     .define("DISABLE_LZMA", "ON")
@@ -250,7 +316,7 @@ mod build_tesseract {
                     .define("debug_file", "/dev/null")
                     .define("HAVE_LIBARCHIVE", "OFF")
                     .define("HAVE_LIBCURL", "OFF")
-                    .define("HAVE_TIFFIO_H", "OFF")
+                    .define("HAVE_TIFFIO_H", have_codecs)
                     .define("GRAPHICS_DISABLED", "ON")
                     .define("DISABLED_LEGACY_ENGINE", "OFF")
                     .define("USE_OPENCL", "OFF")
@@ -259,11 +325,26 @@ mod build_tesseract {
                     .define("ENABLE_LTO", "OFF")
                     .define("BUILD_PROG", "OFF")
                     .define("SW_BUILD", "OFF")
-                    .define("LEPT_TIFF_RESULT", "FALSE")
                     .define("INSTALL_CONFIGS", "ON")
                     .define("USE_SYSTEM_ICU", "ON")
                     .define("CMAKE_CXX_FLAGS", &cmake_cxx_flags);
 
+                // With codecs, libtiff is found next to the other codecs and
+                // Leptonica's TIFF support is probed rather than assumed.
+                #[cfg(feature = "image-codecs")]
+                tesseract_config.define(
+                    "CMAKE_PREFIX_PATH",
+                    format!(
+                        "{};{}",
+                        leptonica_install_dir.display(),
+                        codecs_install_dir.display()
+                    ),
+                );
+                #[cfg(not(feature = "image-codecs"))]
+                tesseract_config
+                    .define("CMAKE_PREFIX_PATH", &leptonica_install_dir)
+                    .define("LEPT_TIFF_RESULT", "FALSE");
+
                 for (key, value) in &additional_defines {
                     tesseract_config.define(key, value);
                 }
@@ -303,6 +384,186 @@ mod build_tesseract {
         download_tessdata(&project_dir);
     }
 
+    /// Builds static zlib, libpng, libjpeg-turbo, libtiff and libwebp into a
+    /// shared prefix that Leptonica's CMake configuration picks up.
+    ///
+    /// Libraries are built in dependency order so that each one finds the
+    /// codecs it depends on in the prefix.
+    #[cfg(feature = "image-codecs")]
+    fn build_codecs(third_party_dir: &Path, cache_dir: &Path, out_dir: &Path) -> PathBuf {
+        let install_dir = out_dir.join("codecs");
+        // Leptonica's configuration needs the codecs' headers and CMake files,
+        // not just their archives, so the whole prefix is cached as well.
+        let prefix_cache_dir = cache_dir.join("codecs");
+        let restored = restore_prefix(&prefix_cache_dir, &install_dir);
+        if !restored {
+            // Archives cached without their prefix would skip the builds that
+            // install the headers, so rebuild everything.
+            for name in ["zlib", "libpng", "libjpeg", "libtiff", "libwebp"] {
+                let _ = fs::remove_dir_all(cache_dir.join(name));
+            }
+        }
+
+        let fetch = |name: &str, url: &str| {
+            let dir = third_party_dir.join(name);
+            if dir.exists() {
+                println!("cargo:warning=Using existing {} source", name);
+                dir
+            } else {
+                download_and_extract(third_party_dir, url, name)
+            }
+        };
+
+        let zlib_dir = fetch("zlib", ZLIB_URL);
+        build_or_use_cached("z", &cache_dir.join("zlib"), &install_dir, || {
+            codec_config(&zlib_dir, &install_dir)
+                .define("ZLIB_BUILD_EXAMPLES", "OFF")
+                .build();
+        });
+
+        let libpng_dir = fetch("libpng", LIBPNG_URL);
+        build_or_use_cached("png16", &cache_dir.join("libpng"), &install_dir, || {
+            codec_config(&libpng_dir, &install_dir)
+                .define("PNG_SHARED", "OFF")
+                .define("PNG_STATIC", "ON")
+                .define("PNG_TESTS", "OFF")
+                .define("PNG_TOOLS", "OFF")
+                .define("ZLIB_ROOT", &install_dir)
+                .build();
+        });
+
+        let libjpeg_dir = fetch("libjpeg-turbo", LIBJPEG_URL);
+        build_or_use_cached("jpeg", &cache_dir.join("libjpeg"), &install_dir, || {
+            codec_config(&libjpeg_dir, &install_dir)
+                .define("ENABLE_SHARED", "OFF")
+                .define("ENABLE_STATIC", "ON")
+                .define("WITH_TURBOJPEG", "OFF")
+                // The SIMD code needs NASM, which is not a given on build hosts.
+                .define("WITH_SIMD", "OFF")
+                .build();
+        });
+
+        let libtiff_dir = fetch("libtiff", LIBTIFF_URL);
+        build_or_use_cached("tiff", &cache_dir.join("libtiff"), &install_dir, || {
+            codec_config(&libtiff_dir, &install_dir)
+                .define("tiff-tools", "OFF")
+                .define("tiff-tests", "OFF")
+                .define("tiff-contrib", "OFF")
+                .define("tiff-docs", "OFF")
+                .define("jpeg", "ON")
+                .define("zlib", "ON")
+                .define("jbig", "OFF")
+                .define("lerc", "OFF")
+                .define("libdeflate", "OFF")
+                .define("lzma", "OFF")
+                .define("webp", "OFF")
+                .define("zstd", "OFF")
+                .build();
+        });
+
+        let libwebp_dir = fetch("libwebp", LIBWEBP_URL);
+        let build_webp = || {
+            codec_config(&libwebp_dir, &install_dir)
+                .define("WEBP_BUILD_ANIM_UTILS", "OFF")
+                .define("WEBP_BUILD_CWEBP", "OFF")
+                .define("WEBP_BUILD_DWEBP", "OFF")
+                .define("WEBP_BUILD_GIF2WEBP", "OFF")
+                .define("WEBP_BUILD_IMG2WEBP", "OFF")
+                .define("WEBP_BUILD_VWEBP", "OFF")
+                .define("WEBP_BUILD_WEBPINFO", "OFF")
+                .define("WEBP_BUILD_WEBPMUX", "OFF")
+                .define("WEBP_BUILD_EXTRAS", "OFF")
+                .define("WEBP_BUILD_LIBWEBPMUX", "ON")
+                .build();
+        };
+        // libwebp is one CMake project producing several libraries; build it
+        // once and let the remaining calls pick up the installed archives.
+        build_or_use_cached("webp", &cache_dir.join("libwebp"), &install_dir, build_webp);
+        build_or_use_cached("webpmux", &cache_dir.join("libwebp"), &install_dir, || {});
+        build_or_use_cached("sharpyuv", &cache_dir.join("libwebp"), &install_dir, || {});
+
+        if !restored {
+            save_prefix(&install_dir, &prefix_cache_dir);
+        }
+
+        install_dir
+    }
+
+    /// Name of the file marking a cached codec prefix as complete.
+    #[cfg(feature = "image-codecs")]
+    const PREFIX_COMPLETE: &str = ".complete";
+
+    /// Copies a cached codec prefix into `install_dir`.
+    ///
+    /// Returns whether a complete cached prefix was found and copied.
+    #[cfg(feature = "image-codecs")]
+    fn restore_prefix(prefix_cache_dir: &Path, install_dir: &Path) -> bool {
+        if !prefix_cache_dir.join(PREFIX_COMPLETE).exists() {
+            return false;
+        }
+        match copy_dir_all(prefix_cache_dir, install_dir) {
+            Ok(()) => {
+                println!("Using cached image codecs");
+                true
+            }
+            Err(e) => {
+                println!("cargo:warning=Failed to copy cached image codecs: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Copies an installed codec prefix into the cache and marks it complete.
+    #[cfg(feature = "image-codecs")]
+    fn save_prefix(install_dir: &Path, prefix_cache_dir: &Path) {
+        let _ = fs::remove_dir_all(prefix_cache_dir);
+        let saved = copy_dir_all(install_dir, prefix_cache_dir)
+            .and_then(|()| fs::write(prefix_cache_dir.join(PREFIX_COMPLETE), ""));
+        if let Err(e) = saved {
+            println!("cargo:warning=Failed to cache image codecs: {}", e);
+            let _ = fs::remove_dir_all(prefix_cache_dir);
+        }
+    }
+
+    /// Recursively copies the contents of `from` into `to`, following symlinks.
+    #[cfg(feature = "image-codecs")]
+    fn copy_dir_all(from: &Path, to: &Path) -> std::io::Result<()> {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            let target = to.join(entry.file_name());
+            if entry.path().is_dir() {
+                copy_dir_all(&entry.path(), &target)?;
+            } else {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Common CMake configuration for the static codec libraries.
+    #[cfg(feature = "image-codecs")]
+    fn codec_config(source_dir: &Path, install_dir: &Path) -> Config {
+        let mut config = Config::new(source_dir);
+        if cfg!(target_os = "windows") {
+            if let Ok(_vs_install_dir) = env::var("VSINSTALLDIR") {
+                config.generator("NMake Makefiles");
+            }
+            config
+                .define("CMAKE_C_FLAGS_RELEASE", "/MD /O2")
+                .define("CMAKE_C_FLAGS_DEBUG", "/MDd /Od");
+        }
+        config
+            .define("CMAKE_POLICY_VERSION_MINIMUM", "3.5")
+            .define("CMAKE_BUILD_TYPE", "Release")
+            .define("BUILD_SHARED_LIBS", "OFF")
+            .define("CMAKE_POSITION_INDEPENDENT_CODE", "ON")
+            .define("CMAKE_INSTALL_LIBDIR", "lib")
+            .define("CMAKE_INSTALL_PREFIX", install_dir)
+            .define("CMAKE_PREFIX_PATH", install_dir);
+        config
+    }
+
     fn get_os_specific_config() -> (String, Vec<(String, String)>) {
         let mut cmake_cxx_flags = String::new();
         let mut additional_defines = Vec::new();
@@ -559,6 +820,16 @@ mod build_tesseract {
                     "tesseract54d.lib".to_string(),
                     "tesseract53d.lib".to_string(),
                 ],
+                "z" => vec!["zlibstatic.lib".to_string(), "zlibstaticd.lib".to_string()],
+                "png16" => vec![
+                    "libpng16_static.lib".to_string(),
+                    "libpng16_staticd.lib".to_string(),
+                ],
+                "jpeg" => vec!["jpeg-static.lib".to_string(), "jpeg.lib".to_string()],
+                "tiff" => vec!["tiff.lib".to_string(), "tiffd.lib".to_string()],
+                "webp" | "webpmux" | "sharpyuv" => {
+                    vec![format!("lib{}.lib", name), format!("{}.lib", name)]
+                }
                 _ => vec![format!("{}.lib", name)],
             }
         } else {
@@ -141,8 +141,7 @@ fn parse_output(value: &str) -> Result<OutputFormat, String> {
 }
 
 fn run(opts: &Options) -> Result<(), Box<dyn std::error::Error>> {
-    let img = image::open(&opts.image)?.to_luma8();
-    let (width, height) = img.dimensions();
+    let img = image::open(&opts.image)?;
 
     let tessdata = match opts.tessdata.clone().or_else(resolve_tessdata) {
         Some(dir) => dir,
@@ -161,7 +160,7 @@ fn run(opts: &Options) -> Result<(), Box<dyn std::error::Error>> {
         api.set_page_seg_mode(TessPageSegMode::from_int(psm))?;
     }
 
-    api.set_image(img.as_raw(), width as i32, height as i32, 1, width as i32)?;
+    api.set_dynamic_image(&img)?;
 
     let text = match opts.output {
         OutputFormat::Txt => api.get_utf8_text()?,
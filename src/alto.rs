@@ -0,0 +1,786 @@
+//! Reading and writing ALTO (Analyzed Layout and Text Object) XML.
+//!
+//! [`AltoDocument::parse`] reads the output of `get_alto_text()` and the
+//! ALTO renderer, whatever its ALTO version, into typed pages, blocks, lines,
+//! strings and spaces. [`AltoDocument::to_alto`] writes ALTO 4 with the
+//! `Description` of the document, so pipelines can record the source image
+//! and their own processing steps next to Tesseract's.
+
+use crate::error::{Result, TesseractError};
+use crate::xml::{self, escape};
+use std::fmt::Write;
+
+/// Namespace of the ALTO 4 documents written by [`AltoDocument::to_alto`].
+pub const ALTO_NAMESPACE: &str = "http://www.loc.gov/standards/alto/ns-v4#";
+
+const ALTO_SCHEMA_LOCATION: &str = "http://www.loc.gov/standards/alto/v4/alto-4-2.xsd";
+
+/// Parsed ALTO document.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoDocument {
+    pub description: AltoDescription,
+    pub pages: Vec<AltoPage>,
+}
+
+/// The `Description` section: units, source image and processing history.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoDescription {
+    /// Unit of the coordinates: `pixel`, `mm10` or `inch1200`.
+    pub measurement_unit: String,
+    /// `sourceImageInformation/fileName`.
+    pub source_image: Option<String>,
+    /// Processing steps, oldest first.
+    pub processing: Vec<AltoProcessing>,
+}
+
+impl Default for AltoDescription {
+    fn default() -> Self {
+        AltoDescription {
+            measurement_unit: "pixel".to_owned(),
+            source_image: None,
+            processing: Vec::new(),
+        }
+    }
+}
+
+/// A `Processing` element describing one step applied to the document.
+///
+/// When reading older ALTO versions, each step of an `OCRProcessing` element
+/// becomes one `AltoProcessing`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoProcessing {
+    pub id: String,
+    /// `contentGeneration`, `contentModification`, `preOperation`,
+    /// `postOperation` or `other`.
+    pub category: Option<String>,
+    /// Date and time of the step, in ISO 8601 format.
+    pub date_time: Option<String>,
+    /// Organization that performed the step.
+    pub agency: Option<String>,
+    pub step_descriptions: Vec<String>,
+    pub settings: Option<String>,
+    pub software: Option<AltoSoftware>,
+}
+
+/// A `processingSoftware` element.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoSoftware {
+    pub creator: Option<String>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A `Page` element.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoPage {
+    pub id: String,
+    pub width: f32,
+    pub height: f32,
+    /// Number of the page in the sequence of scanned images (`PHYSICAL_IMG_NR`).
+    pub physical_img_nr: Option<i32>,
+    pub print_space: AltoPrintSpace,
+}
+
+/// The `PrintSpace` of a page: the area containing its content.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoPrintSpace {
+    pub hpos: f32,
+    pub vpos: f32,
+    pub width: f32,
+    pub height: f32,
+    pub blocks: Vec<AltoBlock>,
+}
+
+/// A block of a print space or composed block.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AltoBlock {
+    Text(AltoTextBlock),
+    Composed(AltoComposedBlock),
+}
+
+/// A `ComposedBlock`: a group of blocks, e.g. Tesseract's layout block
+/// holding one `TextBlock` per paragraph.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoComposedBlock {
+    pub id: String,
+    pub hpos: f32,
+    pub vpos: f32,
+    pub width: f32,
+    pub height: f32,
+    pub blocks: Vec<AltoBlock>,
+}
+
+/// A `TextBlock` element.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoTextBlock {
+    pub id: String,
+    pub hpos: f32,
+    pub vpos: f32,
+    pub width: f32,
+    pub height: f32,
+    /// Language of the block (`LANG`).
+    pub lang: Option<String>,
+    pub lines: Vec<AltoTextLine>,
+}
+
+/// A `TextLine` element.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoTextLine {
+    pub id: String,
+    pub hpos: f32,
+    pub vpos: f32,
+    pub width: f32,
+    pub height: f32,
+    pub items: Vec<AltoLineItem>,
+}
+
+/// Content of a text line.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AltoLineItem {
+    String(AltoString),
+    Space(AltoSpace),
+}
+
+/// A `String` element: a word.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoString {
+    pub id: String,
+    pub hpos: f32,
+    pub vpos: f32,
+    pub width: f32,
+    pub height: f32,
+    /// The text of the word (`CONTENT`).
+    pub content: String,
+    /// Word confidence between 0 and 1 (`WC`).
+    pub wc: Option<f32>,
+    /// Language of the word (`LANG`).
+    pub lang: Option<String>,
+}
+
+/// An `SP` element: the space between two strings.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltoSpace {
+    pub hpos: f32,
+    pub vpos: f32,
+    pub width: f32,
+}
+
+impl AltoDocument {
+    /// Parses ALTO XML.
+    ///
+    /// Accepts complete documents of any ALTO version, as written by the ALTO
+    /// renderer, and the `Page` fragments returned by `get_alto_text()`,
+    /// including several concatenated ones. Unsupported elements such as
+    /// illustrations and hyphens are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `alto` - ALTO XML text.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed document, or `ParseError` if the text is not
+    /// well-formed XML or an attribute cannot be read.
+    pub fn parse(alto: &str) -> Result<Self> {
+        let trimmed = alto.trim_start();
+        // Fragments have no single root element
+        let wrapped;
+        let text = if trimmed.starts_with("<Page") {
+            wrapped = format!("<Layout>{}</Layout>", trimmed);
+            wrapped.as_str()
+        } else {
+            alto
+        };
+        let xml = xml::parse(text, "ALTO")?;
+        let root = xml.root_element();
+
+        let mut document = AltoDocument::default();
+        if let Some(description) = child(root, "Description") {
+            document.description = read_description(description);
+        }
+        for page in root.descendants().filter(|node| is(*node, "Page")) {
+            document.pages.push(read_page(page)?);
+        }
+        Ok(document)
+    }
+
+    /// Writes the document as ALTO 4 XML.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ALTO text.
+    pub fn to_alto(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <alto xmlns=\"{ns}\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+             xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+             xsi:schemaLocation=\"{ns} {schema}\">",
+            ns = ALTO_NAMESPACE,
+            schema = ALTO_SCHEMA_LOCATION
+        );
+        write_description(&mut out, &self.description);
+        out.push_str("\t<Layout>\n");
+        for (index, page) in self.pages.iter().enumerate() {
+            write_page(&mut out, page, index);
+        }
+        out.push_str("\t</Layout>\n</alto>\n");
+        out
+    }
+
+    /// Gets the text of the document.
+    ///
+    /// # Returns
+    ///
+    /// Returns the strings of each line separated by spaces, the lines by
+    /// newlines and the text blocks by blank lines.
+    pub fn text(&self) -> String {
+        let mut blocks = Vec::new();
+        for page in &self.pages {
+            collect_text_blocks(&page.print_space.blocks, &mut blocks);
+        }
+        blocks
+            .iter()
+            .map(|block| {
+                block
+                    .lines
+                    .iter()
+                    .map(AltoTextLine::text)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl std::str::FromStr for AltoDocument {
+    type Err = TesseractError;
+
+    fn from_str(alto: &str) -> Result<Self> {
+        AltoDocument::parse(alto)
+    }
+}
+
+impl AltoTextLine {
+    /// Iterates over the strings of the line, skipping spaces.
+    pub fn strings(&self) -> impl Iterator<Item = &AltoString> {
+        self.items.iter().filter_map(|item| match item {
+            AltoLineItem::String(string) => Some(string),
+            AltoLineItem::Space(_) => None,
+        })
+    }
+
+    /// Gets the text of the line.
+    ///
+    /// # Returns
+    ///
+    /// Returns the contents of the strings separated by spaces.
+    pub fn text(&self) -> String {
+        self.strings()
+            .map(|string| string.content.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn collect_text_blocks<'a>(blocks: &'a [AltoBlock], out: &mut Vec<&'a AltoTextBlock>) {
+    for block in blocks {
+        match block {
+            AltoBlock::Text(text) => out.push(text),
+            AltoBlock::Composed(composed) => collect_text_blocks(&composed.blocks, out),
+        }
+    }
+}
+
+/// Whether the element has the given local name, in any ALTO namespace.
+fn is(node: roxmltree::Node, name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == name
+}
+
+fn child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    node.children().find(|child| is(*child, name))
+}
+
+fn child_text(node: roxmltree::Node, name: &str) -> Option<String> {
+    child(node, name).map(|child| child.text().unwrap_or_default().trim().to_owned())
+}
+
+fn attribute(node: roxmltree::Node, name: &str) -> Option<String> {
+    node.attribute(name).map(str::to_owned)
+}
+
+fn number<T: std::str::FromStr>(node: roxmltree::Node, name: &str) -> Result<Option<T>> {
+    node.attribute(name)
+        .map(|value| {
+            value.trim().parse().map_err(|_| {
+                xml::error(
+                    "ALTO",
+                    format!(
+                        "invalid {} '{}' at {}",
+                        name,
+                        value,
+                        node.document().text_pos_at(node.range().start)
+                    ),
+                )
+            })
+        })
+        .transpose()
+}
+
+fn position(node: roxmltree::Node) -> Result<[f32; 4]> {
+    Ok([
+        number(node, "HPOS")?.unwrap_or_default(),
+        number(node, "VPOS")?.unwrap_or_default(),
+        number(node, "WIDTH")?.unwrap_or_default(),
+        number(node, "HEIGHT")?.unwrap_or_default(),
+    ])
+}
+
+fn read_description(node: roxmltree::Node) -> AltoDescription {
+    let mut description = AltoDescription {
+        measurement_unit: child_text(node, "MeasurementUnit").unwrap_or_else(|| "pixel".into()),
+        source_image: child(node, "sourceImageInformation")
+            .and_then(|info| child_text(info, "fileName")),
+        processing: Vec::new(),
+    };
+    for element in node.children().filter(roxmltree::Node::is_element) {
+        let id = attribute(element, "ID").unwrap_or_default();
+        match element.tag_name().name() {
+            "Processing" => description
+                .processing
+                .push(read_processing(element, id, None)),
+            // ALTO 2 and 3 group steps in OCRProcessing
+            "OCRProcessing" => {
+                let steps = element.children().filter_map(|step| {
+                    let category = match step.tag_name().name() {
+                        "preProcessingStep" => "preOperation",
+                        "ocrProcessingStep" => "contentGeneration",
+                        "postProcessingStep" => "postOperation",
+                        _ => return None,
+                    };
+                    Some((step, category))
+                });
+                for (i, (step, category)) in steps.enumerate() {
+                    let id = if i == 0 {
+                        id.clone()
+                    } else {
+                        format!("{}_{}", id, i)
+                    };
+                    description
+                        .processing
+                        .push(read_processing(step, id, Some(category)));
+                }
+            }
+            _ => {}
+        }
+    }
+    description
+}
+
+fn read_processing(node: roxmltree::Node, id: String, category: Option<&str>) -> AltoProcessing {
+    AltoProcessing {
+        id,
+        category: child_text(node, "processingCategory").or(category.map(str::to_owned)),
+        date_time: child_text(node, "processingDateTime"),
+        agency: child_text(node, "processingAgency"),
+        step_descriptions: node
+            .children()
+            .filter(|child| is(*child, "processingStepDescription"))
+            .map(|child| child.text().unwrap_or_default().trim().to_owned())
+            .collect(),
+        settings: child_text(node, "processingStepSettings"),
+        software: child(node, "processingSoftware").map(|software| AltoSoftware {
+            creator: child_text(software, "softwareCreator"),
+            name: child_text(software, "softwareName"),
+            version: child_text(software, "softwareVersion"),
+            description: child_text(software, "applicationDescription"),
+        }),
+    }
+}
+
+fn read_page(node: roxmltree::Node) -> Result<AltoPage> {
+    let mut page = AltoPage {
+        id: attribute(node, "ID").unwrap_or_default(),
+        width: number(node, "WIDTH")?.unwrap_or_default(),
+        height: number(node, "HEIGHT")?.unwrap_or_default(),
+        physical_img_nr: number(node, "PHYSICAL_IMG_NR")?,
+        print_space: AltoPrintSpace::default(),
+    };
+    if let Some(print_space) = child(node, "PrintSpace") {
+        let [hpos, vpos, width, height] = position(print_space)?;
+        page.print_space = AltoPrintSpace {
+            hpos,
+            vpos,
+            width,
+            height,
+            blocks: read_blocks(print_space)?,
+        };
+    }
+    Ok(page)
+}
+
+fn read_blocks(node: roxmltree::Node) -> Result<Vec<AltoBlock>> {
+    let mut blocks = Vec::new();
+    for element in node.children().filter(roxmltree::Node::is_element) {
+        let id = attribute(element, "ID").unwrap_or_default();
+        let [hpos, vpos, width, height] = position(element)?;
+        match element.tag_name().name() {
+            "TextBlock" => {
+                let mut lines = Vec::new();
+                for line in element.children().filter(|child| is(*child, "TextLine")) {
+                    lines.push(read_line(line)?);
+                }
+                blocks.push(AltoBlock::Text(AltoTextBlock {
+                    id,
+                    hpos,
+                    vpos,
+                    width,
+                    height,
+                    lang: attribute(element, "LANG"),
+                    lines,
+                }));
+            }
+            "ComposedBlock" => blocks.push(AltoBlock::Composed(AltoComposedBlock {
+                id,
+                hpos,
+                vpos,
+                width,
+                height,
+                blocks: read_blocks(element)?,
+            })),
+            _ => {}
+        }
+    }
+    Ok(blocks)
+}
+
+fn read_line(node: roxmltree::Node) -> Result<AltoTextLine> {
+    let [hpos, vpos, width, height] = position(node)?;
+    let mut line = AltoTextLine {
+        id: attribute(node, "ID").unwrap_or_default(),
+        hpos,
+        vpos,
+        width,
+        height,
+        items: Vec::new(),
+    };
+    for element in node.children().filter(roxmltree::Node::is_element) {
+        let [hpos, vpos, width, height] = position(element)?;
+        match element.tag_name().name() {
+            "String" => line.items.push(AltoLineItem::String(AltoString {
+                id: attribute(element, "ID").unwrap_or_default(),
+                hpos,
+                vpos,
+                width,
+                height,
+                content: attribute(element, "CONTENT").unwrap_or_default(),
+                wc: number(element, "WC")?,
+                lang: attribute(element, "LANG"),
+            })),
+            "SP" => line
+                .items
+                .push(AltoLineItem::Space(AltoSpace { hpos, vpos, width })),
+            _ => {}
+        }
+    }
+    Ok(line)
+}
+
+fn write_element(out: &mut String, indent: usize, name: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        let _ = writeln!(
+            out,
+            "{}<{name}>{}</{name}>",
+            "\t".repeat(indent),
+            escape(value),
+            name = name
+        );
+    }
+}
+
+fn write_description(out: &mut String, description: &AltoDescription) {
+    out.push_str("\t<Description>\n");
+    write_element(
+        out,
+        2,
+        "MeasurementUnit",
+        &Some(description.measurement_unit.clone()),
+    );
+    if description.source_image.is_some() {
+        out.push_str("\t\t<sourceImageInformation>\n");
+        write_element(out, 3, "fileName", &description.source_image);
+        out.push_str("\t\t</sourceImageInformation>\n");
+    }
+    for processing in &description.processing {
+        out.push_str("\t\t<Processing");
+        write_id(out, &processing.id);
+        out.push_str(">\n");
+        write_element(out, 3, "processingCategory", &processing.category);
+        write_element(out, 3, "processingDateTime", &processing.date_time);
+        write_element(out, 3, "processingAgency", &processing.agency);
+        for step in &processing.step_descriptions {
+            write_element(out, 3, "processingStepDescription", &Some(step.clone()));
+        }
+        write_element(out, 3, "processingStepSettings", &processing.settings);
+        if let Some(software) = &processing.software {
+            out.push_str("\t\t\t<processingSoftware>\n");
+            write_element(out, 4, "softwareCreator", &software.creator);
+            write_element(out, 4, "softwareName", &software.name);
+            write_element(out, 4, "softwareVersion", &software.version);
+            write_element(out, 4, "applicationDescription", &software.description);
+            out.push_str("\t\t\t</processingSoftware>\n");
+        }
+        out.push_str("\t\t</Processing>\n");
+    }
+    out.push_str("\t</Description>\n");
+}
+
+/// Writes an `ID` attribute, left out when empty as `xsd:ID` cannot be empty.
+fn write_id(out: &mut String, id: &str) {
+    if !id.is_empty() {
+        let _ = write!(out, " ID=\"{}\"", escape(id));
+    }
+}
+
+fn write_position(out: &mut String, hpos: f32, vpos: f32, width: f32, height: f32) {
+    let _ = write!(
+        out,
+        " HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\" HEIGHT=\"{}\"",
+        hpos, vpos, width, height
+    );
+}
+
+/// Writes a page; `index` is its position in the document, used as the
+/// required `PHYSICAL_IMG_NR` when the page has none.
+fn write_page(out: &mut String, page: &AltoPage, index: usize) {
+    out.push_str("\t\t<Page");
+    write_id(out, &page.id);
+    let physical_img_nr = page
+        .physical_img_nr
+        .map_or_else(|| index.to_string(), |number| number.to_string());
+    let _ = write!(
+        out,
+        " WIDTH=\"{}\" HEIGHT=\"{}\" PHYSICAL_IMG_NR=\"{}\"",
+        page.width, page.height, physical_img_nr
+    );
+    out.push_str(">\n\t\t\t<PrintSpace");
+    let space = &page.print_space;
+    write_position(out, space.hpos, space.vpos, space.width, space.height);
+    out.push_str(">\n");
+    write_blocks(out, &space.blocks, 4);
+    out.push_str("\t\t\t</PrintSpace>\n\t\t</Page>\n");
+}
+
+fn write_blocks(out: &mut String, blocks: &[AltoBlock], indent: usize) {
+    let tabs = "\t".repeat(indent);
+    for block in blocks {
+        match block {
+            AltoBlock::Composed(composed) => {
+                let _ = write!(out, "{}<ComposedBlock", tabs);
+                write_id(out, &composed.id);
+                write_position(
+                    out,
+                    composed.hpos,
+                    composed.vpos,
+                    composed.width,
+                    composed.height,
+                );
+                out.push_str(">\n");
+                write_blocks(out, &composed.blocks, indent + 1);
+                let _ = writeln!(out, "{}</ComposedBlock>", tabs);
+            }
+            AltoBlock::Text(text) => {
+                let _ = write!(out, "{}<TextBlock", tabs);
+                write_id(out, &text.id);
+                write_position(out, text.hpos, text.vpos, text.width, text.height);
+                if let Some(lang) = &text.lang {
+                    let _ = write!(out, " LANG=\"{}\"", escape(lang));
+                }
+                out.push_str(">\n");
+                for line in &text.lines {
+                    write_line(out, line, indent + 1);
+                }
+                let _ = writeln!(out, "{}</TextBlock>", tabs);
+            }
+        }
+    }
+}
+
+fn write_line(out: &mut String, line: &AltoTextLine, indent: usize) {
+    let tabs = "\t".repeat(indent);
+    let _ = write!(out, "{}<TextLine", tabs);
+    write_id(out, &line.id);
+    write_position(out, line.hpos, line.vpos, line.width, line.height);
+    out.push_str(">\n");
+    for item in &line.items {
+        out.push_str(&tabs);
+        out.push('\t');
+        match item {
+            AltoLineItem::String(string) => {
+                out.push_str("<String");
+                write_id(out, &string.id);
+                write_position(out, string.hpos, string.vpos, string.width, string.height);
+                if let Some(wc) = string.wc {
+                    let _ = write!(out, " WC=\"{}\"", wc);
+                }
+                if let Some(lang) = &string.lang {
+                    let _ = write!(out, " LANG=\"{}\"", escape(lang));
+                }
+                let _ = write!(out, " CONTENT=\"{}\"/>", escape(&string.content));
+            }
+            AltoLineItem::Space(space) => {
+                let _ = write!(
+                    out,
+                    "<SP WIDTH=\"{}\" VPOS=\"{}\" HPOS=\"{}\"/>",
+                    space.width, space.vpos, space.hpos
+                );
+            }
+        }
+        out.push('\n');
+    }
+    let _ = writeln!(out, "{}</TextLine>", tabs);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALTO_3: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<alto xmlns="http://www.loc.gov/standards/alto/ns-v3#" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://www.loc.gov/standards/alto/ns-v3# http://www.loc.gov/alto/v3/alto-3-0.xsd">
+	<Description>
+		<MeasurementUnit>pixel</MeasurementUnit>
+		<sourceImageInformation>
+			<fileName>scan.png</fileName>
+		</sourceImageInformation>
+		<OCRProcessing ID="OCR_0">
+			<ocrProcessingStep>
+				<processingSoftware>
+					<softwareName>tesseract 5.5.2</softwareName>
+				</processingSoftware>
+			</ocrProcessingStep>
+		</OCRProcessing>
+	</Description>
+	<Layout>
+		<Page WIDTH="1200" HEIGHT="400" PHYSICAL_IMG_NR="0" ID="page_0">
+			<PrintSpace HPOS="0" VPOS="0" WIDTH="1200" HEIGHT="400">
+				<ComposedBlock ID="cblock_0" HPOS="36" VPOS="92" WIDTH="1128" HEIGHT="59">
+					<TextBlock ID="block_0" HPOS="36" VPOS="92" WIDTH="1128" HEIGHT="59">
+						<TextLine ID="line_0" HPOS="36" VPOS="92" WIDTH="1128" HEIGHT="59">
+							<String ID="string_0" HPOS="36" VPOS="92" WIDTH="82" HEIGHT="48" WC="0.96" CONTENT="This"/><SP WIDTH="22" VPOS="92" HPOS="118"/>
+							<String ID="string_1" HPOS="140" VPOS="92" WIDTH="50" HEIGHT="48" WC="0.95" CONTENT="R&amp;D"/>
+						</TextLine>
+					</TextBlock>
+				</ComposedBlock>
+			</PrintSpace>
+		</Page>
+	</Layout>
+</alto>
+"#;
+
+    #[test]
+    fn test_parse_alto_3() {
+        let document = AltoDocument::parse(ALTO_3).unwrap();
+        let description = &document.description;
+        assert_eq!(description.measurement_unit, "pixel");
+        assert_eq!(description.source_image.as_deref(), Some("scan.png"));
+        assert_eq!(description.processing.len(), 1);
+        assert_eq!(description.processing[0].id, "OCR_0");
+        assert_eq!(
+            description.processing[0].category.as_deref(),
+            Some("contentGeneration")
+        );
+        assert_eq!(
+            description.processing[0]
+                .software
+                .as_ref()
+                .and_then(|software| software.name.as_deref()),
+            Some("tesseract 5.5.2")
+        );
+
+        let page = &document.pages[0];
+        assert_eq!((page.width, page.height), (1200.0, 400.0));
+        assert_eq!(page.physical_img_nr, Some(0));
+        let AltoBlock::Composed(composed) = &page.print_space.blocks[0] else {
+            panic!("Expected a composed block");
+        };
+        let AltoBlock::Text(block) = &composed.blocks[0] else {
+            panic!("Expected a text block");
+        };
+        let line = &block.lines[0];
+        assert_eq!(line.items.len(), 3);
+        let strings: Vec<_> = line.strings().collect();
+        assert_eq!(strings[0].wc, Some(0.96));
+        assert_eq!(strings[1].content, "R&D");
+        assert_eq!(document.text(), "This R&D");
+    }
+
+    #[test]
+    fn test_round_trip_with_processing() {
+        let mut document = AltoDocument::parse(ALTO_3).unwrap();
+        document.description.processing.push(AltoProcessing {
+            id: "fix_1".into(),
+            category: Some("contentModification".into()),
+            date_time: Some("2026-10-17T12:00:00Z".into()),
+            step_descriptions: vec!["Manual correction".into()],
+            ..AltoProcessing::default()
+        });
+        let written = document.to_alto();
+        assert!(written.contains(ALTO_NAMESPACE));
+        assert!(written
+            .contains("<processingStepDescription>Manual correction</processingStepDescription>"));
+        assert_eq!(AltoDocument::parse(&written).unwrap(), document);
+    }
+
+    #[test]
+    fn test_write_required_attributes() {
+        let document = AltoDocument {
+            pages: vec![AltoPage::default(), AltoPage::default()],
+            ..AltoDocument::default()
+        };
+        let written = document.to_alto();
+        assert!(written.contains("PHYSICAL_IMG_NR=\"0\""), "{}", written);
+        assert!(written.contains("PHYSICAL_IMG_NR=\"1\""), "{}", written);
+        assert!(!written.contains("ID=\"\""), "{}", written);
+    }
+
+    #[test]
+    fn test_page_fragments() {
+        let start = ALTO_3.find("\t\t<Page").unwrap();
+        let end = ALTO_3.find("\t</Layout>").unwrap();
+        let page = &ALTO_3[start..end];
+        let document: AltoDocument = format!("{}{}", page, page).parse().unwrap();
+        assert_eq!(document.pages.len(), 2);
+        assert_eq!(document.description, AltoDescription::default());
+    }
+
+    #[test]
+    fn test_invalid_alto() {
+        assert!(matches!(
+            AltoDocument::parse("<alto><Layout>"),
+            Err(TesseractError::ParseError { format: "ALTO", .. })
+        ));
+        assert!(matches!(
+            AltoDocument::parse("<Page WIDTH=\"wide\"/>"),
+            Err(TesseractError::ParseError { .. })
+        ));
+    }
+}
@@ -1,19 +1,71 @@
-use crate::enums::TessPageSegMode;
+use crate::diagnostics::DiagnosticCapture;
+use crate::document::{self, Document};
+use crate::enums::{OcrEngineMode, TessPageSegMode};
 use crate::error::{Result, TesseractError};
 use crate::page_iterator::{TessBaseAPIGetIterator, TessPageIteratorDelete};
+use crate::pages::PageReader;
+use crate::pix::{pixClone, pixGetHeight, pixGetWidth};
+use crate::renderer::Renderer;
 use crate::result_iterator::TessResultIteratorDelete;
-use crate::{PageIterator, ResultIterator};
-use std::collections::HashMap;
+use crate::traineddata::{check_engine_mode, check_languages, EngineComponents};
+use crate::types::{OsdResult, PageResult};
+use crate::{PageIterator, Pix, ResultIterator, TessMonitor, TessResultRenderer};
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_double, c_float, c_int, c_void};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+/// Everything needed to re-create an initialized engine, used by `try_clone`.
 #[derive(Clone)]
 pub struct TesseractConfiguration {
-    datapath: String,
-    language: String,
-    variables: HashMap<String, String>,
+    pub(crate) datapath: String,
+    pub(crate) language: String,
+    pub(crate) oem: OcrEngineMode,
+    pub(crate) psm: Option<TessPageSegMode>,
+    pub(crate) config_files: Vec<String>,
+    pub(crate) init_variables: Vec<(String, String)>,
+    pub(crate) traineddata: Option<Arc<[u8]>>,
+    pub(crate) variables: Vec<(String, String)>,
+}
+
+impl TesseractConfiguration {
+    /// Whether this configuration describes an initialized engine.
+    fn is_initialized(&self) -> bool {
+        !self.datapath.is_empty() || !self.language.is_empty() || self.traineddata.is_some()
+    }
+
+    /// Whether both configurations set up the same engine, ignoring the page
+    /// segmentation mode, which can be changed back.
+    pub(crate) fn same_engine(&self, other: &TesseractConfiguration) -> bool {
+        let same_traineddata = match (&self.traineddata, &other.traineddata) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        self.datapath == other.datapath
+            && self.language == other.language
+            && self.oem == other.oem
+            && self.config_files == other.config_files
+            && self.init_variables == other.init_variables
+            && self.variables == other.variables
+            && same_traineddata
+    }
+}
+
+impl Default for TesseractConfiguration {
+    fn default() -> Self {
+        TesseractConfiguration {
+            datapath: String::new(),
+            language: String::new(),
+            oem: OcrEngineMode::Default,
+            psm: None,
+            config_files: Vec::new(),
+            init_variables: Vec::new(),
+            traineddata: None,
+            variables: Vec::new(),
+        }
+    }
 }
 
 /// Main interface to the Tesseract OCR engine.
@@ -22,6 +74,12 @@ pub struct TesseractAPI {
     /// Handle to the Tesseract engine.
     pub handle: Arc<Mutex<*mut c_void>>,
     config: Arc<Mutex<TesseractConfiguration>>,
+    pub(crate) diagnostics: Arc<Mutex<DiagnosticCapture>>,
+    /// Set when an engine operation fails, so a pool can drop the engine.
+    failed: AtomicBool,
+    /// Set once the current image has been recognized, cleared when the
+    /// image, the layout or the engine changes.
+    recognized: AtomicBool,
 }
 
 unsafe impl Send for TesseractAPI {}
@@ -38,11 +96,10 @@ impl TesseractAPI {
         let handle = unsafe { TessBaseAPICreate() };
         TesseractAPI {
             handle: Arc::new(Mutex::new(handle)),
-            config: Arc::new(Mutex::new(TesseractConfiguration {
-                datapath: String::new(),
-                language: String::new(),
-                variables: HashMap::new(),
-            })),
+            config: Arc::new(Mutex::new(TesseractConfiguration::default())),
+            diagnostics: Arc::new(Mutex::new(DiagnosticCapture::default())),
+            failed: AtomicBool::new(false),
+            recognized: AtomicBool::new(false),
         }
     }
 
@@ -69,31 +126,34 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if initialization is successful, otherwise returns an error.
     pub fn init<P: AsRef<Path>>(&self, datapath: P, language: &str) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
         let datapath_str = datapath.as_ref().to_str().unwrap().to_owned();
         let language_str = language.to_owned();
 
-        // Update configuration first
-        {
-            let mut config = self
-                .config
+        check_languages(&datapath_str, &language_str)?;
+        let datapath = CString::new(datapath_str.as_str()).unwrap();
+        let language = CString::new(language_str.as_str()).unwrap();
+        let result = {
+            let handle = self
+                .handle
                 .lock()
                 .map_err(|_| TesseractError::MutexLockError)?;
-            config.datapath = datapath_str.clone();
-            config.language = language_str.clone();
-        }
-
-        let datapath = CString::new(datapath_str).unwrap();
-        let language = CString::new(language_str).unwrap();
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
-        let result = unsafe { TessBaseAPIInit3(*handle, datapath.as_ptr(), language.as_ptr()) };
+            unsafe { TessBaseAPIInit3(*handle, datapath.as_ptr(), language.as_ptr()) }
+        };
         if result != 0 {
-            Err(TesseractError::InitError)
-        } else {
-            Ok(())
+            return Err(self.engine_error(TesseractError::InitError {
+                datapath: datapath_str,
+                language: language_str,
+            }));
         }
+        self.record_init(
+            &datapath_str,
+            &language_str,
+            OcrEngineMode::Default,
+            &[],
+            None,
+        )
     }
 
     /// Gets the confidence values for all recognized words.
@@ -112,6 +172,7 @@ impl TesseractAPI {
     ///
     /// Returns the mean text confidence as an integer.
     pub fn mean_text_conf(&self) -> Result<i32> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
@@ -130,26 +191,31 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if setting the variable is successful, otherwise returns an error.
     pub fn set_variable(&self, name: &str, value: &str) -> Result<()> {
-        {
-            let mut config = self
-                .config
+        let name_c = CString::new(name).unwrap();
+        let value_c = CString::new(value).unwrap();
+        let result = {
+            let handle = self
+                .handle
                 .lock()
                 .map_err(|_| TesseractError::MutexLockError)?;
-            config.variables.insert(name.to_owned(), value.to_owned());
+            unsafe { TessBaseAPISetVariable(*handle, name_c.as_ptr(), value_c.as_ptr()) }
+        };
+        if result != 1 {
+            return Err(TesseractError::SetVariableError {
+                name: name.to_owned(),
+                value: value.to_owned(),
+            });
         }
 
-        let name = CString::new(name).unwrap();
-        let value = CString::new(value).unwrap();
-        let handle = self
-            .handle
+        // Only record variables that were accepted, so try_clone can replay them
+        let mut config = self
+            .config
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
-        let result = unsafe { TessBaseAPISetVariable(*handle, name.as_ptr(), value.as_ptr()) };
-        if result != 1 {
-            Err(TesseractError::SetVariableError)
-        } else {
-            Ok(())
-        }
+        // Keep the order in which variables were last set; some depend on others
+        config.variables.retain(|(recorded, _)| recorded != name);
+        config.variables.push((name.to_owned(), value.to_owned()));
+        Ok(())
     }
 
     /// Gets a string variable.
@@ -162,14 +228,16 @@ impl TesseractAPI {
     ///
     /// Returns the value of the variable as a string.
     pub fn get_string_variable(&self, name: &str) -> Result<String> {
-        let name = CString::new(name).unwrap();
+        let name_c = CString::new(name).unwrap();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
-        let value_ptr = unsafe { TessBaseAPIGetStringVariable(*handle, name.as_ptr()) };
+        let value_ptr = unsafe { TessBaseAPIGetStringVariable(*handle, name_c.as_ptr()) };
         if value_ptr.is_null() {
-            return Err(TesseractError::GetVariableError);
+            return Err(TesseractError::GetVariableError {
+                name: name.to_owned(),
+            });
         }
         let c_str = unsafe { CStr::from_ptr(value_ptr) };
         Ok(c_str.to_str()?.to_owned())
@@ -185,17 +253,19 @@ impl TesseractAPI {
     ///
     /// Returns the value of the variable as an integer.
     pub fn get_int_variable(&self, name: &str) -> Result<i32> {
-        let name = CString::new(name).unwrap();
+        let name_c = CString::new(name).unwrap();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let mut value: c_int = 0;
-        let ok = unsafe { TessBaseAPIGetIntVariable(*handle, name.as_ptr(), &mut value) };
+        let ok = unsafe { TessBaseAPIGetIntVariable(*handle, name_c.as_ptr(), &mut value) };
         if ok != 0 {
             Ok(value)
         } else {
-            Err(TesseractError::GetVariableError)
+            Err(TesseractError::GetVariableError {
+                name: name.to_owned(),
+            })
         }
     }
 
@@ -209,17 +279,19 @@ impl TesseractAPI {
     ///
     /// Returns the value of the variable as a boolean.
     pub fn get_bool_variable(&self, name: &str) -> Result<bool> {
-        let name = CString::new(name).unwrap();
+        let name_c = CString::new(name).unwrap();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let mut value: c_int = 0;
-        let ok = unsafe { TessBaseAPIGetBoolVariable(*handle, name.as_ptr(), &mut value) };
+        let ok = unsafe { TessBaseAPIGetBoolVariable(*handle, name_c.as_ptr(), &mut value) };
         if ok != 0 {
             Ok(value != 0)
         } else {
-            Err(TesseractError::GetVariableError)
+            Err(TesseractError::GetVariableError {
+                name: name.to_owned(),
+            })
         }
     }
 
@@ -233,17 +305,19 @@ impl TesseractAPI {
     ///
     /// Returns the value of the variable as a double.
     pub fn get_double_variable(&self, name: &str) -> Result<f64> {
-        let name = CString::new(name).unwrap();
+        let name_c = CString::new(name).unwrap();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let mut value: c_double = 0.0;
-        let ok = unsafe { TessBaseAPIGetDoubleVariable(*handle, name.as_ptr(), &mut value) };
+        let ok = unsafe { TessBaseAPIGetDoubleVariable(*handle, name_c.as_ptr(), &mut value) };
         if ok != 0 {
             Ok(value)
         } else {
-            Err(TesseractError::GetVariableError)
+            Err(TesseractError::GetVariableError {
+                name: name.to_owned(),
+            })
         }
     }
 
@@ -257,6 +331,14 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if setting the page segmentation mode is successful, otherwise returns an error.
     pub fn set_page_seg_mode(&self, mode: TessPageSegMode) -> Result<()> {
+        {
+            let mut config = self
+                .config
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            config.psm = Some(mode);
+        }
+
         let handle = self
             .handle
             .lock()
@@ -285,13 +367,48 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if recognition is successful, otherwise returns an error.
     pub fn recognize(&self) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let result = unsafe { TessBaseAPIRecognize(*handle, std::ptr::null_mut()) };
+        self.set_recognized(result == 0);
         if result != 0 {
-            Err(TesseractError::OcrError)
+            Err(self.engine_error(ocr_error(*handle)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Performs OCR recognition, reporting progress to and checking for
+    /// cancellation through a monitor.
+    ///
+    /// The monitor is not locked while recognizing, so it can be polled and
+    /// its token cancelled from other threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor` - Monitor with the progress callback, cancellation and deadline.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if recognition is successful, `Cancelled` if the
+    /// monitor's token or cancel callback stopped it, `DeadlineExceeded` if
+    /// the monitor's deadline passed, otherwise returns an error.
+    pub fn recognize_with_monitor(&self, monitor: &TessMonitor) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
+        let monitor_handle = monitor.start()?;
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let result = unsafe { TessBaseAPIRecognize(*handle, monitor_handle) };
+        self.set_recognized(result == 0);
+        if result != 0 {
+            Err(monitor
+                .stop_reason()
+                .unwrap_or_else(|| self.engine_error(ocr_error(*handle))))
         } else {
             Ok(())
         }
@@ -307,13 +424,14 @@ impl TesseractAPI {
     ///
     /// Returns the HOCR text for the specified page as a string.
     pub fn get_hocr_text(&self, page: i32) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let text_ptr = unsafe { TessBaseAPIGetHOCRText(*handle, page) };
         if text_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let c_str = unsafe { CStr::from_ptr(text_ptr) };
         let result = c_str.to_str()?.to_owned();
@@ -331,13 +449,14 @@ impl TesseractAPI {
     ///
     /// Returns the ALTO text for the specified page as a string.
     pub fn get_alto_text(&self, page: i32) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let text_ptr = unsafe { TessBaseAPIGetAltoText(*handle, page) };
         if text_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let c_str = unsafe { CStr::from_ptr(text_ptr) };
         let result = c_str.to_str()?.to_owned();
@@ -355,13 +474,14 @@ impl TesseractAPI {
     ///
     /// Returns the TSV text for the specified page as a string.
     pub fn get_tsv_text(&self, page: i32) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let text_ptr = unsafe { TessBaseAPIGetTsvText(*handle, page) };
         if text_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let c_str = unsafe { CStr::from_ptr(text_ptr) };
         let result = c_str.to_str()?.to_owned();
@@ -441,18 +561,16 @@ impl TesseractAPI {
     ///
     /// # Returns
     ///
-    /// Returns a pointer to the thresholded image.
-    pub fn get_thresholded_image(&self) -> Result<*mut c_void> {
+    /// Returns a copy of the binarized image that Tesseract recognizes from.
+    pub fn get_thresholded_image(&self) -> Result<Pix> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let pix = unsafe { TessBaseAPIGetThresholdedImage(*handle) };
-        if pix.is_null() {
-            Err(TesseractError::NullPointerError)
-        } else {
-            Ok(pix)
-        }
+        // The caller owns the returned image.
+        unsafe { Pix::from_raw(pix) }
     }
 
     /// Gets the box text for the specified page.
@@ -465,13 +583,14 @@ impl TesseractAPI {
     ///
     /// Returns the box text for the specified page as a string.
     pub fn get_box_text(&self, page: i32) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let text_ptr = unsafe { TessBaseAPIGetBoxText(*handle, page) };
         if text_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let c_str = unsafe { CStr::from_ptr(text_ptr) };
         let result = c_str.to_str()?.to_owned();
@@ -489,13 +608,14 @@ impl TesseractAPI {
     ///
     /// Returns the LSTM box text for the specified page as a string.
     pub fn get_lstm_box_text(&self, page: i32) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let text_ptr = unsafe { TessBaseAPIGetLSTMBoxText(*handle, page) };
         if text_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let c_str = unsafe { CStr::from_ptr(text_ptr) };
         let result = c_str.to_str()?.to_owned();
@@ -513,13 +633,14 @@ impl TesseractAPI {
     ///
     /// Returns the word str box text for the specified page as a string.
     pub fn get_word_str_box_text(&self, page: i32) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let text_ptr = unsafe { TessBaseAPIGetWordStrBoxText(*handle, page) };
         if text_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let c_str = unsafe { CStr::from_ptr(text_ptr) };
         let result = c_str.to_str()?.to_owned();
@@ -533,13 +654,14 @@ impl TesseractAPI {
     ///
     /// Returns the UNLV text as a string.
     pub fn get_unlv_text(&self) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let text_ptr = unsafe { TessBaseAPIGetUNLVText(*handle) };
         if text_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let c_str = unsafe { CStr::from_ptr(text_ptr) };
         let result = c_str.to_str()?.to_owned();
@@ -553,13 +675,14 @@ impl TesseractAPI {
     ///
     /// Returns a vector of all word confidences.
     pub fn all_word_confidences(&self) -> Result<Vec<i32>> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let confidences_ptr = unsafe { TessBaseAPIAllWordConfidences(*handle) };
         if confidences_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let mut confidences = Vec::new();
         let mut i = 0;
@@ -595,8 +718,10 @@ impl TesseractAPI {
     ///
     /// # Returns
     ///
-    /// Returns a tuple containing the orientation in degrees, the orientation confidence, the script name, and the script confidence.
-    pub fn detect_os(&self) -> Result<(i32, f32, String, f32)> {
+    /// Returns the orientation in degrees and the script name, each with its confidence.
+    pub fn detect_os(&self) -> Result<OsdResult> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
         let handle = self
             .handle
             .lock()
@@ -615,7 +740,7 @@ impl TesseractAPI {
             )
         };
         if result == 0 {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
         let script_name = if !script_name_ptr.is_null() {
             let c_str = unsafe { CStr::from_ptr(script_name_ptr) };
@@ -625,7 +750,12 @@ impl TesseractAPI {
         } else {
             String::new()
         };
-        Ok((orient_deg, orient_conf, script_name, script_conf))
+        Ok(OsdResult {
+            orientation_degrees: orient_deg,
+            orientation_confidence: orient_conf,
+            script: script_name,
+            script_confidence: script_conf,
+        })
     }
 
     /// Sets the minimum orientation margin.
@@ -665,19 +795,24 @@ impl TesseractAPI {
 
     /// Sets the input image.
     ///
+    /// The input image is the original (not thresholded) image used by the
+    /// renderers, e.g. as the background image of a PDF page.
+    ///
     /// # Arguments
     ///
-    /// * `pix` - Pointer to the input image.
+    /// * `pix` - The input image. Tesseract keeps its own reference to it.
     ///
     /// # Returns
     ///
     /// Returns `Ok(())` if setting the input image is successful, otherwise returns an error.
-    pub fn set_input_image(&self, pix: *mut c_void) -> Result<()> {
+    pub fn set_input_image(&self, pix: &Pix) -> Result<()> {
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
-        unsafe { TessBaseAPISetInputImage(*handle, pix) };
+        // Tesseract takes ownership of the image it is given, so hand it a
+        // reference of its own rather than the one held by `pix`.
+        unsafe { TessBaseAPISetInputImage(*handle, pixClone(pix.as_ptr())) };
         Ok(())
     }
 
@@ -685,18 +820,19 @@ impl TesseractAPI {
     ///
     /// # Returns
     ///
-    /// Returns a pointer to the input image.
-    pub fn get_input_image(&self) -> Result<*mut c_void> {
+    /// Returns a new reference to the input image, which stays valid after
+    /// the engine replaces or clears its own copy.
+    pub fn get_input_image(&self) -> Result<Pix> {
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         let pix = unsafe { TessBaseAPIGetInputImage(*handle) };
         if pix.is_null() {
-            Err(TesseractError::NullPointerError)
-        } else {
-            Ok(pix)
+            return Err(TesseractError::NullPointerError);
         }
+        // The engine keeps ownership of the returned image.
+        unsafe { Pix::from_raw(pixClone(pix)) }
     }
 
     /// Sets the output name.
@@ -729,15 +865,19 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if setting the debug variable is successful, otherwise returns an error.
     pub fn set_debug_variable(&self, name: &str, value: &str) -> Result<()> {
-        let name = CString::new(name).unwrap();
-        let value = CString::new(value).unwrap();
+        let name_c = CString::new(name).unwrap();
+        let value_c = CString::new(value).unwrap();
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
-        let result = unsafe { TessBaseAPISetDebugVariable(*handle, name.as_ptr(), value.as_ptr()) };
+        let result =
+            unsafe { TessBaseAPISetDebugVariable(*handle, name_c.as_ptr(), value_c.as_ptr()) };
         if result != 1 {
-            Err(TesseractError::SetVariableError)
+            Err(TesseractError::SetVariableError {
+                name: name.to_owned(),
+                value: value.to_owned(),
+            })
         } else {
             Ok(())
         }
@@ -760,7 +900,10 @@ impl TesseractAPI {
             .map_err(|_| TesseractError::MutexLockError)?;
         let result = unsafe { TessBaseAPIPrintVariablesToFile(*handle, filename.as_ptr()) };
         if result == 0 {
-            Err(TesseractError::IoError)
+            Err(TesseractError::io(
+                filename.to_string_lossy().as_ref(),
+                std::io::Error::other("Tesseract could not write the variables"),
+            ))
         } else {
             Ok(())
         }
@@ -772,6 +915,7 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if initialization is successful, otherwise returns an error.
     pub fn init_for_analyse_page(&self) -> Result<()> {
+        self.set_recognized(false);
         let handle = self
             .handle
             .lock()
@@ -789,6 +933,7 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if reading the configuration file is successful, otherwise returns an error.
     pub fn read_config_file(&self, filename: &str) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
         let filename = CString::new(filename).unwrap();
         let handle = self
             .handle
@@ -847,6 +992,9 @@ impl TesseractAPI {
         retry_config: Option<&str>,
         timeout_millisec: i32,
     ) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
+        let path = filename;
         let filename = CString::new(filename).unwrap();
         let retry_config_cstring = retry_config.map(|s| CString::new(s).unwrap());
         let retry_config_ptr = retry_config_cstring
@@ -868,12 +1016,189 @@ impl TesseractAPI {
         // TessBaseAPIProcessPages returns BOOL (0 = failure); the recognized
         // text is fetched separately via GetUTF8Text.
         if result == 0 {
-            return Err(TesseractError::ProcessPagesError);
+            return Err(self.engine_error(TesseractError::ProcessPagesError {
+                path: path.to_owned(),
+                page: None,
+            }));
         }
         drop(handle);
         self.get_utf8_text()
     }
 
+    /// Processes every page of a file, writing the results with a renderer.
+    ///
+    /// `filename` may be an image, a multi-page TIFF or a text file listing
+    /// one image path per line. Pages that cannot be read or recognized are
+    /// reported and skipped; the remaining pages are still processed. The
+    /// document is ended even if processing stops with an error, so the
+    /// renderer's output stays well-formed.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - Name of the file to process.
+    /// * `renderer` - Renderer (or chain of renderers) receiving the pages.
+    /// * `timeout_millisec` - Timeout per page in milliseconds, `0` for none.
+    ///
+    /// # Returns
+    ///
+    /// Returns the outcome of every page, or `ProcessPagesError` if the file
+    /// could not be opened or the output document could not be written.
+    pub fn process_pages_with_renderer(
+        &self,
+        filename: &str,
+        renderer: &TessResultRenderer,
+        timeout_millisec: i32,
+    ) -> Result<Vec<PageResult>> {
+        let error = |page| TesseractError::ProcessPagesError {
+            path: filename.to_owned(),
+            page,
+        };
+        let pages = PageReader::open(filename)?;
+        if !renderer.begin_document(filename)? {
+            return Err(error(None));
+        }
+        let mut results = Vec::new();
+        let processed: Result<()> = pages.enumerate().try_for_each(|(page, (source, pix))| {
+            let result = match pix {
+                Ok(pix) => {
+                    self.process_page(&pix, page, &source, None, timeout_millisec, Some(renderer))?
+                }
+                Err(_) => unreadable_page(page, source),
+            };
+            results.push(result);
+            Ok(())
+        });
+        let ended = renderer.end_document();
+        processed?;
+        if !ended? {
+            return Err(error(None));
+        }
+        Ok(results)
+    }
+
+    /// Processes every page of a file, writing the results with a Rust
+    /// [`Renderer`].
+    ///
+    /// Accepts the same inputs as
+    /// [`process_pages_with_renderer`](Self::process_pages_with_renderer).
+    /// Each recognized page is read into a [`Page`](crate::Page) and passed
+    /// to [`Renderer::add_page`]; pages that cannot be read or recognized are
+    /// reported and skipped. [`Renderer::end_document`] is called even if
+    /// processing stops with an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - Name of the file to process.
+    /// * `renderer` - Renderer receiving the pages.
+    /// * `timeout_millisec` - Timeout per page in milliseconds, `0` for none.
+    ///
+    /// # Returns
+    ///
+    /// Returns the outcome of every page, or an error if the input could not
+    /// be opened or the renderer failed.
+    pub fn render_pages<R: Renderer>(
+        &self,
+        filename: &str,
+        mut renderer: R,
+        timeout_millisec: i32,
+    ) -> Result<Vec<PageResult>> {
+        let pages = PageReader::open(filename)?;
+        renderer.begin_document(filename)?;
+        let mut results = Vec::new();
+        let processed: Result<()> = pages.enumerate().try_for_each(|(page, (source, pix))| {
+            let pix = match pix {
+                Ok(pix) => pix,
+                Err(_) => {
+                    results.push(unreadable_page(page, source));
+                    return Ok(());
+                }
+            };
+            let result = self.process_page(&pix, page, &source, None, timeout_millisec, None)?;
+            if result.succeeded {
+                for page in &self.recognize_document()?.pages {
+                    renderer.add_page(page)?;
+                }
+            }
+            results.push(result);
+            Ok(())
+        });
+        let ended = renderer.end_document();
+        processed?;
+        ended?;
+        Ok(results)
+    }
+
+    /// Recognizes one page and optionally passes it to a renderer.
+    ///
+    /// # Arguments
+    ///
+    /// * `pix` - Image of the page.
+    /// * `page_index` - Zero-based index of the page in its document.
+    /// * `filename` - Name of the file the page was read from.
+    /// * `retry_config` - Config file applied and retried with if recognition fails.
+    /// * `timeout_millisec` - Timeout in milliseconds, `0` for none.
+    /// * `renderer` - Renderer receiving the page. Its document must have been begun.
+    ///
+    /// # Returns
+    ///
+    /// Returns the outcome of the page; the results stay available through
+    /// `get_utf8_text()` and the iterators.
+    pub fn process_page(
+        &self,
+        pix: &Pix,
+        page_index: usize,
+        filename: &str,
+        retry_config: Option<&str>,
+        timeout_millisec: i32,
+        renderer: Option<&TessResultRenderer>,
+    ) -> Result<PageResult> {
+        let _diagnostics = self.diagnostics_scope();
+        let filename_cstring =
+            CString::new(filename).map_err(|_| TesseractError::InvalidParameterError)?;
+        let retry_config_cstring = retry_config
+            .map(CString::new)
+            .transpose()
+            .map_err(|_| TesseractError::InvalidParameterError)?;
+        let retry_config_ptr = retry_config_cstring
+            .as_ref()
+            .map_or(std::ptr::null(), |rc| rc.as_ptr());
+        let page_number =
+            c_int::try_from(page_index).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let renderer_handle = renderer
+            .map(|renderer| renderer.handle.lock())
+            .transpose()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let start = Instant::now();
+        let result = unsafe {
+            TessBaseAPIProcessPage(
+                *handle,
+                pix.as_ptr(),
+                page_number,
+                filename_cstring.as_ptr(),
+                retry_config_ptr,
+                timeout_millisec,
+                renderer_handle
+                    .as_deref()
+                    .copied()
+                    .unwrap_or(std::ptr::null_mut()),
+            )
+        };
+        self.set_recognized(result != 0);
+        if result == 0 {
+            self.mark_failed();
+        }
+        Ok(PageResult {
+            page: page_index,
+            source: filename.to_owned(),
+            succeeded: result != 0,
+            elapsed: start.elapsed(),
+        })
+    }
+
     /// Gets the initial languages as a string.
     ///
     /// # Returns
@@ -969,6 +1294,7 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if clearing the OCR engine is successful, otherwise returns an error.
     pub fn clear(&self) -> Result<()> {
+        self.set_recognized(false);
         let handle = self
             .handle
             .lock()
@@ -983,6 +1309,7 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if ending the OCR engine is successful, otherwise returns an error.
     pub fn end(&self) -> Result<()> {
+        self.set_recognized(false);
         let handle = self
             .handle
             .lock()
@@ -1039,30 +1366,45 @@ impl TesseractAPI {
     /// # Returns
     ///
     /// Returns `Ok(())` if initializing the OCR engine is successful, otherwise returns an error.
-    pub fn init_1(&self, datapath: &str, language: &str, oem: i32, configs: &[&str]) -> Result<()> {
-        let datapath = CString::new(datapath).unwrap();
-        let language = CString::new(language).unwrap();
+    /// Returns `UnsupportedEngineMode` if the traineddata lacks the engine `oem` needs.
+    pub fn init_1(
+        &self,
+        datapath: &str,
+        language: &str,
+        oem: OcrEngineMode,
+        configs: &[&str],
+    ) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
+        check_languages(datapath, language)?;
+        check_engine_mode(datapath, language, oem)?;
+        let datapath_c = CString::new(datapath).unwrap();
+        let language_c = CString::new(language).unwrap();
         let config_ptrs: Vec<_> = configs.iter().map(|&s| CString::new(s).unwrap()).collect();
         let config_ptr_ptrs: Vec<_> = config_ptrs.iter().map(|cs| cs.as_ptr()).collect();
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
-        let result = unsafe {
-            TessBaseAPIInit1(
-                *handle,
-                datapath.as_ptr(),
-                language.as_ptr(),
-                oem,
-                config_ptr_ptrs.as_ptr(),
-                config_ptrs.len() as c_int,
-            )
+        let result = {
+            let handle = self
+                .handle
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            unsafe {
+                TessBaseAPIInit1(
+                    *handle,
+                    datapath_c.as_ptr(),
+                    language_c.as_ptr(),
+                    oem as c_int,
+                    config_ptr_ptrs.as_ptr(),
+                    config_ptrs.len() as c_int,
+                )
+            }
         };
         if result != 0 {
-            Err(TesseractError::InitError)
-        } else {
-            Ok(())
+            return Err(self.engine_error(TesseractError::InitError {
+                datapath: datapath.to_owned(),
+                language: language.to_owned(),
+            }));
         }
+        self.record_init(datapath, language, oem, configs, None)
     }
 
     /// Initializes the OCR engine.
@@ -1076,20 +1418,35 @@ impl TesseractAPI {
     /// # Returns
     ///
     /// Returns `Ok(())` if initializing the OCR engine is successful, otherwise returns an error.
-    pub fn init_2(&self, datapath: &str, language: &str, oem: i32) -> Result<()> {
-        let datapath = CString::new(datapath).unwrap();
-        let language = CString::new(language).unwrap();
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
-        let result =
-            unsafe { TessBaseAPIInit2(*handle, datapath.as_ptr(), language.as_ptr(), oem) };
+    /// Returns `UnsupportedEngineMode` if the traineddata lacks the engine `oem` needs.
+    pub fn init_2(&self, datapath: &str, language: &str, oem: OcrEngineMode) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
+        check_languages(datapath, language)?;
+        check_engine_mode(datapath, language, oem)?;
+        let datapath_c = CString::new(datapath).unwrap();
+        let language_c = CString::new(language).unwrap();
+        let result = {
+            let handle = self
+                .handle
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            unsafe {
+                TessBaseAPIInit2(
+                    *handle,
+                    datapath_c.as_ptr(),
+                    language_c.as_ptr(),
+                    oem as c_int,
+                )
+            }
+        };
         if result != 0 {
-            Err(TesseractError::InitError)
-        } else {
-            Ok(())
+            return Err(self.engine_error(TesseractError::InitError {
+                datapath: datapath.to_owned(),
+                language: language.to_owned(),
+            }));
         }
+        self.record_init(datapath, language, oem, &[], None)
     }
 
     /// Initializes the OCR engine.
@@ -1104,34 +1461,49 @@ impl TesseractAPI {
     /// # Returns
     ///
     /// Returns `Ok(())` if initializing the OCR engine is successful, otherwise returns an error.
-    pub fn init_4(&self, datapath: &str, language: &str, oem: i32, configs: &[&str]) -> Result<()> {
-        let datapath = CString::new(datapath).unwrap();
-        let language = CString::new(language).unwrap();
+    /// Returns `UnsupportedEngineMode` if the traineddata lacks the engine `oem` needs.
+    pub fn init_4(
+        &self,
+        datapath: &str,
+        language: &str,
+        oem: OcrEngineMode,
+        configs: &[&str],
+    ) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
+        check_languages(datapath, language)?;
+        check_engine_mode(datapath, language, oem)?;
+        let datapath_c = CString::new(datapath).unwrap();
+        let language_c = CString::new(language).unwrap();
         let config_ptrs: Vec<_> = configs.iter().map(|&s| CString::new(s).unwrap()).collect();
         let config_ptr_ptrs: Vec<_> = config_ptrs.iter().map(|cs| cs.as_ptr()).collect();
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
-        let result = unsafe {
-            TessBaseAPIInit4(
-                *handle,
-                datapath.as_ptr(),
-                language.as_ptr(),
-                oem,
-                config_ptr_ptrs.as_ptr(),
-                config_ptrs.len() as c_int,
-                std::ptr::null(),
-                std::ptr::null(),
-                0,
-                0,
-            )
+        let result = {
+            let handle = self
+                .handle
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            unsafe {
+                TessBaseAPIInit4(
+                    *handle,
+                    datapath_c.as_ptr(),
+                    language_c.as_ptr(),
+                    oem as c_int,
+                    config_ptr_ptrs.as_ptr(),
+                    config_ptrs.len() as c_int,
+                    std::ptr::null(),
+                    std::ptr::null(),
+                    0,
+                    0,
+                )
+            }
         };
         if result != 0 {
-            Err(TesseractError::InitError)
-        } else {
-            Ok(())
+            return Err(self.engine_error(TesseractError::InitError {
+                datapath: datapath.to_owned(),
+                language: language.to_owned(),
+            }));
         }
+        self.record_init(datapath, language, oem, configs, None)
     }
 
     /// Initializes the OCR engine.
@@ -1147,41 +1519,87 @@ impl TesseractAPI {
     /// # Returns
     ///
     /// Returns `Ok(())` if initializing the OCR engine is successful, otherwise returns an error.
+    /// Returns `UnsupportedEngineMode` if the traineddata lacks the engine `oem` needs,
+    /// and `InvalidParameterError` if `data_size` is negative or larger than `data`.
     pub fn init_5(
         &self,
         data: &[u8],
         data_size: i32,
         language: &str,
-        oem: i32,
+        oem: OcrEngineMode,
         configs: &[&str],
     ) -> Result<()> {
-        let language = CString::new(language).unwrap();
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
+        // Only the first `data_size` bytes reach Tesseract, so only they are checked.
+        let data = usize::try_from(data_size)
+            .ok()
+            .and_then(|size| data.get(..size))
+            .ok_or(TesseractError::InvalidParameterError)?;
+        if let Some(components) = EngineComponents::from_bytes(data) {
+            if !components.supports(oem) {
+                return Err(TesseractError::UnsupportedEngineMode {
+                    language: language.to_owned(),
+                    oem,
+                });
+            }
+        }
+        let language_c = CString::new(language).unwrap();
         let config_ptrs: Vec<_> = configs.iter().map(|&s| CString::new(s).unwrap()).collect();
         let config_ptr_ptrs: Vec<_> = config_ptrs.iter().map(|cs| cs.as_ptr()).collect();
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
-        let result = unsafe {
-            TessBaseAPIInit5(
-                *handle,
-                data.as_ptr(),
-                data_size,
-                language.as_ptr(),
-                oem,
-                config_ptr_ptrs.as_ptr(),
-                config_ptrs.len() as c_int,
-                std::ptr::null(),
-                std::ptr::null(),
-                0,
-                0,
-            )
+        let result = {
+            let handle = self
+                .handle
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            unsafe {
+                TessBaseAPIInit5(
+                    *handle,
+                    data.as_ptr(),
+                    data_size,
+                    language_c.as_ptr(),
+                    oem as c_int,
+                    config_ptr_ptrs.as_ptr(),
+                    config_ptrs.len() as c_int,
+                    std::ptr::null(),
+                    std::ptr::null(),
+                    0,
+                    0,
+                )
+            }
         };
         if result != 0 {
-            Err(TesseractError::InitError)
-        } else {
-            Ok(())
+            return Err(self.engine_error(TesseractError::InitError {
+                datapath: String::new(),
+                language: language.to_owned(),
+            }));
+        }
+        self.record_init("", language, oem, configs, Some(data.into()))
+    }
+
+    /// Gets the OCR engine mode in use.
+    ///
+    /// When the engine was initialized with `OcrEngineMode::Default`, this
+    /// reports the mode Tesseract picked for the loaded traineddata, if any.
+    ///
+    /// # Returns
+    ///
+    /// Returns the OCR engine mode.
+    pub fn oem(&self) -> Result<OcrEngineMode> {
+        let requested = {
+            let handle = self
+                .handle
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            OcrEngineMode::from_int(unsafe { TessBaseAPIOem(*handle) })
+        };
+        if requested != OcrEngineMode::Default {
+            return Ok(requested);
         }
+        Ok(self
+            .get_int_variable("tessedit_ocr_engine_mode")
+            .map(OcrEngineMode::from_int)
+            .unwrap_or(requested))
     }
 
     /// Sets the image for OCR processing.
@@ -1201,6 +1619,8 @@ impl TesseractAPI {
         bytes_per_pixel: i32,
         bytes_per_line: i32,
     ) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
         // Validate input parameters
         if width <= 0 || height <= 0 {
             return Err(TesseractError::InvalidDimensions);
@@ -1240,22 +1660,65 @@ impl TesseractAPI {
 
     /// Sets the image for OCR processing.
     ///
+    /// Tesseract makes its own copy of the image, so `pix` can be dropped or
+    /// reused as soon as this returns.
+    ///
     /// # Arguments
     ///
-    /// * `pix` - Pointer to the image data.
+    /// * `pix` - The image to recognize.
     ///
     /// # Returns
     ///
     /// Returns `Ok(())` if setting the image is successful, otherwise returns an error.
-    pub fn set_image_2(&self, pix: *mut c_void) -> Result<()> {
+    pub fn set_image_2(&self, pix: &Pix) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
         let handle = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
-        unsafe { TessBaseAPISetImage2(*handle, pix) };
+        unsafe { TessBaseAPISetImage2(*handle, pix.as_ptr()) };
         Ok(())
     }
 
+    /// Decodes an encoded image file held in memory and sets it for OCR processing.
+    ///
+    /// The decoded image is also set as the input image, so renderers such as
+    /// the PDF renderer can embed it. See [`Pix::read_from_memory`] for the
+    /// supported formats.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Contents of the image file (e.g. PNG, JPEG or TIFF).
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the image could be decoded and set, otherwise returns an error.
+    pub fn set_image_from_memory(&self, data: &[u8]) -> Result<()> {
+        let pix = Pix::read_from_memory(data)?;
+        self.set_image_2(&pix)
+    }
+
+    /// Reads an image file and sets it for OCR processing.
+    ///
+    /// The decoded image is also set as the input image, and the path as the
+    /// input name, as `process_pages` does for each page. See
+    /// [`Pix::read_from_file`] for the supported formats.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the image file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the image could be read and set, otherwise returns an error.
+    pub fn set_image_from_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let pix = Pix::read_from_file(path)?;
+        self.set_input_name(path.to_str().ok_or(TesseractError::InvalidParameterError)?)?;
+        self.set_image_2(&pix)
+    }
+
     /// Sets the source resolution for the image.
     ///
     /// # Arguments
@@ -1287,6 +1750,7 @@ impl TesseractAPI {
     ///
     /// Returns `Ok(())` if setting the rectangle is successful, otherwise returns an error.
     pub fn set_rectangle(&self, left: i32, top: i32, width: i32, height: i32) -> Result<()> {
+        self.set_recognized(false);
         let handle = self
             .handle
             .lock()
@@ -1301,6 +1765,7 @@ impl TesseractAPI {
     ///
     /// Returns the recognized text as a String if successful, otherwise returns an error.
     pub fn get_utf8_text(&self) -> Result<String> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
@@ -1313,7 +1778,7 @@ impl TesseractAPI {
 
         let text_ptr = unsafe { TessBaseAPIGetUTF8Text(*handle) };
         if text_ptr.is_null() {
-            return Err(TesseractError::OcrError);
+            return Err(self.engine_error(ocr_error(*handle)));
         }
 
         // Safely convert C string to Rust string
@@ -1333,6 +1798,7 @@ impl TesseractAPI {
     ///
     /// Returns the iterator for the OCR results as a `ResultIterator` if successful, otherwise returns an error.
     pub fn get_iterator(&self) -> Result<ResultIterator> {
+        let _diagnostics = self.diagnostics_scope();
         let handle = self
             .handle
             .lock()
@@ -1345,12 +1811,61 @@ impl TesseractAPI {
         }
     }
 
+    /// Builds an owned tree of the recognition results: blocks, paragraphs,
+    /// lines, words and symbols with their geometry and attributes.
+    ///
+    /// Recognizes the image first if that has not been done yet. The whole
+    /// tree is read in one pass while the engine is locked.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Document` with one page if successful, otherwise returns an error.
+    pub fn recognize_document(&self) -> Result<Document> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        if (*handle).is_null() {
+            return Err(TesseractError::UninitializedError);
+        }
+        // After `analyse_layout` the engine has results without any text, so
+        // an iterator alone does not mean the image was recognized.
+        let mut iterator = if self.is_recognized() {
+            unsafe { TessBaseAPIGetIterator(*handle) }
+        } else {
+            std::ptr::null_mut()
+        };
+        if iterator.is_null() {
+            let result = unsafe { TessBaseAPIRecognize(*handle, std::ptr::null_mut()) };
+            self.set_recognized(result == 0);
+            if result != 0 {
+                return Err(self.engine_error(ocr_error(*handle)));
+            }
+            iterator = unsafe { TessBaseAPIGetIterator(*handle) };
+            if iterator.is_null() {
+                return Err(TesseractError::NullPointerError);
+            }
+        }
+        // Owned by the engine, not to be destroyed here
+        let image = unsafe { TessBaseAPIGetInputImage(*handle) };
+        let (width, height) = if image.is_null() {
+            (0, 0)
+        } else {
+            unsafe { (pixGetWidth(image), pixGetHeight(image)) }
+        };
+        let page = document::build_page(iterator, width, height)?;
+        Ok(Document { pages: vec![page] })
+    }
+
     /// Analyzes the layout of the image.
     ///
     /// # Returns
     ///
     /// Returns the layout of the image as a `PageIterator` if successful, otherwise returns an error.
     pub fn analyse_layout(&self) -> Result<PageIterator> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
         let handle = self
             .handle
             .lock()
@@ -1388,6 +1903,7 @@ impl TesseractAPI {
 
     /// Gets both page and result iterators for full text analysis
     pub fn get_iterators(&self) -> Result<(PageIterator, ResultIterator)> {
+        let _diagnostics = self.diagnostics_scope();
         // Perform OCR recognition first
         self.recognize()?;
 
@@ -1397,6 +1913,7 @@ impl TesseractAPI {
             .map_err(|_| TesseractError::MutexLockError)?;
 
         // Get both iterators
+        self.set_recognized(false);
         let page_iter = unsafe { TessBaseAPIAnalyseLayout(*handle) };
         let result_iter = unsafe { TessBaseAPIGetIterator(*handle) };
 
@@ -1433,17 +1950,229 @@ impl TesseractAPI {
         let new_api = TesseractAPI {
             handle: Arc::new(Mutex::new(new_handle)),
             config: Arc::new(Mutex::new(config.clone())),
+            diagnostics: Arc::new(Mutex::new(DiagnosticCapture::default())),
+            failed: AtomicBool::new(false),
+            recognized: AtomicBool::new(false),
         };
+        new_api.set_capture_diagnostics(self.captures_diagnostics()?)?;
 
-        if !config.datapath.is_empty() {
-            new_api.init(&config.datapath, &config.language)?;
-            for (name, value) in &config.variables {
-                new_api.set_variable(name, value)?;
-            }
+        if config.is_initialized() {
+            new_api.init_with_configuration(config)?;
         }
 
         Ok(new_api)
     }
+
+    /// Gets the configuration the engine was initialized with.
+    pub(crate) fn configuration(&self) -> Result<TesseractConfiguration> {
+        let config = self
+            .config
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        Ok(config.clone())
+    }
+
+    /// Records that an engine operation failed and passes the error on.
+    pub(crate) fn engine_error(&self, error: TesseractError) -> TesseractError {
+        self.mark_failed();
+        error
+    }
+
+    /// Records that an engine operation failed without returning an error.
+    fn mark_failed(&self) {
+        self.failed.store(true, Ordering::Relaxed);
+    }
+
+    /// Records a successful initialization for `try_clone`, keeping the
+    /// variables set so far.
+    fn record_init(
+        &self,
+        datapath: &str,
+        language: &str,
+        oem: OcrEngineMode,
+        config_files: &[&str],
+        traineddata: Option<Arc<[u8]>>,
+    ) -> Result<()> {
+        let mut config = self
+            .config
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        config.datapath = datapath.to_owned();
+        config.language = language.to_owned();
+        config.oem = oem;
+        config.config_files = config_files.iter().map(|&file| file.to_owned()).collect();
+        config.init_variables.clear();
+        config.traineddata = traineddata;
+        Ok(())
+    }
+
+    /// Records whether the current image has recognition results.
+    fn set_recognized(&self, recognized: bool) {
+        self.recognized.store(recognized, Ordering::Release);
+    }
+
+    /// Whether the current image has been recognized since it was set.
+    pub(crate) fn is_recognized(&self) -> bool {
+        self.recognized.load(Ordering::Acquire)
+    }
+
+    /// Whether an engine operation failed since the last call.
+    pub(crate) fn take_failed(&self) -> bool {
+        self.failed.swap(false, Ordering::Relaxed)
+    }
+
+    /// Drops the input image set with `set_input_image` or `set_image_2`.
+    pub(crate) fn clear_input_image(&self) -> Result<()> {
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        unsafe { TessBaseAPISetInputImage(*handle, std::ptr::null_mut()) };
+        Ok(())
+    }
+
+    /// Whether the engine has been initialized with a language.
+    pub(crate) fn is_initialized(&self) -> Result<bool> {
+        let config = self
+            .config
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        Ok(config.is_initialized())
+    }
+
+    /// Initializes the engine from a full configuration and records it.
+    ///
+    /// Uses `TessBaseAPIInit5` when in-memory traineddata is given and
+    /// `TessBaseAPIInit4` otherwise, so config files and init-only variables
+    /// are applied while the engine loads. Regular variables and the page
+    /// segmentation mode are set afterwards.
+    pub(crate) fn init_with_configuration(&self, config: TesseractConfiguration) -> Result<()> {
+        let _diagnostics = self.diagnostics_scope();
+        self.set_recognized(false);
+        match &config.traineddata {
+            Some(data) => {
+                if let Some(components) = EngineComponents::from_bytes(data) {
+                    if !components.supports(config.oem) {
+                        return Err(TesseractError::UnsupportedEngineMode {
+                            language: config.language.clone(),
+                            oem: config.oem,
+                        });
+                    }
+                }
+            }
+            None => {
+                check_languages(&config.datapath, &config.language)?;
+                check_engine_mode(&config.datapath, &config.language, config.oem)?;
+            }
+        }
+
+        let to_cstring =
+            |s: &str| CString::new(s).map_err(|_| TesseractError::InvalidParameterError);
+        let datapath = to_cstring(&config.datapath)?;
+        let language = to_cstring(&config.language)?;
+        let configs = config
+            .config_files
+            .iter()
+            .map(|s| to_cstring(s))
+            .collect::<Result<Vec<_>>>()?;
+        let config_ptrs: Vec<_> = configs.iter().map(|cs| cs.as_ptr()).collect();
+        let names = config
+            .init_variables
+            .iter()
+            .map(|(name, _)| to_cstring(name))
+            .collect::<Result<Vec<_>>>()?;
+        let values = config
+            .init_variables
+            .iter()
+            .map(|(_, value)| to_cstring(value))
+            .collect::<Result<Vec<_>>>()?;
+        let name_ptrs: Vec<_> = names.iter().map(|cs| cs.as_ptr()).collect();
+        let value_ptrs: Vec<_> = values.iter().map(|cs| cs.as_ptr()).collect();
+
+        {
+            let handle = self
+                .handle
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            let result = unsafe {
+                match &config.traineddata {
+                    Some(data) => TessBaseAPIInit5(
+                        *handle,
+                        data.as_ptr(),
+                        data.len() as c_int,
+                        language.as_ptr(),
+                        config.oem as c_int,
+                        config_ptrs.as_ptr(),
+                        config_ptrs.len() as c_int,
+                        name_ptrs.as_ptr(),
+                        value_ptrs.as_ptr(),
+                        name_ptrs.len(),
+                        0,
+                    ),
+                    None => TessBaseAPIInit4(
+                        *handle,
+                        datapath.as_ptr(),
+                        language.as_ptr(),
+                        config.oem as c_int,
+                        config_ptrs.as_ptr(),
+                        config_ptrs.len() as c_int,
+                        name_ptrs.as_ptr(),
+                        value_ptrs.as_ptr(),
+                        name_ptrs.len(),
+                        0,
+                    ),
+                }
+            };
+            if result != 0 {
+                return Err(self.engine_error(TesseractError::InitError {
+                    datapath: config.datapath,
+                    language: config.language,
+                }));
+            }
+        }
+
+        {
+            let mut recorded = self
+                .config
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            // `set_variable` records the variables again as they are applied
+            *recorded = TesseractConfiguration {
+                variables: Vec::new(),
+                ..config.clone()
+            };
+        }
+        for (name, value) in &config.variables {
+            self.set_variable(name, value)?;
+        }
+        if let Some(psm) = config.psm {
+            self.set_page_seg_mode(psm)?;
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of a page that could not be read.
+fn unreadable_page(page: usize, source: String) -> PageResult {
+    PageResult {
+        page,
+        source,
+        succeeded: false,
+        elapsed: Duration::ZERO,
+    }
+}
+
+/// Builds an `OcrError` naming the image the engine was working on.
+#[cfg(feature = "build-tesseract")]
+pub(crate) fn ocr_error(handle: *mut c_void) -> TesseractError {
+    let name_ptr = unsafe { TessBaseAPIGetInputName(handle) };
+    let input_name = if name_ptr.is_null() {
+        None
+    } else {
+        let name = unsafe { CStr::from_ptr(name_ptr) }.to_string_lossy();
+        (!name.is_empty()).then(|| name.into_owned())
+    };
+    TesseractError::OcrError { input_name }
 }
 
 #[cfg(feature = "build-tesseract")]
@@ -1465,7 +2194,7 @@ impl Drop for TesseractAPI {
 #[link(name = "tesseract")]
 extern "C" {
     fn TessBaseAPIMeanTextConf(handle: *mut c_void) -> c_int;
-    fn TessBaseAPISetVariable(
+    pub fn TessBaseAPISetVariable(
         handle: *mut c_void,
         name: *const c_char,
         value: *const c_char,
@@ -1488,7 +2217,7 @@ extern "C" {
     ) -> c_int;
     fn TessBaseAPISetPageSegMode(handle: *mut c_void, mode: c_int);
     fn TessBaseAPIGetPageSegMode(handle: *mut c_void) -> c_int;
-    fn TessBaseAPIRecognize(handle: *mut c_void, monitor: *mut c_void) -> c_int;
+    pub fn TessBaseAPIRecognize(handle: *mut c_void, monitor: *mut c_void) -> c_int;
     fn TessBaseAPIGetHOCRText(handle: *mut c_void, page: c_int) -> *mut c_char;
 
     fn TessBaseAPIGetAltoText(handle: *mut c_void, page: c_int) -> *mut c_char;
@@ -1508,9 +2237,9 @@ extern "C" {
         script_conf: *mut c_float,
     ) -> c_int;
     fn TessBaseAPISetMinOrientationMargin(handle: *mut c_void, margin: c_double);
-    fn TessDeleteIntArray(arr: *const c_int);
+    pub fn TessDeleteIntArray(arr: *const c_int);
     fn TessBaseAPISetInputImage(handle: *mut c_void, pix: *mut c_void);
-    fn TessBaseAPIGetInputImage(handle: *mut c_void) -> *mut c_void;
+    pub fn TessBaseAPIGetInputImage(handle: *mut c_void) -> *mut c_void;
     fn TessBaseAPISetOutputName(handle: *mut c_void, name: *const c_char);
     fn TessBaseAPISetDebugVariable(
         handle: *mut c_void,
@@ -1530,8 +2259,8 @@ extern "C" {
     fn TessDeleteTextArray(arr: *mut *mut c_char);
 
     fn TessVersion() -> *const c_char;
-    fn TessBaseAPICreate() -> *mut c_void;
-    fn TessBaseAPIDelete(handle: *mut c_void);
+    pub fn TessBaseAPICreate() -> *mut c_void;
+    pub fn TessBaseAPIDelete(handle: *mut c_void);
     fn TessBaseAPIInit3(
         handle: *mut c_void,
         datapath: *const c_char,
@@ -1614,24 +2343,25 @@ extern "C" {
         renderer: *mut c_void,
     ) -> c_int;
 
+    fn TessBaseAPIProcessPage(
+        handle: *mut c_void,
+        pix: *mut c_void,
+        page_index: c_int,
+        filename: *const c_char,
+        retry_config: *const c_char,
+        timeout_millisec: c_int,
+        renderer: *mut c_void,
+    ) -> c_int;
+
     fn TessBaseAPIGetInputName(handle: *mut c_void) -> *const c_char;
     fn TessBaseAPISetInputName(handle: *mut c_void, name: *const c_char);
     fn TessBaseAPIGetSourceYResolution(handle: *mut c_void) -> c_int;
     fn TessBaseAPIGetDatapath(handle: *mut c_void) -> *const c_char;
     fn TessBaseAPIGetThresholdedImage(handle: *mut c_void) -> *mut c_void;
+    fn TessBaseAPIOem(handle: *mut c_void) -> c_int;
 
     // unimplemented functions
     /*
-    fn TessHOcrRendererCreate2(outputbase: *const c_char, font_info: c_int) -> *mut c_void;
-    fn TessAltoRendererCreate(outputbase: *const c_char) -> *mut c_void;
-    fn TessPAGERendererCreate(outputbase: *const c_char) -> *mut c_void;
-    fn TessTsvRendererCreate(outputbase: *const c_char) -> *mut c_void;
-    fn TessUnlvRendererCreate(outputbase: *const c_char) -> *mut c_void;
-    fn TessWordStrBoxRendererCreate(outputbase: *const c_char) -> *mut c_void;
-    fn TessLSTMBoxRendererCreate(outputbase: *const c_char) -> *mut c_void;
-
-    fn TessResultRendererInsert(renderer: *mut c_void, next: *mut c_void);
-    fn TessResultRendererNext(renderer: *mut c_void) -> *mut c_void;
     fn TessBaseAPIPrintVariables(handle: *mut c_void, fp: *mut c_void);
     fn TessBaseAPIRect(
         handle: *mut c_void,
@@ -1644,72 +2374,7 @@ extern "C" {
         height: c_int,
     ) -> *mut c_char;
     fn TessBaseAPIGetGradient(handle: *mut c_void) -> c_float;
-    fn TessBaseAPIGetRegions(handle: *mut c_void, pixa: *mut *mut c_void) -> *mut c_void;
-    fn TessBaseAPIGetTextlines(
-        handle: *mut c_void,
-        pixa: *mut *mut c_void,
-        blockids: *mut *mut c_int,
-    ) -> *mut c_void;
-    fn TessBaseAPIGetTextlines1(
-        handle: *mut c_void,
-        raw_image: c_int,
-        raw_padding: c_int,
-        pixa: *mut *mut c_void,
-        blockids: *mut *mut c_int,
-        paraids: *mut *mut c_int,
-    ) -> *mut c_void;
-    fn TessBaseAPIGetStrips(
-        handle: *mut c_void,
-        pixa: *mut *mut c_void,
-        blockids: *mut *mut c_int,
-    ) -> *mut c_void;
-    fn TessBaseAPIGetWords(handle: *mut c_void, pixa: *mut *mut c_void) -> *mut c_void;
-    fn TessBaseAPIGetConnectedComponents(
-        handle: *mut c_void,
-        pixa: *mut *mut c_void,
-    ) -> *mut c_void;
-    fn TessBaseAPIGetComponentImages(
-        handle: *mut c_void,
-        level: c_int,
-        text_only: c_int,
-        pixa: *mut *mut c_void,
-        blockids: *mut *mut c_int,
-    ) -> *mut c_void;
-    fn TessBaseAPIGetComponentImages1(
-        handle: *mut c_void,
-        level: c_int,
-        text_only: c_int,
-        raw_image: c_int,
-        raw_padding: c_int,
-        pixa: *mut *mut c_void,
-        blockids: *mut *mut c_int,
-        paraids: *mut *mut c_int,
-    ) -> *mut c_void;
-    fn TessBaseAPIOem(handle: *mut c_void) -> c_int;
-    fn TessPageIteratorCopy(handle: *mut c_void) -> *mut c_void;
-    fn TessPageIteratorGetBinaryImage(handle: *mut c_void, level: c_int) -> *mut c_void;
-    fn TessPageIteratorGetImage(
-        handle: *mut c_void,
-        level: c_int,
-        padding: c_int,
-        original_image: *mut c_void,
-        left: *mut c_int,
-        top: *mut c_int,
-    ) -> *mut c_void;
-    fn TessPageIteratorParagraphInfo(
-        handle: *mut c_void,
-        justification: *mut c_int,
-        is_list_item: *mut bool,
-        is_crown: *mut bool,
-        first_line_indent: *mut c_int,
-    ) -> c_int;
-    fn TessResultIteratorCopy(handle: *mut c_void) -> *mut c_void;
-    fn TessResultIteratorGetPageIterator(handle: *mut c_void) -> *mut c_void;
     fn TessResultIteratorGetPageIteratorConst(handle: *mut c_void) -> *const c_void;
     fn TessResultIteratorGetChoiceIterator(handle: *mut c_void) -> *mut c_void;
-    fn TessMonitorSetCancelFunc(monitor: *mut c_void, cancel_func: *mut c_void);
-    fn TessMonitorSetCancelThis(monitor: *mut c_void, cancel_this: *mut c_void);
-    fn TessMonitorGetCancelThis(monitor: *mut c_void) -> *mut c_void;
-    fn TessMonitorSetProgressFunc(monitor: *mut c_void, progress_func: *mut c_void);
     */
 }
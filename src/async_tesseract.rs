@@ -0,0 +1,331 @@
+//! `async` wrapper around [`TesseractAPI`] for tokio applications.
+
+use crate::enums::TessPageSegMode;
+use crate::error::{Result, TesseractError};
+use crate::{CancellationToken, TessMonitor, TesseractAPI};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Asynchronous handle to a Tesseract engine.
+///
+/// Every call runs on tokio's blocking thread pool, so the engine never
+/// blocks the async executor. Calls wait for a permit from a semaphore first:
+/// by default each engine has its own single permit, which queues concurrent
+/// calls without tying up blocking threads; [`AsyncTesseract::with_limit`]
+/// shares one semaphore between several engines to bound the total number
+/// of recognitions running at once.
+///
+/// Recognition is cancelled when its future is dropped, e.g. by
+/// `tokio::time::timeout` or when a request handler is aborted. Tesseract
+/// notices the cancellation after the word it is working on.
+///
+/// Clones share the same engine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # async fn example() -> tesseract_rs::Result<()> {
+/// use tesseract_rs::{AsyncTesseract, TesseractAPI};
+///
+/// let api = TesseractAPI::builder()
+///     .datapath("/usr/share/tesseract-ocr/5/tessdata")
+///     .build()?;
+/// let ocr = AsyncTesseract::new(api);
+/// ocr.set_image_from_file("scan.png").await?;
+/// let text = ocr.get_utf8_text().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AsyncTesseract {
+    api: Arc<TesseractAPI>,
+    permits: Arc<Semaphore>,
+}
+
+/// Cancels a recognition whose future was dropped before it finished.
+struct CancelOnDrop(CancellationToken);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+impl AsyncTesseract {
+    /// Wraps an engine, allowing one call at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - Engine to run the calls on.
+    ///
+    /// # Returns
+    ///
+    /// Returns the asynchronous handle.
+    pub fn new(api: TesseractAPI) -> Self {
+        Self::with_limit(api, Arc::new(Semaphore::new(1)))
+    }
+
+    /// Wraps an engine whose calls take a permit from a shared semaphore.
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - Engine to run the calls on.
+    /// * `limit` - Semaphore bounding the calls running at once.
+    ///
+    /// # Returns
+    ///
+    /// Returns the asynchronous handle.
+    pub fn with_limit(api: TesseractAPI, limit: Arc<Semaphore>) -> Self {
+        AsyncTesseract {
+            api: Arc::new(api),
+            permits: limit,
+        }
+    }
+
+    /// Runs a closure on the engine on the blocking thread pool.
+    ///
+    /// Use this for the `TesseractAPI` methods that have no `async`
+    /// counterpart here.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure receiving the engine.
+    ///
+    /// # Returns
+    ///
+    /// Returns the result of the closure, or `Cancelled` if the semaphore was
+    /// closed or the runtime shut down before the closure ran.
+    pub async fn run<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&TesseractAPI) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = Arc::clone(&self.permits)
+            .acquire_owned()
+            .await
+            .map_err(|_| TesseractError::Cancelled)?;
+        let api = Arc::clone(&self.api);
+        let task = tokio::task::spawn_blocking(move || {
+            // Held until the engine is done, even if the caller went away
+            let _permit = permit;
+            f(&api)
+        });
+        match task.await {
+            Ok(result) => result,
+            Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+            Err(_) => Err(TesseractError::Cancelled),
+        }
+    }
+
+    /// Runs a closure with a monitor that is cancelled if the returned
+    /// future is dropped.
+    async fn run_monitored<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&TesseractAPI, &TessMonitor) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let token = CancellationToken::new();
+        let _cancel_on_drop = CancelOnDrop(token.clone());
+        self.run(move |api| {
+            let monitor = TessMonitor::new();
+            monitor.set_cancellation_token(token)?;
+            f(api, &monitor)
+        })
+        .await
+    }
+
+    /// Runs a closure producing results, recognizing the image first (and
+    /// cancellably) if that has not been done yet.
+    async fn run_recognized<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&TesseractAPI) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.run_monitored(move |api, monitor| {
+            // Layout analysis leaves results behind too, but without text
+            if !api.is_recognized() {
+                api.recognize_with_monitor(monitor)?;
+            }
+            f(api)
+        })
+        .await
+    }
+
+    /// Initializes the engine with the specified datapath and language.
+    ///
+    /// # Arguments
+    ///
+    /// * `datapath` - Path to the directory containing Tesseract data files.
+    /// * `language` - Language code (e.g., "eng" for English, "tur" for Turkish).
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if initialization is successful, otherwise returns an error.
+    pub async fn init<P: AsRef<Path>>(&self, datapath: P, language: &str) -> Result<()> {
+        let datapath = datapath.as_ref().to_path_buf();
+        let language = language.to_owned();
+        self.run(move |api| api.init(datapath, &language)).await
+    }
+
+    /// Sets the image for OCR processing.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_data` - Raw image data.
+    /// * `width` - Width of the image.
+    /// * `height` - Height of the image.
+    /// * `bytes_per_pixel` - Number of bytes per pixel (e.g., 3 for RGB, 1 for grayscale).
+    /// * `bytes_per_line` - Number of bytes per line.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if setting the image is successful, otherwise returns an error.
+    pub async fn set_image(
+        &self,
+        image_data: Vec<u8>,
+        width: i32,
+        height: i32,
+        bytes_per_pixel: i32,
+        bytes_per_line: i32,
+    ) -> Result<()> {
+        self.run(move |api| {
+            api.set_image(&image_data, width, height, bytes_per_pixel, bytes_per_line)
+        })
+        .await
+    }
+
+    /// Decodes an encoded image (PNG, JPEG, TIFF, ...) and sets it for OCR.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Contents of the image file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the image was decoded and set, otherwise returns an error.
+    pub async fn set_image_from_memory(&self, data: Vec<u8>) -> Result<()> {
+        self.run(move |api| api.set_image_from_memory(&data)).await
+    }
+
+    /// Reads and decodes an image file and sets it for OCR.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the image file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the image was read, decoded and set, otherwise returns an error.
+    pub async fn set_image_from_file<P: Into<PathBuf>>(&self, path: P) -> Result<()> {
+        let path = path.into();
+        self.run(move |api| api.set_image_from_file(path)).await
+    }
+
+    /// Sets a Tesseract variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the variable.
+    /// * `value` - Value to set.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if setting the variable is successful, otherwise returns an error.
+    pub async fn set_variable(&self, name: &str, value: &str) -> Result<()> {
+        let name = name.to_owned();
+        let value = value.to_owned();
+        self.run(move |api| api.set_variable(&name, &value)).await
+    }
+
+    /// Sets the page segmentation mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Page segmentation mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if setting the page segmentation mode is successful, otherwise returns an error.
+    pub async fn set_page_seg_mode(&self, mode: TessPageSegMode) -> Result<()> {
+        self.run(move |api| api.set_page_seg_mode(mode)).await
+    }
+
+    /// Performs OCR recognition, cancelling it if the future is dropped.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if recognition is successful, otherwise returns an error.
+    pub async fn recognize(&self) -> Result<()> {
+        self.run_monitored(|api, monitor| api.recognize_with_monitor(monitor))
+            .await
+    }
+
+    /// Gets the recognized text, recognizing the image first if needed.
+    ///
+    /// # Returns
+    ///
+    /// Returns the recognized text as a UTF-8 string.
+    pub async fn get_utf8_text(&self) -> Result<String> {
+        self.run_recognized(|api| api.get_utf8_text()).await
+    }
+
+    /// Gets the HOCR text for the specified page, recognizing the image first if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Page number.
+    ///
+    /// # Returns
+    ///
+    /// Returns the HOCR text for the specified page as a string.
+    pub async fn get_hocr_text(&self, page: i32) -> Result<String> {
+        self.run_recognized(move |api| api.get_hocr_text(page))
+            .await
+    }
+
+    /// Gets the ALTO text for the specified page, recognizing the image first if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Page number.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ALTO text for the specified page as a string.
+    pub async fn get_alto_text(&self, page: i32) -> Result<String> {
+        self.run_recognized(move |api| api.get_alto_text(page))
+            .await
+    }
+
+    /// Gets the TSV text for the specified page, recognizing the image first if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Page number.
+    ///
+    /// # Returns
+    ///
+    /// Returns the TSV text for the specified page as a string.
+    pub async fn get_tsv_text(&self, page: i32) -> Result<String> {
+        self.run_recognized(move |api| api.get_tsv_text(page)).await
+    }
+
+    /// Gets the mean text confidence, recognizing the image first if needed.
+    ///
+    /// # Returns
+    ///
+    /// Returns the mean text confidence as an integer.
+    pub async fn mean_text_conf(&self) -> Result<i32> {
+        self.run_recognized(|api| api.mean_text_conf()).await
+    }
+
+    /// Gets the confidence of each recognized word, recognizing the image first if needed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of confidence values (0-100) for each recognized word.
+    pub async fn all_word_confidences(&self) -> Result<Vec<i32>> {
+        self.run_recognized(|api| api.all_word_confidences()).await
+    }
+}
@@ -0,0 +1,209 @@
+use crate::api::TesseractConfiguration;
+use crate::enums::{OcrEngineMode, TessPageSegMode};
+use crate::error::{Result, TesseractError};
+use crate::TesseractAPI;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Builder for an initialized [`TesseractAPI`].
+///
+/// Collects everything Tesseract needs at initialization time — datapath,
+/// languages, engine mode, config files and init-only variables — and
+/// applies it in a single `TessBaseAPIInit4` (or `TessBaseAPIInit5` for
+/// in-memory traineddata) call. The result is recorded so that
+/// [`TesseractAPI::try_clone`] re-creates the engine exactly.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tesseract_rs::{OcrEngineMode, TessPageSegMode, TesseractAPI};
+///
+/// let api = TesseractAPI::builder()
+///     .datapath("/usr/share/tesseract-ocr/5/tessdata")
+///     .languages(&["eng", "deu"])
+///     .oem(OcrEngineMode::LstmOnly)
+///     .psm(TessPageSegMode::PSM_SINGLE_BLOCK)
+///     .init_variable("load_system_dawg", "0")
+///     .variable("tessedit_char_whitelist", "0123456789")
+///     .build()?;
+/// # Ok::<(), tesseract_rs::TesseractError>(())
+/// ```
+#[derive(Clone)]
+pub struct TesseractBuilder {
+    datapath: PathBuf,
+    languages: Vec<String>,
+    oem: OcrEngineMode,
+    psm: Option<TessPageSegMode>,
+    config_files: Vec<String>,
+    init_variables: Vec<(String, String)>,
+    variables: Vec<(String, String)>,
+    traineddata: Option<Arc<[u8]>>,
+}
+
+impl TesseractBuilder {
+    /// Creates a builder for English with the default engine mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new builder.
+    pub fn new() -> Self {
+        TesseractBuilder {
+            datapath: PathBuf::new(),
+            languages: Vec::new(),
+            oem: OcrEngineMode::Default,
+            psm: None,
+            config_files: Vec::new(),
+            init_variables: Vec::new(),
+            variables: Vec::new(),
+            traineddata: None,
+        }
+    }
+
+    /// Sets the directory containing the `.traineddata` files.
+    ///
+    /// When not set, Tesseract falls back to `TESSDATA_PREFIX`.
+    ///
+    /// # Arguments
+    ///
+    /// * `datapath` - Path to the tessdata directory. It must be valid UTF-8;
+    ///   otherwise [`build`](Self::build) fails.
+    pub fn datapath<P: AsRef<Path>>(mut self, datapath: P) -> Self {
+        self.datapath = datapath.as_ref().to_owned();
+        self
+    }
+
+    /// Adds a language to load.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - Language code (e.g., "eng").
+    pub fn language(mut self, language: &str) -> Self {
+        self.languages.push(language.to_owned());
+        self
+    }
+
+    /// Adds several languages to load, in order of priority.
+    ///
+    /// # Arguments
+    ///
+    /// * `languages` - Language codes (e.g., `&["eng", "deu"]`).
+    pub fn languages(mut self, languages: &[&str]) -> Self {
+        self.languages
+            .extend(languages.iter().map(|&language| language.to_owned()));
+        self
+    }
+
+    /// Sets the OCR engine mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `oem` - OCR engine mode.
+    pub fn oem(mut self, oem: OcrEngineMode) -> Self {
+        self.oem = oem;
+        self
+    }
+
+    /// Sets the page segmentation mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `psm` - Page segmentation mode.
+    pub fn psm(mut self, psm: TessPageSegMode) -> Self {
+        self.psm = Some(psm);
+        self
+    }
+
+    /// Adds a Tesseract config file to read during initialization.
+    ///
+    /// Names are looked up in the `configs` directory of the datapath first,
+    /// then used as a plain path.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_file` - Name or path of the config file.
+    pub fn config_file(mut self, config_file: &str) -> Self {
+        self.config_files.push(config_file.to_owned());
+        self
+    }
+
+    /// Sets a variable while the engine is being initialized.
+    ///
+    /// Use this for init-only parameters such as `load_system_dawg`, which
+    /// have no effect when set after initialization.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the variable.
+    /// * `value` - Value of the variable.
+    pub fn init_variable(mut self, name: &str, value: &str) -> Self {
+        self.init_variables
+            .push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Sets a variable after the engine has been initialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the variable.
+    /// * `value` - Value of the variable.
+    pub fn variable(mut self, name: &str, value: &str) -> Self {
+        self.variables.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Loads the first language from in-memory traineddata instead of a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Contents of a `.traineddata` file.
+    pub fn traineddata<D: Into<Arc<[u8]>>>(mut self, data: D) -> Self {
+        self.traineddata = Some(data.into());
+        self
+    }
+
+    /// Creates and initializes the engine.
+    ///
+    /// # Returns
+    ///
+    /// Returns the initialized `TesseractAPI` if successful, otherwise returns an error.
+    pub fn build(self) -> Result<TesseractAPI> {
+        if self.languages.iter().any(|language| language.is_empty()) {
+            return Err(TesseractError::InvalidParameterError);
+        }
+        let datapath = self
+            .datapath
+            .to_str()
+            .ok_or_else(|| TesseractError::non_utf8_path(&self.datapath))?
+            .to_owned();
+        let language = if self.languages.is_empty() {
+            "eng".to_owned()
+        } else {
+            self.languages.join("+")
+        };
+        let config = TesseractConfiguration {
+            datapath,
+            language,
+            oem: self.oem,
+            psm: self.psm,
+            config_files: self.config_files,
+            init_variables: self.init_variables,
+            traineddata: self.traineddata,
+            variables: self.variables,
+        };
+        let api = TesseractAPI::new();
+        api.init_with_configuration(config)?;
+        Ok(api)
+    }
+}
+
+impl TesseractAPI {
+    /// Creates a builder for an initialized engine.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `TesseractBuilder`.
+    pub fn builder() -> TesseractBuilder {
+        TesseractBuilder::new()
+    }
+}
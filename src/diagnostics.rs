@@ -0,0 +1,453 @@
+//! Redirection of Tesseract and Leptonica diagnostic output.
+//!
+//! Tesseract writes its messages (`tprintf`) to stderr unless the global
+//! `debug_file` parameter names a file, and Leptonica writes to stderr unless
+//! a handler is registered with `leptSetStderrHandler`. The bundled Leptonica
+//! prints nothing until a handler or capture is set. Once redirection is
+//! installed, Tesseract's messages go to a file in a private temporary
+//! directory that is drained after every engine operation, and Leptonica's
+//! messages are received directly. Both are then passed to the registered
+//! handler, to the capture of the running operation, or back to stderr when
+//! nobody else wants them.
+//!
+//! Tesseract opens its debug file on the first message after `debug_file` is
+//! set and only closes it when a later message finds the name empty, so
+//! pointing it at a new file does not take effect reliably. The redirection
+//! is therefore installed once and kept for the rest of the process.
+
+use crate::api::{TessBaseAPICreate, TessBaseAPIDelete, TessBaseAPISetVariable};
+use crate::error::{Result, TesseractError};
+use crate::tempdir::PrivateDir;
+use crate::TesseractAPI;
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::os::raw::{c_char, c_int};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Library a diagnostic message comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSource {
+    Tesseract,
+    Leptonica,
+}
+
+impl fmt::Display for DiagnosticSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DiagnosticSource::Tesseract => "tesseract",
+            DiagnosticSource::Leptonica => "leptonica",
+        };
+        f.write_str(name)
+    }
+}
+
+type Handler = Arc<dyn Fn(DiagnosticSource, &str) + Send + Sync>;
+
+/// Leptonica's `L_SEVERITY_WARNING`: warnings and errors are printed.
+const L_SEVERITY_WARNING: c_int = 4;
+/// Leptonica's `L_SEVERITY_NONE`: nothing is printed.
+const L_SEVERITY_NONE: c_int = 6;
+
+/// Size after which the drained part of the redirection file is discarded.
+const MAX_DRAINED_BYTES: u64 = 1 << 20;
+
+/// Tesseract's redirection file and how far it has been read.
+struct Redirection {
+    file: File,
+    offset: u64,
+    /// Start of a line whose end has not been written yet.
+    partial: Vec<u8>,
+    /// Directory holding the file. Kept in a static, so it lasts as long as
+    /// Tesseract may write to the file.
+    _dir: PrivateDir,
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+/// Number of engines that capture their messages.
+static CAPTURING: AtomicUsize = AtomicUsize::new(0);
+static REDIRECTION: Mutex<Option<Redirection>> = Mutex::new(None);
+static HANDLER: RwLock<Option<Handler>> = RwLock::new(None);
+
+thread_local! {
+    /// Messages of the operation running on this thread, if it captures them.
+    static CAPTURE: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Per-engine capture settings and the messages of its last operation.
+#[derive(Default)]
+pub(crate) struct DiagnosticCapture {
+    enabled: bool,
+    messages: Vec<String>,
+}
+
+/// Sends every Tesseract and Leptonica diagnostic message to `handler`
+/// instead of stderr.
+///
+/// Tesseract's message output is process-wide, so the handler applies to all
+/// engines. Messages are delivered line by line, without the trailing newline.
+///
+/// # Arguments
+///
+/// * `handler` - Called with the source and text of each message.
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the output could be redirected, otherwise returns an error.
+pub fn set_diagnostic_handler<F>(handler: F) -> Result<()>
+where
+    F: Fn(DiagnosticSource, &str) + Send + Sync + 'static,
+{
+    // Set before installing, so a concurrent `release` sees the handler.
+    *HANDLER
+        .write()
+        .map_err(|_| TesseractError::MutexLockError)? = Some(Arc::new(handler));
+    install()
+}
+
+/// Removes the handler set by [`set_diagnostic_handler`].
+///
+/// Messages that are not captured by an engine are printed to stderr again.
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the handler was removed, otherwise returns an error.
+pub fn clear_diagnostic_handler() -> Result<()> {
+    drain();
+    *HANDLER
+        .write()
+        .map_err(|_| TesseractError::MutexLockError)? = None;
+    release()
+}
+
+/// Forwards diagnostic messages to the `log` facade.
+///
+/// Messages are logged with the `tesseract` or `leptonica` target; errors at
+/// `Error` level, everything else at `Warn` level, except Leptonica's
+/// informational messages which are logged at `Info` level.
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the output could be redirected, otherwise returns an error.
+#[cfg(feature = "log")]
+pub fn log_diagnostics() -> Result<()> {
+    set_diagnostic_handler(|source, message| {
+        let level = match source {
+            DiagnosticSource::Tesseract
+                if message.starts_with("Error") || message.starts_with("Failed") =>
+            {
+                log::Level::Error
+            }
+            DiagnosticSource::Tesseract => log::Level::Warn,
+            DiagnosticSource::Leptonica if message.starts_with("Error") => log::Level::Error,
+            DiagnosticSource::Leptonica if message.starts_with("Info") => log::Level::Info,
+            DiagnosticSource::Leptonica => log::Level::Warn,
+        };
+        match source {
+            DiagnosticSource::Tesseract => log::log!(target: "tesseract", level, "{}", message),
+            DiagnosticSource::Leptonica => log::log!(target: "leptonica", level, "{}", message),
+        }
+    })
+}
+
+/// Redirects Tesseract's and Leptonica's output.
+///
+/// Tesseract's output is redirected once and stays redirected for the rest
+/// of the process; messages nobody takes are printed to stderr by
+/// [`dispatch`] instead.
+fn install() -> Result<()> {
+    let mut redirection = REDIRECTION
+        .lock()
+        .map_err(|_| TesseractError::MutexLockError)?;
+    if redirection.is_none() {
+        let dir = PrivateDir::new("tesseract-rs-diagnostics")?;
+        let (path, file) = dir.create_file("tesseract.log")?;
+        set_debug_file(
+            path.to_str()
+                .ok_or_else(|| TesseractError::non_utf8_path(&path))?,
+        )?;
+        *redirection = Some(Redirection {
+            file,
+            offset: 0,
+            partial: Vec::new(),
+            _dir: dir,
+        });
+        INSTALLED.store(true, Ordering::Release);
+    }
+    unsafe {
+        leptSetStderrHandler(Some(leptonica_message));
+        setMsgSeverity(L_SEVERITY_WARNING);
+    }
+    Ok(())
+}
+
+/// Silences Leptonica again if there is no handler and no engine captures
+/// messages.
+fn release() -> Result<()> {
+    drain();
+    let _redirection = REDIRECTION
+        .lock()
+        .map_err(|_| TesseractError::MutexLockError)?;
+    // Checked under the lock: `install` runs after a handler or capture is
+    // registered, so either it is seen here or it reinstalls afterwards.
+    let handler = HANDLER
+        .read()
+        .map_err(|_| TesseractError::MutexLockError)?
+        .is_some();
+    if !handler && CAPTURING.load(Ordering::Acquire) == 0 {
+        unsafe { setMsgSeverity(L_SEVERITY_NONE) };
+    }
+    Ok(())
+}
+
+/// Sets Tesseract's global `debug_file` parameter.
+fn set_debug_file(path: &str) -> Result<()> {
+    let name = CString::new("debug_file").unwrap();
+    let value = CString::new(path).map_err(|_| TesseractError::InvalidParameterError)?;
+
+    // `debug_file` is a global parameter; any engine can set it.
+    let result = unsafe {
+        let handle = TessBaseAPICreate();
+        let result = TessBaseAPISetVariable(handle, name.as_ptr(), value.as_ptr());
+        TessBaseAPIDelete(handle);
+        result
+    };
+    if result == 0 {
+        return Err(TesseractError::SetVariableError {
+            name: "debug_file".to_owned(),
+            value: path.to_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// Reads the messages Tesseract has written since the last drain and
+/// dispatches them.
+fn drain() {
+    if !INSTALLED.load(Ordering::Acquire) {
+        return;
+    }
+    // Tesseract never flushes its debug file.
+    unsafe { libc::fflush(std::ptr::null_mut()) };
+
+    let lines = {
+        let mut guard = match REDIRECTION.lock() {
+            Ok(guard) => guard,
+            Err(_) => return,
+        };
+        let redirection = match guard.as_mut() {
+            Some(redirection) => redirection,
+            None => return,
+        };
+        let mut data = Vec::new();
+        if redirection
+            .file
+            .seek(SeekFrom::Start(redirection.offset))
+            .and_then(|_| redirection.file.read_to_end(&mut data))
+            .is_err()
+        {
+            return;
+        }
+        redirection.offset += data.len() as u64;
+        if redirection.offset > MAX_DRAINED_BYTES {
+            // Tesseract keeps writing at its own position, so truncating
+            // only turns the part already read into a hole.
+            let _ = redirection.file.set_len(0);
+        }
+
+        redirection.partial.extend(data);
+        let complete = match redirection.partial.iter().rposition(|&b| b == b'\n') {
+            Some(end) => redirection.partial.drain(..=end).collect::<Vec<_>>(),
+            None => Vec::new(),
+        };
+        String::from_utf8_lossy(&complete)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_owned)
+            .collect::<Vec<_>>()
+    };
+
+    for line in lines {
+        dispatch(DiagnosticSource::Tesseract, &line);
+    }
+}
+
+/// Passes a message to the capture of the current operation and the handler,
+/// or prints it to stderr if neither takes it.
+fn dispatch(source: DiagnosticSource, message: &str) {
+    let captured = CAPTURE.with(|capture| match capture.borrow_mut().as_mut() {
+        Some(messages) => {
+            messages.push(message.to_owned());
+            true
+        }
+        None => false,
+    });
+    let handler = HANDLER.read().ok().and_then(|handler| handler.clone());
+    match handler {
+        Some(handler) => handler(source, message),
+        None if !captured => eprintln!("{}", message),
+        None => {}
+    }
+}
+
+/// Receives Leptonica's messages.
+extern "C" fn leptonica_message(message: *const c_char) {
+    if message.is_null() {
+        return;
+    }
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    // A panicking handler cannot unwind into Leptonica; drop the message instead
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        for line in message.lines().filter(|line| !line.trim().is_empty()) {
+            dispatch(DiagnosticSource::Leptonica, line);
+        }
+    }));
+}
+
+impl Drop for DiagnosticCapture {
+    fn drop(&mut self) {
+        if self.enabled {
+            CAPTURING.fetch_sub(1, Ordering::AcqRel);
+            let _ = release();
+        }
+    }
+}
+
+/// Collects the messages of one engine operation; see
+/// [`TesseractAPI::diagnostics_scope`].
+pub(crate) struct DiagnosticsScope<'a> {
+    capture: Option<&'a Mutex<DiagnosticCapture>>,
+}
+
+impl Drop for DiagnosticsScope<'_> {
+    fn drop(&mut self) {
+        drain();
+        if let Some(capture) = self.capture {
+            let messages = CAPTURE.with(|current| current.borrow_mut().take());
+            if let Ok(mut capture) = capture.lock() {
+                capture.messages = messages.unwrap_or_default();
+            }
+        }
+    }
+}
+
+impl TesseractAPI {
+    /// Enables or disables capturing the diagnostic messages of each operation.
+    ///
+    /// While enabled, the messages Tesseract and Leptonica print during
+    /// initialization, image loading, recognition and result retrieval are
+    /// kept and can be read with [`TesseractAPI::last_diagnostics`]; they are
+    /// no longer printed to stderr. Tesseract's output is process-wide, so when
+    /// several engines run concurrently a message may be attributed to
+    /// another engine's operation. Once no engine captures messages and no
+    /// handler is set, the output goes back to stderr.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to capture messages.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if successful, otherwise returns an error.
+    pub fn set_capture_diagnostics(&self, enabled: bool) -> Result<()> {
+        let mut capture = self
+            .diagnostics
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        capture.messages.clear();
+        if capture.enabled == enabled {
+            return Ok(());
+        }
+        if enabled {
+            // Counted before installing, so a concurrent `release` sees it.
+            CAPTURING.fetch_add(1, Ordering::AcqRel);
+            if let Err(e) = install() {
+                CAPTURING.fetch_sub(1, Ordering::AcqRel);
+                return Err(e);
+            }
+            capture.enabled = true;
+            Ok(())
+        } else {
+            capture.enabled = false;
+            CAPTURING.fetch_sub(1, Ordering::AcqRel);
+            drop(capture);
+            release()
+        }
+    }
+
+    /// Gets the diagnostic messages of the last operation.
+    ///
+    /// # Returns
+    ///
+    /// Returns the captured messages, empty if capturing is disabled.
+    pub fn last_diagnostics(&self) -> Result<Vec<String>> {
+        let capture = self
+            .diagnostics
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        Ok(capture.messages.clone())
+    }
+
+    /// Whether diagnostic messages are captured.
+    pub(crate) fn captures_diagnostics(&self) -> Result<bool> {
+        let capture = self
+            .diagnostics
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        Ok(capture.enabled)
+    }
+
+    /// Starts an engine operation whose messages are dispatched when the
+    /// returned scope is dropped, and captured if enabled.
+    ///
+    /// Scopes nest: an operation started inside another one (e.g. `init_4`
+    /// setting variables) adds its messages to the outer operation.
+    pub(crate) fn diagnostics_scope(&self) -> DiagnosticsScope<'_> {
+        if !INSTALLED.load(Ordering::Acquire) {
+            return DiagnosticsScope { capture: None };
+        }
+        // Messages left over from earlier work are not part of this operation
+        drain();
+        let enabled = self.captures_diagnostics().unwrap_or(false);
+        let outermost = CAPTURE.with(|current| {
+            let mut current = current.borrow_mut();
+            if current.is_some() || !enabled {
+                return false;
+            }
+            *current = Some(Vec::new());
+            true
+        });
+        DiagnosticsScope {
+            capture: outermost.then_some(&*self.diagnostics),
+        }
+    }
+}
+
+#[cfg(feature = "build-tesseract")]
+extern "C" {
+    fn leptSetStderrHandler(handler: Option<extern "C" fn(*const c_char)>);
+    fn setMsgSeverity(severity: c_int) -> c_int;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_source_display() {
+        assert_eq!(DiagnosticSource::Tesseract.to_string(), "tesseract");
+        assert_eq!(DiagnosticSource::Leptonica.to_string(), "leptonica");
+    }
+
+    #[test]
+    fn test_dispatch_to_capture() {
+        CAPTURE.with(|capture| *capture.borrow_mut() = Some(Vec::new()));
+        dispatch(DiagnosticSource::Tesseract, "Empty page!!");
+        let messages = CAPTURE.with(|capture| capture.borrow_mut().take());
+        assert_eq!(messages, Some(vec!["Empty page!!".to_owned()]));
+    }
+}
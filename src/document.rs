@@ -0,0 +1,536 @@
+//! Owned tree of recognition results, built by [`TesseractAPI::recognize_document`].
+//!
+//! # JSON schema
+//!
+//! With the `serde` feature, a [`Document`] serializes to the JSON below.
+//! Coordinates are pixels in the input image with the origin at the top
+//! left; `right` and `bottom` are exclusive. Confidences range from 0 to 100.
+//! Enums are written as the names of their variants, e.g. `"PT_FLOWING_TEXT"`
+//! or `"JUSTIFICATION_LEFT"`.
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "pages": [{
+//!     "width": 1200, "height": 400,
+//!     "blocks": [{
+//!       "block_type": "PT_FLOWING_TEXT",
+//!       "bounding_box": { "left": 36, "top": 92, "right": 1164, "bottom": 151 },
+//!       "paragraphs": [{
+//!         "bounding_box": { "left": 36, "top": 92, "right": 1164, "bottom": 151 },
+//!         "info": {
+//!           "justification": "JUSTIFICATION_LEFT",
+//!           "is_list_item": false, "is_crown": false, "first_line_indent": 0
+//!         },
+//!         "lines": [{
+//!           "bounding_box": { "left": 36, "top": 92, "right": 1164, "bottom": 151 },
+//!           "baseline": { "x1": 36, "y1": 140, "x2": 1164, "y2": 141 },
+//!           "words": [{
+//!             "text": "This",
+//!             "bounding_box": { "left": 36, "top": 92, "right": 118, "bottom": 140 },
+//!             "confidence": 96.5,
+//!             "font": null,
+//!             "language": "eng",
+//!             "is_from_dictionary": true, "is_numeric": false,
+//!             "symbols": [{
+//!               "text": "T",
+//!               "bounding_box": { "left": 36, "top": 92, "right": 66, "bottom": 140 },
+//!               "confidence": 99.1,
+//!               "is_superscript": false, "is_subscript": false, "is_dropcap": false
+//!             }]
+//!           }]
+//!         }]
+//!       }]
+//!     }]
+//!   }]
+//! }
+//! ```
+//!
+//! `baseline`, `font` and `language` may be `null`; `font` is `null` with
+//! the LSTM engine, which does not estimate font attributes. Non-text blocks
+//! have an empty `paragraphs` array.
+//!
+//! `schema_version` is [`JSON_SCHEMA_VERSION`]. It is incremented whenever a
+//! field is renamed, removed or changes meaning; adding fields does not
+//! change it. [`Document::from_json`] rejects documents with a newer version.
+//!
+//! [`TesseractAPI::recognize_document`]: crate::TesseractAPI::recognize_document
+
+use crate::api::TessDeleteText;
+use crate::enums::{TessPageIteratorLevel, TessParagraphJustification, TessPolyBlockType};
+use crate::error::{Result, TesseractError};
+use crate::page_iterator::{
+    TessPageIteratorBaseline, TessPageIteratorBlockType, TessPageIteratorBoundingBox,
+    TessPageIteratorIsAtBeginningOf, TessPageIteratorIsAtFinalElement,
+    TessPageIteratorParagraphInfo,
+};
+use crate::result_iterator::{
+    TessResultIteratorConfidence, TessResultIteratorDelete, TessResultIteratorGetUTF8Text,
+    TessResultIteratorNext, TessResultIteratorSymbolIsDropcap, TessResultIteratorSymbolIsSubscript,
+    TessResultIteratorSymbolIsSuperscript, TessResultIteratorWordFontAttributes,
+    TessResultIteratorWordIsFromDictionary, TessResultIteratorWordIsNumeric,
+    TessResultIteratorWordRecognitionLanguage,
+};
+use crate::types::{Baseline, BoundingBox, FontAttributes, ParagraphInfo};
+use std::ffi::CStr;
+use std::os::raw::{c_int, c_void};
+
+/// Version of the JSON schema written by [`Document::to_json`].
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Recognition results of one or more pages.
+///
+/// With the `serde` feature, serializes to the versioned JSON schema
+/// described in the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(try_from = "JsonDocument")
+)]
+pub struct Document {
+    pub pages: Vec<Page>,
+}
+
+/// A recognized page.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Page {
+    /// Width of the input image in pixels.
+    pub width: i32,
+    /// Height of the input image in pixels.
+    pub height: i32,
+    pub blocks: Vec<Block>,
+}
+
+/// A block of the page layout. Non-text blocks have no paragraphs.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Block {
+    pub block_type: TessPolyBlockType,
+    pub bounding_box: BoundingBox,
+    pub paragraphs: Vec<Paragraph>,
+}
+
+/// A paragraph of a text block.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Paragraph {
+    pub bounding_box: BoundingBox,
+    pub info: ParagraphInfo,
+    pub lines: Vec<Line>,
+}
+
+/// A line of text.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
+    pub bounding_box: BoundingBox,
+    pub baseline: Option<Baseline>,
+    pub words: Vec<Word>,
+}
+
+/// A recognized word.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Word {
+    pub text: String,
+    pub bounding_box: BoundingBox,
+    /// Confidence between 0 and 100.
+    pub confidence: f32,
+    /// Font attributes, `None` when the engine does not provide them (LSTM).
+    pub font: Option<FontAttributes>,
+    /// Language the word was recognized with, e.g. `eng`.
+    pub language: Option<String>,
+    pub is_from_dictionary: bool,
+    pub is_numeric: bool,
+    pub symbols: Vec<Symbol>,
+}
+
+/// A recognized character.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Symbol {
+    pub text: String,
+    pub bounding_box: BoundingBox,
+    /// Confidence between 0 and 100.
+    pub confidence: f32,
+    pub is_superscript: bool,
+    pub is_subscript: bool,
+    pub is_dropcap: bool,
+}
+
+impl Document {
+    /// Gets the text of the document.
+    ///
+    /// # Returns
+    ///
+    /// Returns the text of the pages, separated by form feeds.
+    pub fn text(&self) -> String {
+        self.pages
+            .iter()
+            .map(Page::text)
+            .collect::<Vec<_>>()
+            .join("\u{c}")
+    }
+
+    /// Iterates over the words of every page in reading order.
+    pub fn words(&self) -> impl Iterator<Item = &Word> {
+        self.pages.iter().flat_map(Page::words)
+    }
+
+    /// Serializes the document to JSON, following the schema version
+    /// [`JSON_SCHEMA_VERSION`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the JSON text if successful, otherwise returns an error.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Serializes the document to indented JSON.
+    ///
+    /// # Returns
+    ///
+    /// Returns the JSON text if successful, otherwise returns an error.
+    #[cfg(feature = "serde")]
+    pub fn to_json_pretty(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Reads a document written by [`Document::to_json`].
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - JSON text.
+    ///
+    /// # Returns
+    ///
+    /// Returns the document, or an error if the JSON is malformed or was
+    /// written with a newer schema version.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Document {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut document = serializer.serialize_struct("Document", 2)?;
+        document.serialize_field("schema_version", &JSON_SCHEMA_VERSION)?;
+        document.serialize_field("pages", &self.pages)?;
+        document.end()
+    }
+}
+
+/// Serialized form of a [`Document`], checked against the schema version.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct JsonDocument {
+    schema_version: u32,
+    pages: Vec<Page>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<JsonDocument> for Document {
+    type Error = String;
+
+    fn try_from(json: JsonDocument) -> std::result::Result<Self, String> {
+        if json.schema_version > JSON_SCHEMA_VERSION {
+            return Err(format!(
+                "unsupported schema_version {}, expected at most {}",
+                json.schema_version, JSON_SCHEMA_VERSION
+            ));
+        }
+        Ok(Document { pages: json.pages })
+    }
+}
+
+impl Page {
+    /// Gets the text of the page.
+    ///
+    /// # Returns
+    ///
+    /// Returns the paragraphs separated by blank lines, each line on its own line.
+    pub fn text(&self) -> String {
+        self.blocks
+            .iter()
+            .flat_map(|block| &block.paragraphs)
+            .map(Paragraph::text)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Iterates over the words of the page in reading order.
+    pub fn words(&self) -> impl Iterator<Item = &Word> {
+        self.blocks
+            .iter()
+            .flat_map(|block| &block.paragraphs)
+            .flat_map(|paragraph| &paragraph.lines)
+            .flat_map(|line| &line.words)
+    }
+}
+
+impl Paragraph {
+    /// Gets the text of the paragraph.
+    ///
+    /// # Returns
+    ///
+    /// Returns the lines of the paragraph separated by newlines.
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(Line::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Line {
+    /// Gets the text of the line.
+    ///
+    /// # Returns
+    ///
+    /// Returns the words of the line separated by spaces.
+    pub fn text(&self) -> String {
+        self.words
+            .iter()
+            .map(|word| word.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Deletes the result iterator when the walk ends, including on error.
+struct IteratorGuard(*mut c_void);
+
+impl Drop for IteratorGuard {
+    fn drop(&mut self) {
+        unsafe { TessResultIteratorDelete(self.0) };
+    }
+}
+
+/// Builds a page by walking a result iterator from its current position to
+/// the end. Takes ownership of the iterator.
+///
+/// # Arguments
+///
+/// * `iterator` - Raw result iterator positioned at the start of the page.
+/// * `width` - Width of the page in pixels.
+/// * `height` - Height of the page in pixels.
+///
+/// # Returns
+///
+/// Returns the page, or an error if a recognized text is not valid UTF-8.
+pub(crate) fn build_page(iterator: *mut c_void, width: i32, height: i32) -> Result<Page> {
+    let iterator = IteratorGuard(iterator);
+    let it = iterator.0;
+    let mut page = Page {
+        width,
+        height,
+        blocks: Vec::new(),
+    };
+    // Past the last block the iterator has no bounding box at any level
+    while let Some(block_box) = bounding_box(it, TessPageIteratorLevel::RIL_BLOCK) {
+        if page.blocks.is_empty() || is_at_beginning_of(it, TessPageIteratorLevel::RIL_BLOCK) {
+            page.blocks.push(Block {
+                block_type: TessPolyBlockType::from_int(unsafe { TessPageIteratorBlockType(it) }),
+                bounding_box: block_box,
+                paragraphs: Vec::new(),
+            });
+        }
+        // Image and line-art blocks are visited without a word
+        if let Some(text) = utf8_text(it, TessPageIteratorLevel::RIL_WORD)? {
+            let block = page.blocks.last_mut().expect("a block was pushed");
+            if block.paragraphs.is_empty()
+                || is_at_beginning_of(it, TessPageIteratorLevel::RIL_PARA)
+            {
+                block.paragraphs.push(Paragraph {
+                    bounding_box: bounding_box(it, TessPageIteratorLevel::RIL_PARA)
+                        .unwrap_or_default(),
+                    info: paragraph_info(it),
+                    lines: Vec::new(),
+                });
+            }
+            let paragraph = block.paragraphs.last_mut().expect("a paragraph was pushed");
+            if paragraph.lines.is_empty()
+                || is_at_beginning_of(it, TessPageIteratorLevel::RIL_TEXTLINE)
+            {
+                paragraph.lines.push(Line {
+                    bounding_box: bounding_box(it, TessPageIteratorLevel::RIL_TEXTLINE)
+                        .unwrap_or_default(),
+                    baseline: baseline(it, TessPageIteratorLevel::RIL_TEXTLINE),
+                    words: Vec::new(),
+                });
+            }
+            let line = paragraph.lines.last_mut().expect("a line was pushed");
+            line.words.push(build_word(it, text)?);
+        }
+        if unsafe { TessResultIteratorNext(it, TessPageIteratorLevel::RIL_WORD as c_int) } == 0 {
+            break;
+        }
+    }
+    Ok(page)
+}
+
+/// Reads the word at the iterator, leaving the iterator on its last symbol.
+fn build_word(it: *mut c_void, text: String) -> Result<Word> {
+    let language = unsafe {
+        let ptr = TessResultIteratorWordRecognitionLanguage(it);
+        if ptr.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(ptr).to_str()?.to_owned())
+        }
+    };
+    let mut word = Word {
+        text,
+        bounding_box: bounding_box(it, TessPageIteratorLevel::RIL_WORD).unwrap_or_default(),
+        confidence: unsafe {
+            TessResultIteratorConfidence(it, TessPageIteratorLevel::RIL_WORD as c_int)
+        },
+        font: font_attributes(it),
+        language,
+        is_from_dictionary: unsafe { TessResultIteratorWordIsFromDictionary(it) } != 0,
+        is_numeric: unsafe { TessResultIteratorWordIsNumeric(it) } != 0,
+        symbols: Vec::new(),
+    };
+    loop {
+        if let Some(text) = utf8_text(it, TessPageIteratorLevel::RIL_SYMBOL)? {
+            word.symbols.push(Symbol {
+                text,
+                bounding_box: bounding_box(it, TessPageIteratorLevel::RIL_SYMBOL)
+                    .unwrap_or_default(),
+                confidence: unsafe {
+                    TessResultIteratorConfidence(it, TessPageIteratorLevel::RIL_SYMBOL as c_int)
+                },
+                is_superscript: unsafe { TessResultIteratorSymbolIsSuperscript(it) } != 0,
+                is_subscript: unsafe { TessResultIteratorSymbolIsSubscript(it) } != 0,
+                is_dropcap: unsafe { TessResultIteratorSymbolIsDropcap(it) } != 0,
+            });
+        }
+        let last_symbol = unsafe {
+            TessPageIteratorIsAtFinalElement(
+                it,
+                TessPageIteratorLevel::RIL_WORD as c_int,
+                TessPageIteratorLevel::RIL_SYMBOL as c_int,
+            )
+        } != 0;
+        if last_symbol
+            || unsafe { TessResultIteratorNext(it, TessPageIteratorLevel::RIL_SYMBOL as c_int) }
+                == 0
+        {
+            return Ok(word);
+        }
+    }
+}
+
+fn utf8_text(it: *mut c_void, level: TessPageIteratorLevel) -> Result<Option<String>> {
+    let ptr = unsafe { TessResultIteratorGetUTF8Text(it, level as c_int) };
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    let result = unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map(str::to_owned)
+        .map_err(TesseractError::from);
+    unsafe { TessDeleteText(ptr) };
+    result.map(Some)
+}
+
+fn is_at_beginning_of(it: *mut c_void, level: TessPageIteratorLevel) -> bool {
+    unsafe { TessPageIteratorIsAtBeginningOf(it, level as c_int) != 0 }
+}
+
+fn bounding_box(it: *mut c_void, level: TessPageIteratorLevel) -> Option<BoundingBox> {
+    let mut bbox = BoundingBox::default();
+    let found = unsafe {
+        TessPageIteratorBoundingBox(
+            it,
+            level as c_int,
+            &mut bbox.left,
+            &mut bbox.top,
+            &mut bbox.right,
+            &mut bbox.bottom,
+        )
+    };
+    (found != 0).then_some(bbox)
+}
+
+fn baseline(it: *mut c_void, level: TessPageIteratorLevel) -> Option<Baseline> {
+    let mut baseline = Baseline::default();
+    let found = unsafe {
+        TessPageIteratorBaseline(
+            it,
+            level as c_int,
+            &mut baseline.x1,
+            &mut baseline.y1,
+            &mut baseline.x2,
+            &mut baseline.y2,
+        )
+    };
+    (found != 0).then_some(baseline)
+}
+
+fn paragraph_info(it: *mut c_void) -> ParagraphInfo {
+    let mut justification = 0;
+    let mut is_list_item = 0;
+    let mut is_crown = 0;
+    let mut first_line_indent = 0;
+    unsafe {
+        TessPageIteratorParagraphInfo(
+            it,
+            &mut justification,
+            &mut is_list_item,
+            &mut is_crown,
+            &mut first_line_indent,
+        )
+    };
+    ParagraphInfo {
+        justification: TessParagraphJustification::from_int(justification),
+        is_list_item: is_list_item != 0,
+        is_crown: is_crown != 0,
+        first_line_indent,
+    }
+}
+
+fn font_attributes(it: *mut c_void) -> Option<FontAttributes> {
+    let mut is_bold = 0;
+    let mut is_italic = 0;
+    let mut is_underlined = 0;
+    let mut is_monospace = 0;
+    let mut is_serif = 0;
+    let mut is_smallcaps = 0;
+    let mut pointsize = 0;
+    let mut font_id = 0;
+    let found = unsafe {
+        TessResultIteratorWordFontAttributes(
+            it,
+            &mut is_bold,
+            &mut is_italic,
+            &mut is_underlined,
+            &mut is_monospace,
+            &mut is_serif,
+            &mut is_smallcaps,
+            &mut pointsize,
+            &mut font_id,
+        )
+    };
+    (found != 0).then_some(FontAttributes {
+        is_bold: is_bold != 0,
+        is_italic: is_italic != 0,
+        is_underlined: is_underlined != 0,
+        is_monospace: is_monospace != 0,
+        is_serif: is_serif != 0,
+        is_smallcaps: is_smallcaps != 0,
+        pointsize,
+        font_id,
+    })
+}
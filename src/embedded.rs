@@ -3,7 +3,7 @@
 //! This module provides functionality to embed Tesseract training data directly
 //! into the binary, eliminating the need to ship separate tessdata files.
 
-use crate::{Result, TesseractAPI};
+use crate::{OcrEngineMode, Result, TesseractAPI};
 
 // Include the generated embedded tessdata
 include!(concat!(env!("OUT_DIR"), "/embedded_tessdata.rs"));
@@ -33,11 +33,34 @@ impl TesseractAPI {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn init_embedded(&self, language: &str) -> Result<()> {
-        let tessdata = EMBEDDED_TESSDATA
-            .get(language)
-            .ok_or_else(|| crate::TesseractError::InitError)?;
+        self.init_embedded_with_oem(language, OcrEngineMode::Default)
+    }
+
+    /// Initialize Tesseract with embedded training data and a specific OCR engine mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language code (e.g., "eng", "tur")
+    /// * `oem` - The OCR engine mode to use
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if initialization is successful, otherwise returns an error.
+    /// Returns `UnsupportedEngineMode` if the embedded traineddata lacks the
+    /// engine `oem` needs.
+    pub fn init_embedded_with_oem(&self, language: &str, oem: OcrEngineMode) -> Result<()> {
+        let tessdata = EMBEDDED_TESSDATA.get(language).ok_or_else(|| {
+            crate::TesseractError::MissingLanguage {
+                language: language.to_owned(),
+                available: self
+                    .embedded_languages()
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+            }
+        })?;
 
-        self.init_5(tessdata, tessdata.len() as i32, language, 3, &[])
+        self.init_5(tessdata, tessdata.len() as i32, language, oem, &[])
     }
 
     /// Get a list of available embedded languages.
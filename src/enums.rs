@@ -1,5 +1,6 @@
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum TessPageSegMode {
     PSM_OSD_ONLY = 0,
@@ -44,6 +45,7 @@ impl TessPageSegMode {
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum TessPageIteratorLevel {
     RIL_BLOCK = 0,
@@ -68,6 +70,7 @@ impl TessPageIteratorLevel {
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum TessPolyBlockType {
     PT_UNKNOWN = 0,
@@ -114,6 +117,7 @@ impl TessPolyBlockType {
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum TessOrientation {
     ORIENTATION_PAGE_UP = 0,
@@ -136,6 +140,7 @@ impl TessOrientation {
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum TessParagraphJustification {
     JUSTIFICATION_UNKNOWN = 0,
@@ -158,6 +163,7 @@ impl TessParagraphJustification {
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum TessWritingDirection {
     WRITING_DIRECTION_LEFT_TO_RIGHT = 0,
@@ -178,6 +184,7 @@ impl TessWritingDirection {
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum TessTextlineOrder {
     TEXTLINE_ORDER_LEFT_TO_RIGHT = 0,
@@ -196,6 +203,42 @@ impl TessTextlineOrder {
     }
 }
 
+/// OCR engine mode passed to the `init_*` methods.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OcrEngineMode {
+    /// The legacy Tesseract engine only.
+    TesseractOnly = 0,
+    /// The LSTM neural network engine only.
+    LstmOnly = 1,
+    /// Both engines, combining their results.
+    Combined = 2,
+    /// Whatever the traineddata and config files support, preferring LSTM.
+    Default = 3,
+}
+
+impl OcrEngineMode {
+    pub fn from_int(value: i32) -> Self {
+        match value {
+            0 => OcrEngineMode::TesseractOnly,
+            1 => OcrEngineMode::LstmOnly,
+            2 => OcrEngineMode::Combined,
+            _ => OcrEngineMode::Default,
+        }
+    }
+
+    /// Whether this mode needs the legacy engine components in the traineddata.
+    pub fn needs_legacy(self) -> bool {
+        matches!(self, OcrEngineMode::TesseractOnly | OcrEngineMode::Combined)
+    }
+
+    /// Whether this mode needs the LSTM components in the traineddata.
+    pub fn needs_lstm(self) -> bool {
+        matches!(self, OcrEngineMode::LstmOnly | OcrEngineMode::Combined)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -457,6 +500,28 @@ mod tests {
         ); // negative default
     }
 
+    #[test]
+    fn test_ocr_engine_mode_from_int() {
+        assert_eq!(OcrEngineMode::from_int(0), OcrEngineMode::TesseractOnly);
+        assert_eq!(OcrEngineMode::from_int(1), OcrEngineMode::LstmOnly);
+        assert_eq!(OcrEngineMode::from_int(2), OcrEngineMode::Combined);
+        assert_eq!(OcrEngineMode::from_int(3), OcrEngineMode::Default);
+        assert_eq!(OcrEngineMode::from_int(42), OcrEngineMode::Default); // default
+        assert_eq!(OcrEngineMode::LstmOnly as i32, 1);
+    }
+
+    #[test]
+    fn test_ocr_engine_mode_requirements() {
+        assert!(OcrEngineMode::TesseractOnly.needs_legacy());
+        assert!(!OcrEngineMode::TesseractOnly.needs_lstm());
+        assert!(!OcrEngineMode::LstmOnly.needs_legacy());
+        assert!(OcrEngineMode::LstmOnly.needs_lstm());
+        assert!(OcrEngineMode::Combined.needs_legacy());
+        assert!(OcrEngineMode::Combined.needs_lstm());
+        assert!(!OcrEngineMode::Default.needs_legacy());
+        assert!(!OcrEngineMode::Default.needs_lstm());
+    }
+
     #[test]
     fn test_enums_are_copy() {
         fn assert_copy<T: Copy>() {}
@@ -467,5 +532,6 @@ mod tests {
         assert_copy::<TessParagraphJustification>();
         assert_copy::<TessWritingDirection>();
         assert_copy::<TessTextlineOrder>();
+        assert_copy::<OcrEngineMode>();
     }
 }
@@ -1,33 +1,47 @@
+use crate::enums::OcrEngineMode;
+use crate::parameters::ParameterType;
+use std::path::{Path, PathBuf};
 use std::str::Utf8Error;
 use thiserror::Error;
 
 /// Errors that can occur when using the Tesseract API.
 #[derive(Error, Debug)]
 pub enum TesseractError {
-    #[error("Failed to initialize Tesseract")]
-    InitError,
+    #[error("Failed to initialize Tesseract for language '{language}' (datapath: '{datapath}')")]
+    InitError { datapath: String, language: String },
+    #[error("Traineddata for language '{language}' not found at {}", .path.display())]
+    MissingTraineddata { language: String, path: PathBuf },
+    #[error("Language '{language}' is not embedded (available: {})", .available.join(", "))]
+    MissingLanguage {
+        language: String,
+        available: Vec<String>,
+    },
     #[error("Failed to set image")]
     SetImageError,
-    #[error("OCR operation failed")]
-    OcrError,
+    #[error("OCR operation failed{}", describe_input(.input_name))]
+    OcrError { input_name: Option<String> },
     #[error("Invalid UTF-8 in Tesseract output")]
     Utf8Error(#[from] Utf8Error),
     #[error("Failed to lock mutex")]
     MutexLockError,
-    #[error("Failed to set variable")]
-    SetVariableError,
-    #[error("Failed to get variable")]
-    GetVariableError,
+    #[error("Failed to set variable '{name}' to '{value}'")]
+    SetVariableError { name: String, value: String },
+    #[error("Failed to get variable '{name}'")]
+    GetVariableError { name: String },
     #[error("Null pointer error")]
     NullPointerError,
     #[error("Invalid parameter")]
     InvalidParameterError,
     #[error("Layout analysis failed")]
     AnalyseLayoutError,
-    #[error("Page processing failed")]
-    ProcessPagesError,
-    #[error("I/O error")]
-    IoError,
+    #[error("Page processing failed for '{path}'{}", describe_page(.page))]
+    ProcessPagesError { path: String, page: Option<usize> },
+    #[error("I/O error{}: {source}", describe_path(.path))]
+    IoError {
+        path: Option<PathBuf>,
+        #[source]
+        source: std::io::Error,
+    },
     #[error("Invalid dimensions")]
     InvalidDimensions,
     #[error("Invalid bytes per pixel")]
@@ -38,6 +52,88 @@ pub enum TesseractError {
     InvalidImageData,
     #[error("Uninitialized error")]
     UninitializedError,
+    #[error("OCR engine mode {oem:?} not supported by the traineddata for '{language}'")]
+    UnsupportedEngineMode {
+        language: String,
+        oem: OcrEngineMode,
+    },
+    #[error("Unknown parameter: {0}")]
+    UnknownParameter(String),
+    #[error("Parameter {name} is of type {actual}, not {expected}")]
+    ParameterTypeMismatch {
+        name: String,
+        expected: ParameterType,
+        actual: ParameterType,
+    },
+    #[error("Parameter {0} can only be set during initialization")]
+    ReadOnlyParameter(String),
+    #[error("Recognition was cancelled")]
+    Cancelled,
+    #[error("Recognition deadline exceeded")]
+    DeadlineExceeded,
+    #[error("Timed out waiting for a pooled engine")]
+    PoolTimeout,
+    #[error("Failed to render the '{extension}' document")]
+    RenderError { extension: String },
+    #[error("Invalid {format}: {message}")]
+    ParseError {
+        format: &'static str,
+        message: String,
+    },
+    // Holds a `serde_json::Error`. Defined without the `serde` feature too,
+    // so that enabling the feature does not add a variant.
+    #[error("JSON error: {0}")]
+    JsonError(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for TesseractError {
+    fn from(error: serde_json::Error) -> Self {
+        TesseractError::JsonError(Box::new(error))
+    }
+}
+
+/// Formats the optional input name of an `OcrError`.
+fn describe_input(input_name: &Option<String>) -> String {
+    match input_name {
+        Some(name) => format!(" on '{}'", name),
+        None => String::new(),
+    }
+}
+
+/// Formats the optional path of an `IoError`.
+fn describe_path(path: &Option<PathBuf>) -> String {
+    match path {
+        Some(path) => format!(" on '{}'", path.display()),
+        None => String::new(),
+    }
+}
+
+impl TesseractError {
+    /// Builds an `IoError` for an operation on `path`.
+    pub(crate) fn io(path: impl AsRef<Path>, source: std::io::Error) -> Self {
+        TesseractError::IoError {
+            path: Some(path.as_ref().to_owned()),
+            source,
+        }
+    }
+
+    /// Builds an `IoError` for a path that cannot be passed to Tesseract
+    /// because it is not valid UTF-8.
+    pub(crate) fn non_utf8_path(path: impl AsRef<Path>) -> Self {
+        Self::io(
+            path,
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "path is not valid UTF-8"),
+        )
+    }
+}
+
+/// Formats the optional page index of a `ProcessPagesError`.
+fn describe_page(page: &Option<usize>) -> String {
+    match page {
+        Some(page) => format!(" at page {}", page),
+        None => String::new(),
+    }
 }
 
 /// Result type for Tesseract operations.
@@ -50,17 +146,81 @@ mod tests {
     #[test]
     fn test_all_error_display() {
         let cases = vec![
-            (TesseractError::InitError, "Failed to initialize Tesseract"),
+            (
+                TesseractError::InitError {
+                    datapath: "/data".into(),
+                    language: "eng+foo".into(),
+                },
+                "Failed to initialize Tesseract for language 'eng+foo' (datapath: '/data')",
+            ),
+            (
+                TesseractError::MissingTraineddata {
+                    language: "foo".into(),
+                    path: PathBuf::from("/data/foo.traineddata"),
+                },
+                "Traineddata for language 'foo' not found at /data/foo.traineddata",
+            ),
+            (
+                TesseractError::MissingLanguage {
+                    language: "foo".into(),
+                    available: vec!["eng".into(), "tur".into()],
+                },
+                "Language 'foo' is not embedded (available: eng, tur)",
+            ),
             (TesseractError::SetImageError, "Failed to set image"),
-            (TesseractError::OcrError, "OCR operation failed"),
+            (
+                TesseractError::OcrError { input_name: None },
+                "OCR operation failed",
+            ),
+            (
+                TesseractError::OcrError {
+                    input_name: Some("scan.png".into()),
+                },
+                "OCR operation failed on 'scan.png'",
+            ),
             (TesseractError::MutexLockError, "Failed to lock mutex"),
-            (TesseractError::SetVariableError, "Failed to set variable"),
-            (TesseractError::GetVariableError, "Failed to get variable"),
+            (
+                TesseractError::SetVariableError {
+                    name: "foo".into(),
+                    value: "1".into(),
+                },
+                "Failed to set variable 'foo' to '1'",
+            ),
+            (
+                TesseractError::GetVariableError { name: "foo".into() },
+                "Failed to get variable 'foo'",
+            ),
             (TesseractError::NullPointerError, "Null pointer error"),
             (TesseractError::InvalidParameterError, "Invalid parameter"),
             (TesseractError::AnalyseLayoutError, "Layout analysis failed"),
-            (TesseractError::ProcessPagesError, "Page processing failed"),
-            (TesseractError::IoError, "I/O error"),
+            (
+                TesseractError::ProcessPagesError {
+                    path: "scan.tif".into(),
+                    page: None,
+                },
+                "Page processing failed for 'scan.tif'",
+            ),
+            (
+                TesseractError::ProcessPagesError {
+                    path: "scan.tif".into(),
+                    page: Some(3),
+                },
+                "Page processing failed for 'scan.tif' at page 3",
+            ),
+            (
+                TesseractError::IoError {
+                    path: None,
+                    source: std::io::Error::new(std::io::ErrorKind::BrokenPipe, "broken pipe"),
+                },
+                "I/O error: broken pipe",
+            ),
+            (
+                TesseractError::io(
+                    "/tmp/out.txt",
+                    std::io::Error::new(std::io::ErrorKind::NotFound, "not found"),
+                ),
+                "I/O error on '/tmp/out.txt': not found",
+            ),
             (TesseractError::InvalidDimensions, "Invalid dimensions"),
             (
                 TesseractError::InvalidBytesPerPixel,
@@ -72,6 +232,51 @@ mod tests {
             ),
             (TesseractError::InvalidImageData, "Invalid image data"),
             (TesseractError::UninitializedError, "Uninitialized error"),
+            (
+                TesseractError::UnsupportedEngineMode {
+                    language: "eng".into(),
+                    oem: OcrEngineMode::TesseractOnly,
+                },
+                "OCR engine mode TesseractOnly not supported by the traineddata for 'eng'",
+            ),
+            (
+                TesseractError::UnknownParameter("foo".into()),
+                "Unknown parameter: foo",
+            ),
+            (
+                TesseractError::ParameterTypeMismatch {
+                    name: "foo".into(),
+                    expected: ParameterType::Int,
+                    actual: ParameterType::Bool,
+                },
+                "Parameter foo is of type bool, not int",
+            ),
+            (
+                TesseractError::ReadOnlyParameter("foo".into()),
+                "Parameter foo can only be set during initialization",
+            ),
+            (TesseractError::Cancelled, "Recognition was cancelled"),
+            (
+                TesseractError::DeadlineExceeded,
+                "Recognition deadline exceeded",
+            ),
+            (
+                TesseractError::PoolTimeout,
+                "Timed out waiting for a pooled engine",
+            ),
+            (
+                TesseractError::RenderError {
+                    extension: "pdf".into(),
+                },
+                "Failed to render the 'pdf' document",
+            ),
+            (
+                TesseractError::ParseError {
+                    format: "hOCR",
+                    message: "unexpected end of stream".into(),
+                },
+                "Invalid hOCR: unexpected end of stream",
+            ),
         ];
         for (error, expected) in cases {
             assert_eq!(error.to_string(), expected, "Mismatch for {:?}", error);
@@ -90,6 +295,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_io_error_source() {
+        use std::error::Error;
+
+        let error = TesseractError::io(
+            "scan.png",
+            std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied"),
+        );
+        let source = error.source().expect("IoError should have a source");
+        assert_eq!(source.to_string(), "denied");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_error_source() {
+        use std::error::Error;
+
+        let error = TesseractError::from(serde_json::from_str::<u32>("x").unwrap_err());
+        assert!(matches!(error, TesseractError::JsonError(_)));
+        assert!(error.to_string().starts_with("JSON error: "));
+        assert!(error.source().unwrap().is::<serde_json::Error>());
+    }
+
     #[test]
     fn test_error_is_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
@@ -0,0 +1,699 @@
+//! Reading and writing hOCR, the HTML-based format of `get_hocr_text()` and
+//! the hOCR renderer.
+//!
+//! [`HocrDocument::parse`] reads `ocr_page`, `ocr_carea`, `ocr_par`, the
+//! line elements (`ocr_line`, `ocr_header`, `ocr_caption`, `ocr_textfloat`)
+//! and `ocrx_word` with the properties of their `title` attributes.
+//! [`HocrDocument::to_hocr`] writes a complete XHTML document in the layout
+//! Tesseract uses, so corrected results can be saved without recognizing
+//! the image again.
+
+use crate::error::{Result, TesseractError};
+use crate::types::BoundingBox;
+use crate::xml::{self, escape};
+use std::fmt::Write;
+
+/// Parsed hOCR: the pages of one or more `ocr_page` elements.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HocrDocument {
+    /// Content of the `ocr-system` meta tag, e.g. `tesseract 5.5.2`.
+    pub ocr_system: Option<String>,
+    pub pages: Vec<HocrPage>,
+}
+
+/// An `ocr_page` element.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HocrPage {
+    pub id: String,
+    /// Path of the source image (`image` property).
+    pub image: Option<String>,
+    pub bbox: BoundingBox,
+    /// Zero-based physical page number (`ppageno` property).
+    pub page_number: Option<u32>,
+    /// Horizontal and vertical resolution in DPI (`scan_res` property).
+    pub scan_res: Option<(i32, i32)>,
+    pub areas: Vec<HocrArea>,
+}
+
+/// An `ocr_carea` element: a block of the page layout.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HocrArea {
+    pub id: String,
+    pub bbox: BoundingBox,
+    pub paragraphs: Vec<HocrParagraph>,
+}
+
+/// An `ocr_par` element.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HocrParagraph {
+    pub id: String,
+    pub bbox: BoundingBox,
+    /// Language of the paragraph (`lang` attribute).
+    pub lang: Option<String>,
+    /// Text direction, `ltr` or `rtl` (`dir` attribute).
+    pub dir: Option<String>,
+    pub lines: Vec<HocrLine>,
+}
+
+/// Class of a line element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HocrLineKind {
+    /// `ocr_line`: a line of body text.
+    #[default]
+    Line,
+    /// `ocr_header`: a line of a heading.
+    Header,
+    /// `ocr_caption`: a line of a caption.
+    Caption,
+    /// `ocr_textfloat`: a line of a pull-out text.
+    TextFloat,
+}
+
+impl HocrLineKind {
+    /// Gets the hOCR class name of the line kind.
+    ///
+    /// # Returns
+    ///
+    /// Returns the class name, e.g. `ocr_line`.
+    pub fn class(self) -> &'static str {
+        match self {
+            HocrLineKind::Line => "ocr_line",
+            HocrLineKind::Header => "ocr_header",
+            HocrLineKind::Caption => "ocr_caption",
+            HocrLineKind::TextFloat => "ocr_textfloat",
+        }
+    }
+
+    fn from_class(class: &str) -> Option<Self> {
+        match class {
+            "ocr_line" => Some(HocrLineKind::Line),
+            "ocr_header" => Some(HocrLineKind::Header),
+            "ocr_caption" => Some(HocrLineKind::Caption),
+            "ocr_textfloat" => Some(HocrLineKind::TextFloat),
+            _ => None,
+        }
+    }
+}
+
+/// Baseline of a line (`baseline` property), relative to the bottom left
+/// corner of the line's bounding box.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HocrBaseline {
+    pub slope: f32,
+    /// Vertical offset in pixels, usually negative.
+    pub offset: f32,
+}
+
+/// A line element.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HocrLine {
+    pub id: String,
+    pub kind: HocrLineKind,
+    pub bbox: BoundingBox,
+    pub baseline: Option<HocrBaseline>,
+    /// Height of the lowercase letters plus ascenders (`x_size` property).
+    pub x_size: Option<f32>,
+    /// Depth of the descenders (`x_descenders` property).
+    pub x_descenders: Option<f32>,
+    /// Height of the ascenders above the x-height (`x_ascenders` property).
+    pub x_ascenders: Option<f32>,
+    pub words: Vec<HocrWord>,
+}
+
+/// An `ocrx_word` element.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HocrWord {
+    pub id: String,
+    pub text: String,
+    pub bbox: BoundingBox,
+    /// Confidence between 0 and 100 (`x_wconf` property).
+    pub confidence: Option<f32>,
+    /// Font name (`x_font` property).
+    pub font: Option<String>,
+    /// Font size in points (`x_fsize` property).
+    pub font_size: Option<f32>,
+    /// Language, when it differs from the paragraph's (`lang` attribute).
+    pub lang: Option<String>,
+    /// Text direction, when it differs from the paragraph's (`dir` attribute).
+    pub dir: Option<String>,
+    /// Whether the text is wrapped in `<strong>`.
+    pub is_bold: bool,
+    /// Whether the text is wrapped in `<em>`.
+    pub is_italic: bool,
+}
+
+impl HocrDocument {
+    /// Parses hOCR text.
+    ///
+    /// Accepts both complete documents, as written by the hOCR renderer, and
+    /// the `ocr_page` fragments returned by `get_hocr_text()`, including
+    /// several concatenated ones. Elements with other classes are skipped
+    /// but their children are still read.
+    ///
+    /// # Arguments
+    ///
+    /// * `hocr` - hOCR text.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed document, or `ParseError` if the text is not
+    /// well-formed XML or a property cannot be read.
+    pub fn parse(hocr: &str) -> Result<Self> {
+        let trimmed = hocr.trim_start();
+        // Fragments have no single root element
+        let wrapped;
+        let text = if trimmed.starts_with("<div") {
+            wrapped = format!("<body>{}</body>", trimmed);
+            wrapped.as_str()
+        } else {
+            hocr
+        };
+        let xml = xml::parse(text, "hOCR")?;
+
+        let mut document = HocrDocument::default();
+        for node in xml.descendants().filter(|node| node.has_tag_name("meta")) {
+            if node.attribute("name") == Some("ocr-system") {
+                document.ocr_system = node.attribute("content").map(str::to_owned);
+            }
+        }
+        read_children(xml.root_element(), &mut document)?;
+        Ok(document)
+    }
+
+    /// Writes the document as XHTML in the layout of Tesseract's hOCR renderer.
+    ///
+    /// # Returns
+    ///
+    /// Returns the hOCR text.
+    pub fn to_hocr(&self) -> String {
+        let mut out = String::new();
+        let has_fonts = self
+            .pages
+            .iter()
+            .flat_map(|page| &page.areas)
+            .flat_map(|area| &area.paragraphs)
+            .flat_map(|paragraph| &paragraph.lines)
+            .flat_map(|line| &line.words)
+            .any(|word| word.font.is_some() || word.font_size.is_some());
+        out.push_str(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\"\n",
+            "    \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">\n",
+            "<html xmlns=\"http://www.w3.org/1999/xhtml\" xml:lang=\"en\" lang=\"en\">\n",
+            " <head>\n",
+            "  <title></title>\n",
+            "  <meta http-equiv=\"Content-Type\" content=\"text/html;charset=utf-8\"/>\n",
+        ));
+        if let Some(system) = &self.ocr_system {
+            let _ = writeln!(
+                out,
+                "  <meta name='ocr-system' content='{}' />",
+                escape(system)
+            );
+        }
+        out.push_str(
+            "  <meta name='ocr-capabilities' content='ocr_page ocr_carea ocr_par ocr_line \
+             ocrx_word ocrp_wconf ocrp_lang ocrp_dir",
+        );
+        if has_fonts {
+            out.push_str(" ocrp_font ocrp_fsize");
+        }
+        out.push_str("'/>\n </head>\n <body>\n");
+        for page in &self.pages {
+            write_page(&mut out, page);
+        }
+        out.push_str(" </body>\n</html>\n");
+        out
+    }
+
+    /// Gets the text of the document.
+    ///
+    /// # Returns
+    ///
+    /// Returns the words of each line separated by spaces, the lines by
+    /// newlines and the paragraphs by blank lines.
+    pub fn text(&self) -> String {
+        self.pages
+            .iter()
+            .flat_map(|page| &page.areas)
+            .flat_map(|area| &area.paragraphs)
+            .map(|paragraph| {
+                paragraph
+                    .lines
+                    .iter()
+                    .map(|line| {
+                        line.words
+                            .iter()
+                            .map(|word| word.text.as_str())
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl std::str::FromStr for HocrDocument {
+    type Err = TesseractError;
+
+    fn from_str(hocr: &str) -> Result<Self> {
+        HocrDocument::parse(hocr)
+    }
+}
+
+fn parse_error(message: impl std::fmt::Display) -> TesseractError {
+    xml::error("hOCR", message)
+}
+
+/// Adds the hOCR elements below `node` to the innermost open container.
+fn read_children(node: roxmltree::Node, document: &mut HocrDocument) -> Result<()> {
+    for child in node.children().filter(roxmltree::Node::is_element) {
+        let class = child
+            .attribute("class")
+            .and_then(|class| class.split_whitespace().next())
+            .unwrap_or("");
+        match class {
+            "ocr_page" => {
+                let title = Title::parse(child);
+                document.pages.push(HocrPage {
+                    id: id(child),
+                    image: title.string("image"),
+                    bbox: title.bbox()?,
+                    page_number: title.number("ppageno")?,
+                    scan_res: title.pair("scan_res")?,
+                    areas: Vec::new(),
+                });
+                read_children(child, document)?;
+            }
+            "ocr_carea" => {
+                let area = HocrArea {
+                    id: id(child),
+                    bbox: Title::parse(child).bbox()?,
+                    paragraphs: Vec::new(),
+                };
+                current_page(document, child)?.areas.push(area);
+                read_children(child, document)?;
+            }
+            "ocr_par" => {
+                let paragraph = HocrParagraph {
+                    id: id(child),
+                    bbox: Title::parse(child).bbox()?,
+                    lang: lang(child),
+                    dir: child.attribute("dir").map(str::to_owned),
+                    lines: Vec::new(),
+                };
+                current_area(document, child)?.paragraphs.push(paragraph);
+                read_children(child, document)?;
+            }
+            "ocrx_word" => {
+                let title = Title::parse(child);
+                let word = HocrWord {
+                    id: id(child),
+                    text: child
+                        .descendants()
+                        .filter(roxmltree::Node::is_text)
+                        .filter_map(|text| text.text())
+                        .collect(),
+                    bbox: title.bbox()?,
+                    confidence: title.number("x_wconf")?,
+                    font: title.string("x_font"),
+                    font_size: title.number("x_fsize")?,
+                    lang: lang(child),
+                    dir: child.attribute("dir").map(str::to_owned),
+                    is_bold: child.descendants().any(|node| node.has_tag_name("strong")),
+                    is_italic: child.descendants().any(|node| node.has_tag_name("em")),
+                };
+                current_line(document, child)?.words.push(word);
+            }
+            _ => match HocrLineKind::from_class(class) {
+                Some(kind) => {
+                    let title = Title::parse(child);
+                    let line = HocrLine {
+                        id: id(child),
+                        kind,
+                        bbox: title.bbox()?,
+                        baseline: title
+                            .pair("baseline")?
+                            .map(|(slope, offset)| HocrBaseline { slope, offset }),
+                        x_size: title.number("x_size")?,
+                        x_descenders: title.number("x_descenders")?,
+                        x_ascenders: title.number("x_ascenders")?,
+                        words: Vec::new(),
+                    };
+                    current_paragraph(document, child)?.lines.push(line);
+                    read_children(child, document)?;
+                }
+                None => read_children(child, document)?,
+            },
+        }
+    }
+    Ok(())
+}
+
+fn misplaced(node: roxmltree::Node, parent: &str) -> TesseractError {
+    parse_error(format!(
+        "'{}' at {} is not inside an {} element",
+        node.attribute("class").unwrap_or_default(),
+        node.document().text_pos_at(node.range().start),
+        parent
+    ))
+}
+
+fn current_page<'a>(
+    document: &'a mut HocrDocument,
+    node: roxmltree::Node,
+) -> Result<&'a mut HocrPage> {
+    document
+        .pages
+        .last_mut()
+        .ok_or_else(|| misplaced(node, "ocr_page"))
+}
+
+fn current_area<'a>(
+    document: &'a mut HocrDocument,
+    node: roxmltree::Node,
+) -> Result<&'a mut HocrArea> {
+    current_page(document, node)?
+        .areas
+        .last_mut()
+        .ok_or_else(|| misplaced(node, "ocr_carea"))
+}
+
+fn current_paragraph<'a>(
+    document: &'a mut HocrDocument,
+    node: roxmltree::Node,
+) -> Result<&'a mut HocrParagraph> {
+    current_area(document, node)?
+        .paragraphs
+        .last_mut()
+        .ok_or_else(|| misplaced(node, "ocr_par"))
+}
+
+fn current_line<'a>(
+    document: &'a mut HocrDocument,
+    node: roxmltree::Node,
+) -> Result<&'a mut HocrLine> {
+    current_paragraph(document, node)?
+        .lines
+        .last_mut()
+        .ok_or_else(|| misplaced(node, "ocr_line"))
+}
+
+fn id(node: roxmltree::Node) -> String {
+    node.attribute("id").unwrap_or_default().to_owned()
+}
+
+fn lang(node: roxmltree::Node) -> Option<String> {
+    node.attribute("lang")
+        .or_else(|| node.attribute(("http://www.w3.org/XML/1998/namespace", "lang")))
+        .map(str::to_owned)
+}
+
+/// Properties of a `title` attribute, e.g. `bbox 0 0 10 10; x_wconf 96`.
+struct Title<'a> {
+    properties: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Title<'a> {
+    fn parse(node: roxmltree::Node<'a, '_>) -> Self {
+        let title = node.attribute("title").unwrap_or_default();
+        let mut properties = Vec::new();
+        let mut rest = title;
+        while !rest.trim().is_empty() {
+            // Quoted values, like the image path, may contain semicolons
+            let mut in_quotes = false;
+            let end = rest
+                .char_indices()
+                .find(|&(_, c)| {
+                    if c == '"' {
+                        in_quotes = !in_quotes;
+                    }
+                    c == ';' && !in_quotes
+                })
+                .map_or(rest.len(), |(i, _)| i);
+            let property = rest[..end].trim();
+            let (name, value) = property
+                .split_once(char::is_whitespace)
+                .unwrap_or((property, ""));
+            properties.push((name, value.trim()));
+            rest = rest.get(end + 1..).unwrap_or("");
+        }
+        Title { properties }
+    }
+
+    fn get(&self, name: &str) -> Option<&'a str> {
+        self.properties
+            .iter()
+            .find(|(property, _)| *property == name)
+            .map(|(_, value)| *value)
+    }
+
+    fn values<T: std::str::FromStr>(&self, name: &str, count: usize) -> Result<Option<Vec<T>>> {
+        let Some(value) = self.get(name) else {
+            return Ok(None);
+        };
+        let values = value
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<std::result::Result<Vec<T>, _>>()
+            .ok()
+            .filter(|values| values.len() == count)
+            .ok_or_else(|| parse_error(format!("invalid {} property '{}'", name, value)))?;
+        Ok(Some(values))
+    }
+
+    fn number<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>> {
+        Ok(self
+            .values(name, 1)?
+            .and_then(|values| values.into_iter().next()))
+    }
+
+    fn pair<T: std::str::FromStr + Copy>(&self, name: &str) -> Result<Option<(T, T)>> {
+        Ok(self.values(name, 2)?.map(|values| (values[0], values[1])))
+    }
+
+    fn bbox(&self) -> Result<BoundingBox> {
+        Ok(self
+            .values::<i32>("bbox", 4)?
+            .map(|v| BoundingBox {
+                left: v[0],
+                top: v[1],
+                right: v[2],
+                bottom: v[3],
+            })
+            .unwrap_or_default())
+    }
+
+    fn string(&self, name: &str) -> Option<String> {
+        self.get(name)
+            .map(|value| value.trim_matches('"').to_owned())
+    }
+}
+
+fn write_bbox(out: &mut String, bbox: &BoundingBox) {
+    let _ = write!(
+        out,
+        "bbox {} {} {} {}",
+        bbox.left, bbox.top, bbox.right, bbox.bottom
+    );
+}
+
+fn write_id(out: &mut String, id: &str) {
+    if !id.is_empty() {
+        let _ = write!(out, " id='{}'", escape(id));
+    }
+}
+
+fn write_optional_attribute(out: &mut String, name: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        let _ = write!(out, " {}='{}'", name, escape(value));
+    }
+}
+
+fn write_page(out: &mut String, page: &HocrPage) {
+    out.push_str("  <div class='ocr_page'");
+    write_id(out, &page.id);
+    out.push_str(" title='");
+    if let Some(image) = &page.image {
+        let _ = write!(out, "image \"{}\"; ", escape(image));
+    }
+    write_bbox(out, &page.bbox);
+    if let Some(page_number) = page.page_number {
+        let _ = write!(out, "; ppageno {}", page_number);
+    }
+    if let Some((x, y)) = page.scan_res {
+        let _ = write!(out, "; scan_res {} {}", x, y);
+    }
+    out.push_str("'>");
+    for area in &page.areas {
+        out.push_str("\n   <div class='ocr_carea'");
+        write_id(out, &area.id);
+        out.push_str(" title=\"");
+        write_bbox(out, &area.bbox);
+        out.push_str("\">");
+        for paragraph in &area.paragraphs {
+            write_paragraph(out, paragraph);
+        }
+        out.push_str("\n   </div>");
+    }
+    out.push_str("\n  </div>\n");
+}
+
+fn write_paragraph(out: &mut String, paragraph: &HocrParagraph) {
+    out.push_str("\n    <p class='ocr_par'");
+    write_id(out, &paragraph.id);
+    write_optional_attribute(out, "lang", &paragraph.lang);
+    write_optional_attribute(out, "dir", &paragraph.dir);
+    out.push_str(" title=\"");
+    write_bbox(out, &paragraph.bbox);
+    out.push_str("\">");
+    for line in &paragraph.lines {
+        let _ = write!(out, "\n     <span class='{}'", line.kind.class());
+        write_id(out, &line.id);
+        out.push_str(" title=\"");
+        write_bbox(out, &line.bbox);
+        if let Some(baseline) = line.baseline {
+            let _ = write!(out, "; baseline {} {}", baseline.slope, baseline.offset);
+        }
+        for (name, value) in [
+            ("x_size", line.x_size),
+            ("x_descenders", line.x_descenders),
+            ("x_ascenders", line.x_ascenders),
+        ] {
+            if let Some(value) = value {
+                let _ = write!(out, "; {} {}", name, value);
+            }
+        }
+        out.push_str("\">");
+        for word in &line.words {
+            write_word(out, word);
+        }
+        out.push_str("\n     </span>");
+    }
+    out.push_str("\n    </p>");
+}
+
+fn write_word(out: &mut String, word: &HocrWord) {
+    out.push_str("\n      <span class='ocrx_word'");
+    write_id(out, &word.id);
+    out.push_str(" title='");
+    write_bbox(out, &word.bbox);
+    if let Some(confidence) = word.confidence {
+        let _ = write!(out, "; x_wconf {}", confidence);
+    }
+    if let Some(font) = &word.font {
+        let _ = write!(out, "; x_font {}", escape(font));
+    }
+    if let Some(font_size) = word.font_size {
+        let _ = write!(out, "; x_fsize {}", font_size);
+    }
+    out.push('\'');
+    write_optional_attribute(out, "lang", &word.lang);
+    write_optional_attribute(out, "dir", &word.dir);
+    out.push('>');
+    if word.is_bold {
+        out.push_str("<strong>");
+    }
+    if word.is_italic {
+        out.push_str("<em>");
+    }
+    out.push_str(&escape(&word.text));
+    if word.is_italic {
+        out.push_str("</em>");
+    }
+    if word.is_bold {
+        out.push_str("</strong>");
+    }
+    out.push_str("</span>");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"  <div class='ocr_page' id='page_1' title='image "scans/a;b.png"; bbox 0 0 1200 400; ppageno 0; scan_res 70 70'>
+   <div class='ocr_carea' id='block_1_1' title="bbox 36 92 1164 151">
+    <p class='ocr_par' id='par_1_1' lang='eng' title="bbox 36 92 1164 151">
+     <span class='ocr_line' id='line_1_1' title="bbox 36 92 1164 151; baseline 0.001 -11; x_size 48; x_descenders 11; x_ascenders 12">
+      <span class='ocrx_word' id='word_1_1' title='bbox 36 92 118 140; x_wconf 96'>This</span>
+      <span class='ocrx_word' id='word_1_2' title='bbox 140 92 190 140; x_wconf 95'><strong>R&amp;D</strong></span>
+     </span>
+    </p>
+   </div>
+  </div>
+"#;
+
+    #[test]
+    fn test_parse_fragment() {
+        let document = HocrDocument::parse(PAGE).unwrap();
+        assert_eq!(document.pages.len(), 1);
+        let page = &document.pages[0];
+        assert_eq!(page.id, "page_1");
+        assert_eq!(page.image.as_deref(), Some("scans/a;b.png"));
+        assert_eq!(page.bbox.right, 1200);
+        assert_eq!(page.page_number, Some(0));
+        assert_eq!(page.scan_res, Some((70, 70)));
+
+        let paragraph = &page.areas[0].paragraphs[0];
+        assert_eq!(paragraph.lang.as_deref(), Some("eng"));
+        let line = &paragraph.lines[0];
+        assert_eq!(line.kind, HocrLineKind::Line);
+        assert_eq!(
+            line.baseline,
+            Some(HocrBaseline {
+                slope: 0.001,
+                offset: -11.0
+            })
+        );
+        assert_eq!(line.x_size, Some(48.0));
+        assert_eq!(line.words.len(), 2);
+        assert_eq!(line.words[0].confidence, Some(96.0));
+        assert_eq!(line.words[1].text, "R&D");
+        assert!(line.words[1].is_bold);
+        assert_eq!(document.text(), "This R&D");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut document = HocrDocument::parse(PAGE).unwrap();
+        document.ocr_system = Some("tesseract 5.5.2".into());
+        document.pages[0].areas[0].paragraphs[0].lines[0].words[0].text = "<Thus>".into();
+        let written = document.to_hocr();
+        assert!(written.contains("<meta name='ocr-system' content='tesseract 5.5.2' />"));
+        assert!(written.contains("&lt;Thus&gt;"));
+        assert_eq!(HocrDocument::parse(&written).unwrap(), document);
+    }
+
+    #[test]
+    fn test_concatenated_pages() {
+        let document: HocrDocument = format!("{}{}", PAGE, PAGE).parse().unwrap();
+        assert_eq!(document.pages.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_hocr() {
+        assert!(matches!(
+            HocrDocument::parse("<div class='ocr_page'>"),
+            Err(TesseractError::ParseError { format: "hOCR", .. })
+        ));
+        assert!(matches!(
+            HocrDocument::parse("<div class='ocr_page' title='bbox 0 0 x 10'></div>"),
+            Err(TesseractError::ParseError { .. })
+        ));
+        assert!(matches!(
+            HocrDocument::parse("<div><span class='ocrx_word'>word</span></div>"),
+            Err(TesseractError::ParseError { .. })
+        ));
+    }
+}
@@ -0,0 +1,232 @@
+//! Integration with the [`image`](https://crates.io/crates/image) crate.
+//!
+//! This module lets `DynamicImage` and `ImageBuffer` values be handed to
+//! Tesseract directly, choosing the right pixel layout for each color type.
+
+use crate::{Result, TesseractAPI, TesseractError};
+use ::image::{DynamicImage, GrayImage, ImageBuffer, Luma, Rgb, RgbImage, Rgba};
+use std::borrow::Cow;
+use std::ops::Deref;
+
+impl TesseractAPI {
+    /// Sets an image from the `image` crate for OCR processing.
+    ///
+    /// 8-bit grayscale and RGB images are passed to Tesseract without copying.
+    /// Other formats are converted first: 16-bit and floating point samples are
+    /// scaled down to 8 bits, and images with an alpha channel are composited
+    /// onto a white background so transparent areas do not read as black.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to recognize.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if setting the image is successful, otherwise returns an error.
+    pub fn set_dynamic_image(&self, image: &DynamicImage) -> Result<()> {
+        let (data, bytes_per_pixel) = tesseract_layout(image);
+        self.set_image_data(&data, image.width(), image.height(), bytes_per_pixel)
+    }
+
+    /// Sets an image from the `image` crate together with its resolution.
+    ///
+    /// The `image` crate does not keep the DPI stored in image files, so pass
+    /// it here when it is known; Tesseract otherwise has to estimate it.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to recognize.
+    /// * `ppi` - Resolution of the image in pixels per inch.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if setting the image is successful, otherwise returns an error.
+    pub fn set_dynamic_image_with_resolution(&self, image: &DynamicImage, ppi: i32) -> Result<()> {
+        self.set_dynamic_image(image)?;
+        self.set_source_resolution(ppi)
+    }
+
+    /// Sets an 8-bit grayscale image buffer for OCR processing.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to recognize.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if setting the image is successful, otherwise returns an error.
+    pub fn set_gray_image<C>(&self, image: &ImageBuffer<Luma<u8>, C>) -> Result<()>
+    where
+        C: Deref<Target = [u8]>,
+    {
+        self.set_image_data(image.as_raw(), image.width(), image.height(), 1)
+    }
+
+    /// Sets an 8-bit RGB image buffer for OCR processing.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to recognize.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if setting the image is successful, otherwise returns an error.
+    pub fn set_rgb_image<C>(&self, image: &ImageBuffer<Rgb<u8>, C>) -> Result<()>
+    where
+        C: Deref<Target = [u8]>,
+    {
+        self.set_image_data(image.as_raw(), image.width(), image.height(), 3)
+    }
+
+    /// Sets an 8-bit RGBA image buffer for OCR processing.
+    ///
+    /// The image is composited onto a white background before it is passed
+    /// to Tesseract, which ignores the alpha channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to recognize.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if setting the image is successful, otherwise returns an error.
+    pub fn set_rgba_image<C>(&self, image: &ImageBuffer<Rgba<u8>, C>) -> Result<()>
+    where
+        C: Deref<Target = [u8]>,
+    {
+        let rgb = flatten_rgba(image);
+        self.set_image_data(rgb.as_raw(), rgb.width(), rgb.height(), 3)
+    }
+
+    /// Passes tightly packed 8-bit pixel data to `set_image`.
+    fn set_image_data(
+        &self,
+        data: &[u8],
+        width: u32,
+        height: u32,
+        bytes_per_pixel: u32,
+    ) -> Result<()> {
+        let width = i32::try_from(width).map_err(|_| TesseractError::InvalidDimensions)?;
+        let height = i32::try_from(height).map_err(|_| TesseractError::InvalidDimensions)?;
+        let bytes_per_pixel = bytes_per_pixel as i32;
+        let bytes_per_line = width
+            .checked_mul(bytes_per_pixel)
+            .ok_or(TesseractError::InvalidDimensions)?;
+        self.set_image(data, width, height, bytes_per_pixel, bytes_per_line)
+    }
+}
+
+/// Converts an image into pixel data Tesseract understands.
+///
+/// Returns tightly packed 8-bit samples and the number of bytes per pixel
+/// (1 for grayscale, 3 for RGB). Luma8 and Rgb8 images are borrowed as-is.
+fn tesseract_layout(image: &DynamicImage) -> (Cow<'_, [u8]>, u32) {
+    match image {
+        DynamicImage::ImageLuma8(buffer) => (Cow::Borrowed(buffer.as_raw().as_slice()), 1),
+        DynamicImage::ImageRgb8(buffer) => (Cow::Borrowed(buffer.as_raw().as_slice()), 3),
+        _ => {
+            let color = image.color();
+            match (color.has_color(), color.has_alpha()) {
+                (true, true) => (Cow::Owned(flatten_rgba(&image.to_rgba8()).into_raw()), 3),
+                (true, false) => (Cow::Owned(image.to_rgb8().into_raw()), 3),
+                (false, true) => (Cow::Owned(flatten_luma_alpha(image).into_raw()), 1),
+                (false, false) => (Cow::Owned(image.to_luma8().into_raw()), 1),
+            }
+        }
+    }
+}
+
+/// Composites an RGBA image onto a white background.
+fn flatten_rgba<C>(image: &ImageBuffer<Rgba<u8>, C>) -> RgbImage
+where
+    C: Deref<Target = [u8]>,
+{
+    RgbImage::from_fn(image.width(), image.height(), |x, y| {
+        let Rgba([r, g, b, a]) = *image.get_pixel(x, y);
+        Rgb([over_white(r, a), over_white(g, a), over_white(b, a)])
+    })
+}
+
+/// Composites a grayscale image with alpha onto a white background.
+fn flatten_luma_alpha(image: &DynamicImage) -> GrayImage {
+    let luma_alpha = image.to_luma_alpha8();
+    GrayImage::from_fn(image.width(), image.height(), |x, y| {
+        let [l, a] = luma_alpha.get_pixel(x, y).0;
+        Luma([over_white(l, a)])
+    })
+}
+
+/// Blends one 8-bit sample with white according to its alpha.
+fn over_white(value: u8, alpha: u8) -> u8 {
+    let value = value as u32;
+    let alpha = alpha as u32;
+    ((value * alpha + 255 * (255 - alpha) + 127) / 255) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::image::{ImageBuffer, LumaA};
+
+    #[test]
+    fn test_luma8_is_borrowed() {
+        let image = DynamicImage::ImageLuma8(GrayImage::from_pixel(4, 2, Luma([7])));
+        let (data, bytes_per_pixel) = tesseract_layout(&image);
+        assert!(matches!(data, Cow::Borrowed(_)));
+        assert_eq!(bytes_per_pixel, 1);
+        assert_eq!(data.len(), 8);
+    }
+
+    #[test]
+    fn test_rgb8_is_borrowed() {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 2, Rgb([1, 2, 3])));
+        let (data, bytes_per_pixel) = tesseract_layout(&image);
+        assert!(matches!(data, Cow::Borrowed(_)));
+        assert_eq!(bytes_per_pixel, 3);
+        assert_eq!(&data[..3], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_transparent_rgba_becomes_white() {
+        let mut buffer = ImageBuffer::from_pixel(2, 1, Rgba([0u8, 0, 0, 0]));
+        buffer.put_pixel(1, 0, Rgba([10, 20, 30, 255]));
+        let image = DynamicImage::ImageRgba8(buffer);
+        let (data, bytes_per_pixel) = tesseract_layout(&image);
+        assert_eq!(bytes_per_pixel, 3);
+        assert_eq!(&data[..], &[255, 255, 255, 10, 20, 30]);
+    }
+
+    #[test]
+    fn test_half_transparent_luma_alpha_is_blended() {
+        let buffer = ImageBuffer::from_pixel(1, 1, LumaA([0u8, 128]));
+        let image = DynamicImage::ImageLumaA8(buffer);
+        let (data, bytes_per_pixel) = tesseract_layout(&image);
+        assert_eq!(bytes_per_pixel, 1);
+        assert_eq!(&data[..], &[127]);
+    }
+
+    #[test]
+    fn test_16_bit_gray_is_scaled_to_8_bit() {
+        let buffer = ImageBuffer::from_pixel(3, 1, Luma([u16::MAX]));
+        let image = DynamicImage::ImageLuma16(buffer);
+        let (data, bytes_per_pixel) = tesseract_layout(&image);
+        assert_eq!(bytes_per_pixel, 1);
+        assert_eq!(&data[..], &[255, 255, 255]);
+    }
+
+    #[test]
+    fn test_16_bit_rgb_is_scaled_to_8_bit() {
+        let buffer = ImageBuffer::from_pixel(1, 1, Rgb([0u16, 32896, u16::MAX]));
+        let image = DynamicImage::ImageRgb16(buffer);
+        let (data, bytes_per_pixel) = tesseract_layout(&image);
+        assert_eq!(bytes_per_pixel, 3);
+        assert_eq!(&data[..], &[0, 128, 255]);
+    }
+
+    #[test]
+    fn test_over_white() {
+        assert_eq!(over_white(0, 255), 0);
+        assert_eq!(over_white(0, 0), 255);
+        assert_eq!(over_white(200, 255), 200);
+    }
+}
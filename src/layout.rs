@@ -0,0 +1,325 @@
+//! Layout-only analysis: regions, text lines, strips, words and connected
+//! components found by page segmentation, without running recognition.
+
+use crate::api::TessDeleteIntArray;
+use crate::enums::TessPageIteratorLevel;
+use crate::error::{Result, TesseractError};
+use crate::pix::{Boxa, Pix, Pixa};
+use crate::types::BoundingBox;
+use crate::TesseractAPI;
+use std::os::raw::{c_int, c_void};
+
+/// A component found by layout analysis, with its image crop.
+#[derive(Debug, Clone)]
+pub struct LayoutComponent {
+    /// Bounding box of the component in image coordinates.
+    pub bounding_box: BoundingBox,
+    /// Crop of the page image covering the component.
+    pub image: Pix,
+    /// Index of the block the component belongs to, when Tesseract reports it.
+    pub block_id: Option<i32>,
+    /// Index of the paragraph the component belongs to, when Tesseract reports it.
+    pub paragraph_id: Option<i32>,
+}
+
+impl TesseractAPI {
+    /// Gets the page regions found by layout analysis.
+    ///
+    /// Layout analysis runs on the current image if it has not been done yet;
+    /// recognition is not needed.
+    ///
+    /// # Returns
+    ///
+    /// Returns the regions with their crops if successful, otherwise returns an error.
+    pub fn get_regions(&self) -> Result<Vec<LayoutComponent>> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut pixa = std::ptr::null_mut();
+        let boxa = unsafe { TessBaseAPIGetRegions(*handle, &mut pixa) };
+        unsafe { collect(boxa, pixa, std::ptr::null_mut(), std::ptr::null_mut()) }
+    }
+
+    /// Gets the text lines found by layout analysis.
+    ///
+    /// # Returns
+    ///
+    /// Returns the text lines with their crops and block ids if successful,
+    /// otherwise returns an error.
+    pub fn get_textlines(&self) -> Result<Vec<LayoutComponent>> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut pixa = std::ptr::null_mut();
+        let mut blockids = std::ptr::null_mut();
+        let boxa = unsafe { TessBaseAPIGetTextlines(*handle, &mut pixa, &mut blockids) };
+        unsafe { collect(boxa, pixa, blockids, std::ptr::null_mut()) }
+    }
+
+    /// Gets the text lines found by layout analysis, optionally cropped from
+    /// the original image.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_image` - Whether to crop the original image instead of the thresholded one.
+    /// * `raw_padding` - Padding in pixels around the crops from the original image.
+    ///
+    /// # Returns
+    ///
+    /// Returns the text lines with their crops, block ids and paragraph ids if
+    /// successful, otherwise returns an error.
+    pub fn get_textlines_1(
+        &self,
+        raw_image: bool,
+        raw_padding: i32,
+    ) -> Result<Vec<LayoutComponent>> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut pixa = std::ptr::null_mut();
+        let mut blockids = std::ptr::null_mut();
+        let mut paraids = std::ptr::null_mut();
+        let boxa = unsafe {
+            TessBaseAPIGetTextlines1(
+                *handle,
+                raw_image as c_int,
+                raw_padding,
+                &mut pixa,
+                &mut blockids,
+                &mut paraids,
+            )
+        };
+        unsafe { collect(boxa, pixa, blockids, paraids) }
+    }
+
+    /// Gets the text lines found by layout analysis as strips, following the
+    /// shape of their blocks rather than plain rectangles.
+    ///
+    /// # Returns
+    ///
+    /// Returns the strips with their crops and block ids if successful,
+    /// otherwise returns an error.
+    pub fn get_strips(&self) -> Result<Vec<LayoutComponent>> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut pixa = std::ptr::null_mut();
+        let mut blockids = std::ptr::null_mut();
+        let boxa = unsafe { TessBaseAPIGetStrips(*handle, &mut pixa, &mut blockids) };
+        unsafe { collect(boxa, pixa, blockids, std::ptr::null_mut()) }
+    }
+
+    /// Gets the words found by layout analysis.
+    ///
+    /// # Returns
+    ///
+    /// Returns the words with their crops if successful, otherwise returns an error.
+    pub fn get_words(&self) -> Result<Vec<LayoutComponent>> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut pixa = std::ptr::null_mut();
+        let boxa = unsafe { TessBaseAPIGetWords(*handle, &mut pixa) };
+        unsafe { collect(boxa, pixa, std::ptr::null_mut(), std::ptr::null_mut()) }
+    }
+
+    /// Gets the connected components of the thresholded image.
+    ///
+    /// # Returns
+    ///
+    /// Returns the connected components with their crops if successful,
+    /// otherwise returns an error.
+    pub fn get_connected_components(&self) -> Result<Vec<LayoutComponent>> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut pixa = std::ptr::null_mut();
+        let boxa = unsafe { TessBaseAPIGetConnectedComponents(*handle, &mut pixa) };
+        unsafe { collect(boxa, pixa, std::ptr::null_mut(), std::ptr::null_mut()) }
+    }
+
+    /// Gets the components found by layout analysis at a given level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Level of the components (block, paragraph, line, word or symbol).
+    /// * `text_only` - Whether to skip non-text blocks such as images and rules.
+    ///
+    /// # Returns
+    ///
+    /// Returns the components with their crops and block ids if successful,
+    /// otherwise returns an error.
+    pub fn get_component_images(
+        &self,
+        level: TessPageIteratorLevel,
+        text_only: bool,
+    ) -> Result<Vec<LayoutComponent>> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut pixa = std::ptr::null_mut();
+        let mut blockids = std::ptr::null_mut();
+        let boxa = unsafe {
+            TessBaseAPIGetComponentImages(
+                *handle,
+                level as c_int,
+                text_only as c_int,
+                &mut pixa,
+                &mut blockids,
+            )
+        };
+        unsafe { collect(boxa, pixa, blockids, std::ptr::null_mut()) }
+    }
+
+    /// Gets the components found by layout analysis at a given level,
+    /// optionally cropped from the original image.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Level of the components (block, paragraph, line, word or symbol).
+    /// * `text_only` - Whether to skip non-text blocks such as images and rules.
+    /// * `raw_image` - Whether to crop the original image instead of the thresholded one.
+    /// * `raw_padding` - Padding in pixels around the crops from the original image.
+    ///
+    /// # Returns
+    ///
+    /// Returns the components with their crops, block ids and paragraph ids if
+    /// successful, otherwise returns an error.
+    pub fn get_component_images_1(
+        &self,
+        level: TessPageIteratorLevel,
+        text_only: bool,
+        raw_image: bool,
+        raw_padding: i32,
+    ) -> Result<Vec<LayoutComponent>> {
+        let _diagnostics = self.diagnostics_scope();
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut pixa = std::ptr::null_mut();
+        let mut blockids = std::ptr::null_mut();
+        let mut paraids = std::ptr::null_mut();
+        let boxa = unsafe {
+            TessBaseAPIGetComponentImages1(
+                *handle,
+                level as c_int,
+                text_only as c_int,
+                raw_image as c_int,
+                raw_padding,
+                &mut pixa,
+                &mut blockids,
+                &mut paraids,
+            )
+        };
+        unsafe { collect(boxa, pixa, blockids, paraids) }
+    }
+}
+
+/// An id array allocated by Tesseract, released with `TessDeleteIntArray`.
+struct IdArray(*mut c_int);
+
+impl IdArray {
+    fn get(&self, index: usize) -> Option<i32> {
+        (!self.0.is_null()).then(|| unsafe { *self.0.add(index) })
+    }
+}
+
+impl Drop for IdArray {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { TessDeleteIntArray(self.0) };
+        }
+    }
+}
+
+/// Takes ownership of the results of a `TessBaseAPIGet*` layout call and
+/// pairs each box with its crop and ids.
+///
+/// # Safety
+///
+/// The pointers must come from the same call, and each id array must be null
+/// or hold one entry per box.
+unsafe fn collect(
+    boxa: *mut c_void,
+    pixa: *mut c_void,
+    blockids: *mut c_int,
+    paraids: *mut c_int,
+) -> Result<Vec<LayoutComponent>> {
+    let blockids = IdArray(blockids);
+    let paraids = IdArray(paraids);
+    let pixa = Pixa::from_raw(pixa);
+    let boxa = Boxa::from_raw(boxa).map_err(|_| TesseractError::AnalyseLayoutError)?;
+    let pixa = pixa?;
+    boxa.iter()
+        .enumerate()
+        .map(|(index, bounding_box)| {
+            Ok(LayoutComponent {
+                bounding_box,
+                image: pixa.get(index).ok_or(TesseractError::NullPointerError)?,
+                block_id: blockids.get(index),
+                paragraph_id: paraids.get(index),
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "build-tesseract")]
+#[link(name = "tesseract")]
+extern "C" {
+    fn TessBaseAPIGetRegions(handle: *mut c_void, pixa: *mut *mut c_void) -> *mut c_void;
+    fn TessBaseAPIGetTextlines(
+        handle: *mut c_void,
+        pixa: *mut *mut c_void,
+        blockids: *mut *mut c_int,
+    ) -> *mut c_void;
+    fn TessBaseAPIGetTextlines1(
+        handle: *mut c_void,
+        raw_image: c_int,
+        raw_padding: c_int,
+        pixa: *mut *mut c_void,
+        blockids: *mut *mut c_int,
+        paraids: *mut *mut c_int,
+    ) -> *mut c_void;
+    fn TessBaseAPIGetStrips(
+        handle: *mut c_void,
+        pixa: *mut *mut c_void,
+        blockids: *mut *mut c_int,
+    ) -> *mut c_void;
+    fn TessBaseAPIGetWords(handle: *mut c_void, pixa: *mut *mut c_void) -> *mut c_void;
+    fn TessBaseAPIGetConnectedComponents(
+        handle: *mut c_void,
+        pixa: *mut *mut c_void,
+    ) -> *mut c_void;
+    fn TessBaseAPIGetComponentImages(
+        handle: *mut c_void,
+        level: c_int,
+        text_only: c_int,
+        pixa: *mut *mut c_void,
+        blockids: *mut *mut c_int,
+    ) -> *mut c_void;
+    fn TessBaseAPIGetComponentImages1(
+        handle: *mut c_void,
+        level: c_int,
+        text_only: c_int,
+        raw_image: c_int,
+        raw_padding: c_int,
+        pixa: *mut *mut c_void,
+        blockids: *mut *mut c_int,
+        paraids: *mut *mut c_int,
+    ) -> *mut c_void;
+}
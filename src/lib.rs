@@ -129,22 +129,77 @@
 pub use error::{Result, TesseractError};
 mod error;
 mod page_iterator;
-pub use page_iterator::PageIterator;
+pub use page_iterator::{IteratorPosition, PageIterator};
 mod result_iterator;
-pub use result_iterator::ResultIterator;
+pub use result_iterator::{PageIteratorView, ResultIterator};
 mod choice_iterator;
 pub use choice_iterator::ChoiceIterator;
 mod monitor;
-pub use monitor::TessMonitor;
+pub use monitor::{CancellationToken, TessMonitor};
+mod pix;
+pub use pix::{Boxa, Pix, Pixa};
 mod result_renderer;
-pub use result_renderer::TessResultRenderer;
+pub use result_renderer::{ChainedRenderer, MemoryRenderer, TessResultRenderer};
 mod enums;
 pub use enums::{
-    TessOrientation, TessPageIteratorLevel, TessPageSegMode, TessParagraphJustification,
-    TessPolyBlockType, TessTextlineOrder, TessWritingDirection,
+    OcrEngineMode, TessOrientation, TessPageIteratorLevel, TessPageSegMode,
+    TessParagraphJustification, TessPolyBlockType, TessTextlineOrder, TessWritingDirection,
 };
 mod api;
 pub use api::TesseractAPI;
+mod builder;
+pub use builder::TesseractBuilder;
+mod document;
+pub use document::{Block, Document, Line, Page, Paragraph, Symbol, Word, JSON_SCHEMA_VERSION};
+mod types;
+pub use types::{
+    Baseline, BoundingBox, FontAttributes, ImageCrop, OrientationInfo, OsdResult, PageResult,
+    ParagraphInfo, WordBox,
+};
+mod pool;
+pub use pool::{PoolStats, PooledTesseract, TesseractPool};
+mod parameters;
+pub use parameters::{Parameter, ParameterType, ParameterValue, TypedParameter};
+mod diagnostics;
+mod layout;
+pub use layout::LayoutComponent;
+mod pages;
+mod renderer;
+#[cfg(feature = "serde")]
+pub use renderer::JsonRenderer;
+pub use renderer::{CsvRenderer, MarkdownRenderer, Renderer};
+mod tempdir;
+mod traineddata;
+#[cfg(feature = "log")]
+pub use diagnostics::log_diagnostics;
+pub use diagnostics::{clear_diagnostic_handler, set_diagnostic_handler, DiagnosticSource};
+
+#[cfg(feature = "image")]
+mod image_support;
+
+#[cfg(any(feature = "hocr", feature = "alto"))]
+mod xml;
+
+#[cfg(feature = "hocr")]
+mod hocr;
+#[cfg(feature = "hocr")]
+pub use hocr::{
+    HocrArea, HocrBaseline, HocrDocument, HocrLine, HocrLineKind, HocrPage, HocrParagraph, HocrWord,
+};
+
+#[cfg(feature = "alto")]
+mod alto;
+#[cfg(feature = "alto")]
+pub use alto::{
+    AltoBlock, AltoComposedBlock, AltoDescription, AltoDocument, AltoLineItem, AltoPage,
+    AltoPrintSpace, AltoProcessing, AltoSoftware, AltoSpace, AltoString, AltoTextBlock,
+    AltoTextLine, ALTO_NAMESPACE,
+};
+
+#[cfg(feature = "tokio")]
+mod async_tesseract;
+#[cfg(feature = "tokio")]
+pub use async_tesseract::AsyncTesseract;
 
 #[cfg(feature = "embed-tessdata")]
 mod embedded;
@@ -1,9 +1,61 @@
 use crate::error::{Result, TesseractError};
 use std::os::raw::{c_int, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Token for cancelling a recognition from another thread.
+///
+/// Clones share the same state, so one clone can be attached to a
+/// [`TessMonitor`] while another is cancelled elsewhere.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a token that is not cancelled.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of the recognitions monitored with this token.
+    ///
+    /// Tesseract checks for cancellation after each word, so the running
+    /// recognition stops shortly afterwards.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether cancellation has been requested.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` once `cancel` has been called on any clone.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Rust state reachable from Tesseract's callbacks through `cancel_this`.
+#[derive(Default)]
+struct MonitorCallbacks {
+    progress: Option<Box<dyn FnMut(i32) + Send>>,
+    cancel: Option<Box<dyn FnMut(i32) -> bool + Send>>,
+    token: Option<CancellationToken>,
+    /// Set when a callback or the token stopped the current recognition.
+    cancelled: bool,
+}
 
 pub struct TessMonitor {
     handle: Arc<Mutex<*mut c_void>>,
+    callbacks: Box<Mutex<MonitorCallbacks>>,
+    deadline: Mutex<Option<Instant>>,
 }
 
 unsafe impl Send for TessMonitor {}
@@ -17,8 +69,17 @@ impl TessMonitor {
     /// Returns the new instance of the TessMonitor.
     pub fn new() -> Self {
         let handle = unsafe { TessMonitorCreate() };
+        let callbacks = Box::new(Mutex::new(MonitorCallbacks::default()));
+        unsafe {
+            let cancel_this = &*callbacks as *const Mutex<MonitorCallbacks> as *mut c_void;
+            TessMonitorSetCancelThis(handle, cancel_this);
+            TessMonitorSetCancelFunc(handle, Some(cancel_callback));
+            TessMonitorSetProgressFunc(handle, Some(progress_callback));
+        }
         TessMonitor {
             handle: Arc::new(Mutex::new(handle)),
+            callbacks,
+            deadline: Mutex::new(None),
         }
     }
 
@@ -33,6 +94,11 @@ impl TessMonitor {
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
         unsafe { TessMonitorSetDeadlineMSecs(*handle, deadline) };
+        *self
+            .deadline
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)? =
+            Some(Instant::now() + Duration::from_millis(deadline.max(0) as u64));
         Ok(())
     }
 
@@ -48,6 +114,83 @@ impl TessMonitor {
             .map_err(|_| TesseractError::MutexLockError)?;
         Ok(unsafe { TessMonitorGetProgress(*handle) })
     }
+
+    /// Sets a closure called with the progress (0-100) after each word.
+    ///
+    /// The closure runs on the thread performing the recognition and must not
+    /// call this monitor's setters.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Closure receiving the progress in percent.
+    pub fn set_progress_callback<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnMut(i32) + Send + 'static,
+    {
+        self.callbacks()?.progress = Some(Box::new(callback));
+        Ok(())
+    }
+
+    /// Sets a closure asked after each word whether to stop the recognition.
+    ///
+    /// The closure runs on the thread performing the recognition and must not
+    /// call this monitor's setters.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Closure receiving the number of dictionary words found
+    ///   so far and returning `true` to cancel.
+    pub fn set_cancel_callback<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnMut(i32) -> bool + Send + 'static,
+    {
+        self.callbacks()?.cancel = Some(Box::new(callback));
+        Ok(())
+    }
+
+    /// Attaches a cancellation token checked after each word.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Token whose cancellation stops the recognition.
+    pub fn set_cancellation_token(&self, token: CancellationToken) -> Result<()> {
+        self.callbacks()?.token = Some(token);
+        Ok(())
+    }
+
+    /// Prepares the monitor for a recognition.
+    ///
+    /// # Returns
+    ///
+    /// Returns the raw monitor to pass to Tesseract.
+    pub(crate) fn start(&self) -> Result<*mut c_void> {
+        self.callbacks()?.cancelled = false;
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        Ok(*handle)
+    }
+
+    /// Explains why a monitored recognition failed.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Cancelled` or `DeadlineExceeded` if the monitor stopped the
+    /// recognition, otherwise `None`.
+    pub(crate) fn stop_reason(&self) -> Option<TesseractError> {
+        if self.callbacks().ok()?.cancelled {
+            return Some(TesseractError::Cancelled);
+        }
+        let deadline = (*self.deadline.lock().ok()?)?;
+        (Instant::now() >= deadline).then_some(TesseractError::DeadlineExceeded)
+    }
+
+    fn callbacks(&self) -> Result<std::sync::MutexGuard<'_, MonitorCallbacks>> {
+        self.callbacks
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)
+    }
 }
 
 impl Drop for TessMonitor {
@@ -58,6 +201,64 @@ impl Drop for TessMonitor {
     }
 }
 
+/// Called by Tesseract after each word with the monitor's `cancel_this`.
+extern "C" fn cancel_callback(cancel_this: *mut c_void, words: c_int) -> bool {
+    if cancel_this.is_null() {
+        return false;
+    }
+    let callbacks = unsafe { &*(cancel_this as *const Mutex<MonitorCallbacks>) };
+    // A panicking closure cannot unwind into Tesseract; stop recognizing instead
+    catch_unwind(AssertUnwindSafe(|| {
+        let mut callbacks = match callbacks.lock() {
+            Ok(callbacks) => callbacks,
+            Err(_) => return true,
+        };
+        let token_cancelled = callbacks
+            .token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled);
+        let cancel = token_cancelled || callbacks.cancel.as_mut().is_some_and(|f| f(words));
+        if cancel {
+            callbacks.cancelled = true;
+        }
+        cancel
+    }))
+    .unwrap_or(true)
+}
+
+/// Called by Tesseract after each word with the monitor itself.
+extern "C" fn progress_callback(
+    monitor: *mut c_void,
+    _left: c_int,
+    _right: c_int,
+    _top: c_int,
+    _bottom: c_int,
+) -> bool {
+    let cancel_this = unsafe { TessMonitorGetCancelThis(monitor) };
+    if cancel_this.is_null() {
+        return true;
+    }
+    let callbacks = unsafe { &*(cancel_this as *const Mutex<MonitorCallbacks>) };
+    let progress = unsafe { TessMonitorGetProgress(monitor) };
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        if let Ok(mut callbacks) = callbacks.lock() {
+            if let Some(callback) = callbacks.progress.as_mut() {
+                callback(progress);
+            }
+        }
+    }));
+    true
+}
+
+type TessCancelFunc = extern "C" fn(cancel_this: *mut c_void, words: c_int) -> bool;
+type TessProgressFunc = extern "C" fn(
+    monitor: *mut c_void,
+    left: c_int,
+    right: c_int,
+    top: c_int,
+    bottom: c_int,
+) -> bool;
+
 #[cfg(feature = "build-tesseract")]
 #[link(name = "tesseract")]
 extern "C" {
@@ -65,4 +266,22 @@ extern "C" {
     pub fn TessMonitorDelete(monitor: *mut c_void);
     pub fn TessMonitorSetDeadlineMSecs(monitor: *mut c_void, deadline: c_int);
     pub fn TessMonitorGetProgress(monitor: *mut c_void) -> c_int;
+    fn TessMonitorSetCancelFunc(monitor: *mut c_void, cancel_func: Option<TessCancelFunc>);
+    fn TessMonitorSetCancelThis(monitor: *mut c_void, cancel_this: *mut c_void);
+    fn TessMonitorGetCancelThis(monitor: *mut c_void) -> *mut c_void;
+    fn TessMonitorSetProgressFunc(monitor: *mut c_void, progress_func: Option<TessProgressFunc>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancellation_token_clones_share_state() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
 }
@@ -3,12 +3,18 @@ use crate::enums::{
     TessTextlineOrder, TessWritingDirection,
 };
 use crate::error::{Result, TesseractError};
+use crate::pix::Pix;
+use crate::result_iterator::TessResultIteratorGetPageIterator;
+use crate::types::{Baseline, BoundingBox, ImageCrop, OrientationInfo, ParagraphInfo};
+use std::ops::Deref;
 use std::os::raw::{c_float, c_int, c_void};
 use std::sync::Arc;
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 
 pub struct PageIterator {
     pub handle: Arc<Mutex<*mut c_void>>,
+    /// Set when `handle` is owned by a `ResultIterator` this is a view of.
+    borrowed: bool,
 }
 
 unsafe impl Send for PageIterator {}
@@ -27,15 +33,38 @@ impl PageIterator {
     pub fn new(handle: *mut c_void) -> Self {
         PageIterator {
             handle: Arc::new(Mutex::new(handle)),
+            borrowed: false,
         }
     }
 
-    /// Begins the iteration.
-    pub fn begin(&self) -> Result<()> {
-        let handle = self
+    /// Creates a page iterator sharing the lock and position of a result
+    /// iterator, which keeps ownership of the handle.
+    pub(crate) fn result_view(handle: Arc<Mutex<*mut c_void>>) -> Self {
+        PageIterator {
+            handle,
+            borrowed: true,
+        }
+    }
+
+    fn lock(&self) -> Result<Locked<'_>> {
+        let guard = self
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
+        let handle = if self.borrowed {
+            unsafe { TessResultIteratorGetPageIterator(*guard) }
+        } else {
+            *guard
+        };
+        Ok(Locked {
+            _guard: guard,
+            handle,
+        })
+    }
+
+    /// Begins the iteration.
+    pub fn begin(&self) -> Result<()> {
+        let handle = self.lock()?;
         unsafe { TessPageIteratorBegin(*handle) };
         Ok(())
     }
@@ -50,10 +79,7 @@ impl PageIterator {
     ///
     /// Returns `true` if the next iterator is successful, otherwise returns `false`.
     pub fn next(&self, level: TessPageIteratorLevel) -> Result<bool> {
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
+        let handle = self.lock()?;
         Ok(unsafe { TessPageIteratorNext(*handle, level as c_int) != 0 })
     }
 
@@ -67,10 +93,7 @@ impl PageIterator {
     ///
     /// Returns `true` if the current iterator is at the beginning of the specified level, otherwise returns `false`.
     pub fn is_at_beginning_of(&self, level: TessPageIteratorLevel) -> Result<bool> {
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
+        let handle = self.lock()?;
         Ok(unsafe { TessPageIteratorIsAtBeginningOf(*handle, level as c_int) != 0 })
     }
 
@@ -89,10 +112,7 @@ impl PageIterator {
         level: TessPageIteratorLevel,
         element: TessPageIteratorLevel,
     ) -> Result<bool> {
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
+        let handle = self.lock()?;
         Ok(unsafe {
             TessPageIteratorIsAtFinalElement(*handle, level as c_int, element as c_int) != 0
         })
@@ -106,31 +126,10 @@ impl PageIterator {
     ///
     /// # Returns
     ///
-    /// Returns the bounding box as a tuple if successful, otherwise returns an error.
-    pub fn bounding_box(&self, level: TessPageIteratorLevel) -> Result<(i32, i32, i32, i32)> {
-        let mut left = 0;
-        let mut top = 0;
-        let mut right = 0;
-        let mut bottom = 0;
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
-        let result = unsafe {
-            TessPageIteratorBoundingBox(
-                *handle,
-                level as c_int,
-                &mut left,
-                &mut top,
-                &mut right,
-                &mut bottom,
-            )
-        };
-        if result == 0 {
-            Err(TesseractError::InvalidParameterError)
-        } else {
-            Ok((left, top, right, bottom))
-        }
+    /// Returns the bounding box if successful, otherwise returns an error.
+    pub fn bounding_box(&self, level: TessPageIteratorLevel) -> Result<BoundingBox> {
+        let handle = self.lock()?;
+        unsafe { bounding_box_of(*handle, level) }
     }
 
     /// Gets the block type of the current iterator.
@@ -139,10 +138,7 @@ impl PageIterator {
     ///
     /// Returns the block type as a `TessPolyBlockType`.
     pub fn block_type(&self) -> Result<TessPolyBlockType> {
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
+        let handle = self.lock()?;
         let block_type = unsafe { TessPageIteratorBlockType(*handle) };
         Ok(TessPolyBlockType::from_int(block_type))
     }
@@ -155,22 +151,19 @@ impl PageIterator {
     ///
     /// # Returns
     ///
-    /// Returns the baseline as a tuple if successful, otherwise returns an error.
-    pub fn baseline(&self, level: i32) -> Result<(i32, i32, i32, i32)> {
+    /// Returns the baseline if successful, otherwise returns an error.
+    pub fn baseline(&self, level: i32) -> Result<Baseline> {
         let mut x1 = 0;
         let mut y1 = 0;
         let mut x2 = 0;
         let mut y2 = 0;
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
+        let handle = self.lock()?;
         let result =
             unsafe { TessPageIteratorBaseline(*handle, level, &mut x1, &mut y1, &mut x2, &mut y2) };
         if result == 0 {
             Err(TesseractError::InvalidParameterError)
         } else {
-            Ok((x1, y1, x2, y2))
+            Ok(Baseline { x1, y1, x2, y2 })
         }
     }
 
@@ -178,23 +171,13 @@ impl PageIterator {
     ///
     /// # Returns
     ///
-    /// Returns the orientation as a tuple if successful, otherwise returns an error.
-    pub fn orientation(
-        &self,
-    ) -> Result<(
-        TessOrientation,
-        TessWritingDirection,
-        TessTextlineOrder,
-        f32,
-    )> {
+    /// Returns the orientation if successful, otherwise returns an error.
+    pub fn orientation(&self) -> Result<OrientationInfo> {
         let mut orientation = 0;
         let mut writing_direction = 0;
         let mut textline_order = 0;
         let mut deskew_angle = 0.0;
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
+        let handle = self.lock()?;
         let result = unsafe {
             TessPageIteratorOrientation(
                 *handle,
@@ -207,12 +190,12 @@ impl PageIterator {
         if result == 0 {
             Err(TesseractError::InvalidParameterError)
         } else {
-            Ok((
-                TessOrientation::from_int(orientation),
-                TessWritingDirection::from_int(writing_direction),
-                TessTextlineOrder::from_int(textline_order),
+            Ok(OrientationInfo {
+                orientation: TessOrientation::from_int(orientation),
+                writing_direction: TessWritingDirection::from_int(writing_direction),
+                textline_order: TessTextlineOrder::from_int(textline_order),
                 deskew_angle,
-            ))
+            })
         }
     }
 
@@ -220,16 +203,13 @@ impl PageIterator {
     ///
     /// # Returns
     ///
-    /// Returns the paragraph information as a tuple if successful, otherwise returns an error.
-    pub fn paragraph_info(&self) -> Result<(TessParagraphJustification, bool, bool, i32)> {
+    /// Returns the paragraph information if successful, otherwise returns an error.
+    pub fn paragraph_info(&self) -> Result<ParagraphInfo> {
         let mut justification = 0;
-        let mut is_list_item = false;
-        let mut is_crown = false;
+        let mut is_list_item = 0;
+        let mut is_crown = 0;
         let mut first_line_indent = 0;
-        let handle = self
-            .handle
-            .lock()
-            .map_err(|_| TesseractError::MutexLockError)?;
+        let handle = self.lock()?;
         let result = unsafe {
             TessPageIteratorParagraphInfo(
                 *handle,
@@ -242,24 +222,215 @@ impl PageIterator {
         if result == 0 {
             Err(TesseractError::InvalidParameterError)
         } else {
-            Ok((
-                TessParagraphJustification::from_int(justification),
-                is_list_item,
-                is_crown,
+            Ok(ParagraphInfo {
+                justification: TessParagraphJustification::from_int(justification),
+                is_list_item: is_list_item != 0,
+                is_crown: is_crown != 0,
                 first_line_indent,
-            ))
+            })
         }
     }
+
+    /// Gets the thresholded image of the current element.
+    ///
+    /// The crop is taken from the binarized image Tesseract recognized. For
+    /// blocks and paragraphs, pixels outside the block outline are cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Level of the element.
+    ///
+    /// # Returns
+    ///
+    /// Returns the 1-bit crop and its offset if successful, otherwise returns an error.
+    pub fn binary_image(&self, level: TessPageIteratorLevel) -> Result<ImageCrop> {
+        // The offset and the crop must come from the same iterator position.
+        let handle = self.lock()?;
+        let bounding_box = unsafe { bounding_box_of(*handle, level) }?;
+        let pix = unsafe { TessPageIteratorGetBinaryImage(*handle, level as c_int) };
+        Ok(ImageCrop {
+            image: unsafe { Pix::from_raw(pix) }?,
+            left: bounding_box.left,
+            top: bounding_box.top,
+        })
+    }
+
+    /// Gets the image of the current element cut out of the original image.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Level of the element.
+    /// * `padding` - Pixels to add around the element, clipped to the image.
+    /// * `original_image` - The image given to Tesseract, e.g. from
+    ///   [`TesseractAPI::get_input_image`](crate::TesseractAPI::get_input_image).
+    ///
+    /// # Returns
+    ///
+    /// Returns the crop and its offset if successful, otherwise returns an error.
+    pub fn image(
+        &self,
+        level: TessPageIteratorLevel,
+        padding: i32,
+        original_image: &Pix,
+    ) -> Result<ImageCrop> {
+        let mut left = 0;
+        let mut top = 0;
+        let handle = self.lock()?;
+        let pix = unsafe {
+            TessPageIteratorGetImage(
+                *handle,
+                level as c_int,
+                padding,
+                original_image.as_ptr(),
+                &mut left,
+                &mut top,
+            )
+        };
+        Ok(ImageCrop {
+            image: unsafe { Pix::from_raw(pix) }?,
+            left,
+            top,
+        })
+    }
+
+    /// Creates an independent iterator at the same position.
+    ///
+    /// Copying is cheap: the recognition results are shared, only the
+    /// position is duplicated. A copy of a [`PageIteratorView`] is a plain
+    /// page iterator that no longer moves with the result iterator.
+    ///
+    /// [`PageIteratorView`]: crate::PageIteratorView
+    ///
+    /// # Returns
+    ///
+    /// Returns the copy if successful, otherwise returns an error.
+    pub fn try_clone(&self) -> Result<Self> {
+        let handle = self.lock()?;
+        let copy = unsafe { TessPageIteratorCopy(*handle) };
+        if copy.is_null() {
+            Err(TesseractError::NullPointerError)
+        } else {
+            Ok(PageIterator::new(copy))
+        }
+    }
+
+    /// Saves the current position so it can be restored later.
+    ///
+    /// # Returns
+    ///
+    /// Returns the saved position if successful, otherwise returns an error.
+    pub fn save_position(&self) -> Result<IteratorPosition<PageIterator>> {
+        Ok(IteratorPosition {
+            iterator: self.try_clone()?,
+        })
+    }
+
+    /// Moves back to a saved position.
+    ///
+    /// A [`PageIteratorView`] cannot be restored, as its position belongs to
+    /// the result iterator; use [`ResultIterator::restore_position`] instead.
+    ///
+    /// [`PageIteratorView`]: crate::PageIteratorView
+    /// [`ResultIterator::restore_position`]: crate::ResultIterator::restore_position
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position saved from an iterator over the same page.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if successful, otherwise returns an error.
+    pub fn restore_position(&self, position: &IteratorPosition<PageIterator>) -> Result<()> {
+        if self.borrowed {
+            return Err(TesseractError::InvalidParameterError);
+        }
+        let copy = {
+            let saved = position.iterator.lock()?;
+            unsafe { TessPageIteratorCopy(*saved) }
+        };
+        if copy.is_null() {
+            return Err(TesseractError::NullPointerError);
+        }
+        let mut handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let previous = std::mem::replace(&mut *handle, copy);
+        unsafe { TessPageIteratorDelete(previous) };
+        Ok(())
+    }
+}
+
+impl Clone for PageIterator {
+    /// Creates an independent iterator at the same position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator cannot be copied; see [`PageIterator::try_clone`].
+    fn clone(&self) -> Self {
+        self.try_clone().expect("Failed to copy the page iterator")
+    }
+}
+
+/// A position saved by `save_position()` on a [`PageIterator`] or a
+/// [`ResultIterator`](crate::ResultIterator), restored with `restore_position()`.
+pub struct IteratorPosition<I> {
+    pub(crate) iterator: I,
+}
+
+/// A locked `TessPageIterator` handle.
+struct Locked<'a> {
+    _guard: MutexGuard<'a, *mut c_void>,
+    handle: *mut c_void,
+}
+
+impl Deref for Locked<'_> {
+    type Target = *mut c_void;
+
+    fn deref(&self) -> &*mut c_void {
+        &self.handle
+    }
 }
 
 impl Drop for PageIterator {
     fn drop(&mut self) {
+        if self.borrowed {
+            return;
+        }
         if let Ok(handle) = self.handle.lock() {
             unsafe { TessPageIteratorDelete(*handle) };
         }
     }
 }
 
+/// Gets the bounding box of the element at `level` through a locked handle.
+///
+/// # Safety
+///
+/// `handle` must be a valid page iterator, locked for the duration of the call.
+unsafe fn bounding_box_of(
+    handle: *mut c_void,
+    level: TessPageIteratorLevel,
+) -> Result<BoundingBox> {
+    let mut left = 0;
+    let mut top = 0;
+    let mut right = 0;
+    let mut bottom = 0;
+    let result = TessPageIteratorBoundingBox(
+        handle,
+        level as c_int,
+        &mut left,
+        &mut top,
+        &mut right,
+        &mut bottom,
+    );
+    if result == 0 {
+        Err(TesseractError::InvalidParameterError)
+    } else {
+        Ok(BoundingBox::new(left, top, right, bottom))
+    }
+}
+
 #[cfg(feature = "build-tesseract")]
 #[link(name = "tesseract")]
 extern "C" {
@@ -300,8 +471,18 @@ extern "C" {
     pub fn TessPageIteratorParagraphInfo(
         handle: *mut c_void,
         justification: *mut c_int,
-        is_list_item: *mut bool,
-        is_crown: *mut bool,
+        is_list_item: *mut c_int,
+        is_crown: *mut c_int,
         first_line_indent: *mut c_int,
     ) -> c_int;
+    pub fn TessPageIteratorCopy(handle: *mut c_void) -> *mut c_void;
+    pub fn TessPageIteratorGetBinaryImage(handle: *mut c_void, level: c_int) -> *mut c_void;
+    pub fn TessPageIteratorGetImage(
+        handle: *mut c_void,
+        level: c_int,
+        padding: c_int,
+        original_image: *mut c_void,
+        left: *mut c_int,
+        top: *mut c_int,
+    ) -> *mut c_void;
 }
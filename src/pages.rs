@@ -0,0 +1,110 @@
+//! Page-by-page reading of the inputs accepted by `process_pages`: a single
+//! image, a multi-page TIFF, or a text file listing one image path per line.
+
+use crate::error::{Result, TesseractError};
+use crate::pix::{findFileFormat, pixRead, pixReadFromMultipageTiff, Pix};
+use std::ffi::CString;
+use std::os::raw::c_int;
+
+// Leptonica's `IFF_*` format codes for the TIFF variants.
+const IFF_UNKNOWN: c_int = 0;
+const TIFF_FORMATS: [c_int; 8] = [4, 5, 6, 7, 8, 9, 10, 17];
+
+enum Source {
+    Image(String),
+    Tiff { path: CString, offset: usize },
+    List(std::vec::IntoIter<String>),
+    Done,
+}
+
+/// Iterator over the pages of an input, yielding each page with the file it
+/// was read from.
+///
+/// A page that cannot be read is yielded as an error. Reading goes on with
+/// the next entry of a list; a single image or a TIFF ends there.
+pub(crate) struct PageReader {
+    path: String,
+    source: Source,
+    page: usize,
+}
+
+impl PageReader {
+    /// Opens `path`, detecting its kind from the file contents like Tesseract.
+    pub(crate) fn open(path: &str) -> Result<Self> {
+        let error = || TesseractError::ProcessPagesError {
+            path: path.to_owned(),
+            page: None,
+        };
+        let filename = CString::new(path).map_err(|_| error())?;
+        let mut format = IFF_UNKNOWN;
+        // Leptonica also fails for text files and files too short to carry a
+        // signature, which are read as lists like Tesseract does. Only a file
+        // that cannot be read at all is an error.
+        let detected = unsafe { findFileFormat(filename.as_ptr(), &mut format) } == 0;
+        let source = if !detected || format == IFF_UNKNOWN {
+            let list = std::fs::read(path).map_err(|_| error())?;
+            let entries: Vec<String> = String::from_utf8_lossy(&list)
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_owned)
+                .collect();
+            Source::List(entries.into_iter())
+        } else if TIFF_FORMATS.contains(&format) {
+            Source::Tiff {
+                path: filename,
+                offset: 0,
+            }
+        } else {
+            Source::Image(path.to_owned())
+        };
+        Ok(PageReader {
+            path: path.to_owned(),
+            source,
+            page: 0,
+        })
+    }
+
+    fn page_error(&self) -> TesseractError {
+        TesseractError::ProcessPagesError {
+            path: self.path.clone(),
+            page: Some(self.page),
+        }
+    }
+}
+
+impl Iterator for PageReader {
+    type Item = (String, Result<Pix>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (source, pix) = match std::mem::replace(&mut self.source, Source::Done) {
+            Source::Done => return None,
+            Source::Image(path) => {
+                let pix = read_image(&path);
+                (path, pix)
+            }
+            Source::Tiff { path, mut offset } => {
+                let pix = unsafe { pixReadFromMultipageTiff(path.as_ptr(), &mut offset) };
+                // Leptonica resets the offset to 0 after the last page.
+                if !pix.is_null() && offset != 0 {
+                    self.source = Source::Tiff { path, offset };
+                }
+                (self.path.clone(), unsafe { Pix::from_raw(pix) }.ok())
+            }
+            Source::List(mut entries) => {
+                let path = entries.next()?;
+                self.source = Source::List(entries);
+                let pix = read_image(&path);
+                (path, pix)
+            }
+        };
+        let pix = pix.ok_or_else(|| self.page_error());
+        self.page += 1;
+        Some((source, pix))
+    }
+}
+
+fn read_image(path: &str) -> Option<Pix> {
+    let filename = CString::new(path).ok()?;
+    unsafe { Pix::from_raw(pixRead(filename.as_ptr())) }.ok()
+}
@@ -0,0 +1,334 @@
+//! Introspection and typed access for Tesseract parameters.
+
+use crate::api::TessBaseAPISetVariable;
+use crate::error::{Result, TesseractError};
+use crate::tempdir::PrivateDir;
+use crate::TesseractAPI;
+use std::ffi::CString;
+use std::fmt;
+
+/// Type of a Tesseract parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterType {
+    Int,
+    Bool,
+    Double,
+    String,
+}
+
+impl fmt::Display for ParameterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ParameterType::Int => "int",
+            ParameterType::Bool => "bool",
+            ParameterType::Double => "double",
+            ParameterType::String => "string",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Current value of a Tesseract parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterValue {
+    Int(i32),
+    Bool(bool),
+    Double(f64),
+    String(String),
+}
+
+impl ParameterValue {
+    /// Gets the type of the value.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parameter type matching this value.
+    pub fn parameter_type(&self) -> ParameterType {
+        match self {
+            ParameterValue::Int(_) => ParameterType::Int,
+            ParameterValue::Bool(_) => ParameterType::Bool,
+            ParameterValue::Double(_) => ParameterType::Double,
+            ParameterValue::String(_) => ParameterType::String,
+        }
+    }
+
+    fn to_param_string(&self) -> String {
+        match self {
+            ParameterValue::Int(value) => value.to_param_string(),
+            ParameterValue::Bool(value) => value.to_param_string(),
+            ParameterValue::Double(value) => value.to_param_string(),
+            ParameterValue::String(value) => value.to_param_string(),
+        }
+    }
+}
+
+/// A Tesseract parameter as reported by [`TesseractAPI::parameters`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    /// Name of the parameter, as used by `set_variable`.
+    pub name: String,
+    /// Current value of the parameter.
+    pub value: ParameterValue,
+    /// Human-readable description from Tesseract.
+    pub description: String,
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for i32 {}
+    impl Sealed for bool {}
+    impl Sealed for f64 {}
+    impl Sealed for String {}
+}
+
+/// Rust types that map onto a Tesseract parameter type.
+///
+/// Implemented for `i32`, `bool`, `f64` and `String`; used by
+/// [`TesseractAPI::get_param`] and [`TesseractAPI::set_param`].
+pub trait TypedParameter: private::Sealed + Sized {
+    /// The Tesseract parameter type this Rust type maps onto.
+    const TYPE: ParameterType;
+
+    #[doc(hidden)]
+    fn read(api: &TesseractAPI, name: &str) -> Result<Self>;
+
+    #[doc(hidden)]
+    fn to_param_string(&self) -> String;
+}
+
+impl TypedParameter for i32 {
+    const TYPE: ParameterType = ParameterType::Int;
+
+    fn read(api: &TesseractAPI, name: &str) -> Result<Self> {
+        api.get_int_variable(name)
+    }
+
+    fn to_param_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl TypedParameter for bool {
+    const TYPE: ParameterType = ParameterType::Bool;
+
+    fn read(api: &TesseractAPI, name: &str) -> Result<Self> {
+        api.get_bool_variable(name)
+    }
+
+    fn to_param_string(&self) -> String {
+        if *self { "1" } else { "0" }.to_owned()
+    }
+}
+
+impl TypedParameter for f64 {
+    const TYPE: ParameterType = ParameterType::Double;
+
+    fn read(api: &TesseractAPI, name: &str) -> Result<Self> {
+        api.get_double_variable(name)
+    }
+
+    fn to_param_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl TypedParameter for String {
+    const TYPE: ParameterType = ParameterType::String;
+
+    fn read(api: &TesseractAPI, name: &str) -> Result<Self> {
+        api.get_string_variable(name)
+    }
+
+    fn to_param_string(&self) -> String {
+        self.clone()
+    }
+}
+
+impl TesseractAPI {
+    /// Lists every Tesseract parameter with its type, value and description.
+    ///
+    /// The engine must be initialized.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parameters in the order Tesseract reports them.
+    pub fn parameters(&self) -> Result<Vec<Parameter>> {
+        if !self.is_initialized()? {
+            return Err(TesseractError::UninitializedError);
+        }
+        // Removed with the dump when it goes out of scope.
+        let dir = PrivateDir::new("tesseract-rs-params")?;
+        let (path, _) = dir.create_file("params.txt")?;
+        self.print_variables_to_file(
+            path.to_str()
+                .ok_or_else(|| TesseractError::non_utf8_path(&path))?,
+        )?;
+        let dump = std::fs::read(&path).map_err(|e| TesseractError::io(&path, e))?;
+        let dump = String::from_utf8_lossy(&dump).into_owned();
+
+        let mut parameters = Vec::new();
+        for line in dump.lines() {
+            let mut fields = line.splitn(3, '\t');
+            let name = match fields.next() {
+                Some(name) if !name.is_empty() => name,
+                _ => continue,
+            };
+            let description = fields.nth(1).unwrap_or("").to_owned();
+            if let Some(value) = self.parameter_value(name) {
+                parameters.push(Parameter {
+                    name: name.to_owned(),
+                    value,
+                    description,
+                });
+            }
+        }
+        Ok(parameters)
+    }
+
+    /// Gets the type of a Tesseract parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the parameter.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parameter type, or `UnknownParameter` if no parameter has this name.
+    pub fn parameter_type(&self, name: &str) -> Result<ParameterType> {
+        self.parameter_value(name)
+            .map(|value| value.parameter_type())
+            .ok_or_else(|| TesseractError::UnknownParameter(name.to_owned()))
+    }
+
+    /// Gets a parameter as a Rust type, checking its name and type first.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the parameter.
+    ///
+    /// # Returns
+    ///
+    /// Returns the value, `UnknownParameter` if no parameter has this name, or
+    /// `ParameterTypeMismatch` if the parameter has a different type.
+    pub fn get_param<T: TypedParameter>(&self, name: &str) -> Result<T> {
+        self.check_parameter_type(name, T::TYPE)?;
+        T::read(self, name)
+    }
+
+    /// Sets a parameter from a Rust type, checking its name and type first.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the parameter.
+    /// * `value` - New value of the parameter.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the parameter was set, `UnknownParameter` if no
+    /// parameter has this name, `ParameterTypeMismatch` if the parameter has a
+    /// different type, `ReadOnlyParameter` if it can only be set during
+    /// initialization, or `SetVariableError` if Tesseract rejected the value.
+    pub fn set_param<T: TypedParameter>(&self, name: &str, value: impl Into<T>) -> Result<()> {
+        self.check_parameter_type(name, T::TYPE)?;
+        match self.set_variable(name, &value.into().to_param_string()) {
+            Err(error @ TesseractError::SetVariableError { .. }) => {
+                if self.is_init_only(name)? {
+                    Err(TesseractError::ReadOnlyParameter(name.to_owned()))
+                } else {
+                    Err(error)
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Whether an existing parameter can only be set during initialization.
+    ///
+    /// Setting an existing parameter to its current value only fails if the
+    /// parameter is init-only.
+    fn is_init_only(&self, name: &str) -> Result<bool> {
+        let value = self
+            .parameter_value(name)
+            .ok_or_else(|| TesseractError::UnknownParameter(name.to_owned()))?;
+        let name_c = CString::new(name).map_err(|_| TesseractError::InvalidParameterError)?;
+        let value_c = CString::new(value.to_param_string())
+            .map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        // Goes around `set_variable`, which would record the value for `try_clone`.
+        let result = unsafe { TessBaseAPISetVariable(*handle, name_c.as_ptr(), value_c.as_ptr()) };
+        Ok(result == 0)
+    }
+
+    fn check_parameter_type(&self, name: &str, expected: ParameterType) -> Result<()> {
+        let actual = self.parameter_type(name)?;
+        if actual != expected {
+            return Err(TesseractError::ParameterTypeMismatch {
+                name: name.to_owned(),
+                expected,
+                actual,
+            });
+        }
+        Ok(())
+    }
+
+    /// Looks a parameter up with each typed getter; only the getter of the
+    /// parameter's own type finds it.
+    fn parameter_value(&self, name: &str) -> Option<ParameterValue> {
+        if let Ok(value) = self.get_int_variable(name) {
+            return Some(ParameterValue::Int(value));
+        }
+        if let Ok(value) = self.get_bool_variable(name) {
+            return Some(ParameterValue::Bool(value));
+        }
+        if let Ok(value) = self.get_double_variable(name) {
+            return Some(ParameterValue::Double(value));
+        }
+        self.get_string_variable(name)
+            .ok()
+            .map(ParameterValue::String)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parameter_type_display() {
+        assert_eq!(ParameterType::Int.to_string(), "int");
+        assert_eq!(ParameterType::Bool.to_string(), "bool");
+        assert_eq!(ParameterType::Double.to_string(), "double");
+        assert_eq!(ParameterType::String.to_string(), "string");
+    }
+
+    #[test]
+    fn test_parameter_value_type() {
+        assert_eq!(ParameterValue::Int(1).parameter_type(), ParameterType::Int);
+        assert_eq!(
+            ParameterValue::Bool(true).parameter_type(),
+            ParameterType::Bool
+        );
+        assert_eq!(
+            ParameterValue::Double(0.5).parameter_type(),
+            ParameterType::Double
+        );
+        assert_eq!(
+            ParameterValue::String(String::new()).parameter_type(),
+            ParameterType::String
+        );
+    }
+
+    #[test]
+    fn test_param_strings() {
+        assert_eq!(true.to_param_string(), "1");
+        assert_eq!(false.to_param_string(), "0");
+        assert_eq!(42.to_param_string(), "42");
+        assert_eq!(0.25f64.to_param_string(), "0.25");
+        assert_eq!("abc".to_owned().to_param_string(), "abc");
+        assert_eq!(ParameterValue::Bool(true).to_param_string(), "1");
+        assert_eq!(ParameterValue::Double(0.5).to_param_string(), "0.5");
+    }
+}
@@ -0,0 +1,516 @@
+use crate::error::{Result, TesseractError};
+use crate::types::BoundingBox;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::Path;
+
+/// An owned Leptonica image (`PIX`).
+///
+/// `Pix` holds one reference to the underlying Leptonica image and releases it
+/// with `pixDestroy` when dropped. Cloning a `Pix` copies the pixel data, like
+/// [`Pix::deep_copy`]. An image can still be shared with the engine (e.g. one
+/// returned by [`TesseractAPI::get_input_image`]); the methods that modify it
+/// then copy it first, so the engine's image never changes underneath it.
+///
+/// `Pix` is `Send` but not `Sync`: Leptonica's reference count is not atomic,
+/// so a `&Pix` sharing its image with an engine must not be used on several
+/// threads at once.
+///
+/// [`TesseractAPI::get_input_image`]: crate::TesseractAPI::get_input_image
+pub struct Pix {
+    handle: *mut c_void,
+}
+
+unsafe impl Send for Pix {}
+
+impl Pix {
+    /// Creates a new image filled with zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the image in pixels.
+    /// * `height` - Height of the image in pixels.
+    /// * `depth` - Bits per pixel (1, 2, 4, 8, 16 or 32).
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Pix` if successful, otherwise returns an error.
+    pub fn new(width: i32, height: i32, depth: i32) -> Result<Self> {
+        if width <= 0 || height <= 0 {
+            return Err(TesseractError::InvalidDimensions);
+        }
+        if !matches!(depth, 1 | 2 | 4 | 8 | 16 | 32) {
+            return Err(TesseractError::InvalidParameterError);
+        }
+        let handle = unsafe { pixCreate(width, height, depth) };
+        if handle.is_null() {
+            Err(TesseractError::NullPointerError)
+        } else {
+            Ok(Pix { handle })
+        }
+    }
+
+    /// Decodes an image from an in-memory encoded file.
+    ///
+    /// BMP and PNM are always supported. PNG, JPEG, TIFF and WebP need a
+    /// Leptonica built with those codecs: a system Leptonica usually is, the
+    /// bundled one only with the `image-codecs` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Contents of the encoded image file.
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded image if successful, otherwise returns an error.
+    pub fn read_from_memory(data: &[u8]) -> Result<Self> {
+        if data.is_empty() {
+            return Err(TesseractError::InvalidImageData);
+        }
+        let handle = unsafe { pixReadMem(data.as_ptr(), data.len()) };
+        if handle.is_null() {
+            Err(TesseractError::InvalidImageData)
+        } else {
+            Ok(Pix { handle })
+        }
+    }
+
+    /// Decodes an image file.
+    ///
+    /// The same formats as [`Pix::read_from_memory`] are supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the image file.
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded image if successful, otherwise returns an error.
+    pub fn read_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let metadata = std::fs::metadata(path).map_err(|e| TesseractError::io(path, e))?;
+        if !metadata.is_file() {
+            return Err(TesseractError::io(
+                path,
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a regular file"),
+            ));
+        }
+        let filename = path.to_str().ok_or(TesseractError::InvalidParameterError)?;
+        let filename = CString::new(filename).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = unsafe { pixRead(filename.as_ptr()) };
+        if handle.is_null() {
+            Err(TesseractError::InvalidImageData)
+        } else {
+            Ok(Pix { handle })
+        }
+    }
+
+    /// Takes ownership of a raw Leptonica `PIX` pointer.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid `PIX*` whose reference the caller owns. The
+    /// reference is released with `pixDestroy` when the returned `Pix` is dropped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Pix` if the pointer is not null, otherwise returns an error.
+    pub unsafe fn from_raw(handle: *mut c_void) -> Result<Self> {
+        if handle.is_null() {
+            Err(TesseractError::NullPointerError)
+        } else {
+            Ok(Pix { handle })
+        }
+    }
+
+    /// Gets the raw `PIX` pointer without giving up ownership.
+    ///
+    /// # Returns
+    ///
+    /// Returns the pointer, which stays valid for as long as this `Pix` is alive.
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.handle
+    }
+
+    /// Releases ownership of the raw `PIX` pointer.
+    ///
+    /// # Returns
+    ///
+    /// Returns the pointer; the caller becomes responsible for destroying it.
+    pub fn into_raw(self) -> *mut c_void {
+        let handle = self.handle;
+        std::mem::forget(self);
+        handle
+    }
+
+    /// Creates an independent copy of the image, including its pixel data.
+    ///
+    /// # Returns
+    ///
+    /// Returns the copy if successful, otherwise returns an error.
+    pub fn deep_copy(&self) -> Result<Self> {
+        let handle = unsafe { pixCopy(std::ptr::null_mut(), self.handle) };
+        unsafe { Self::from_raw(handle) }
+    }
+
+    /// Gets the width of the image.
+    ///
+    /// # Returns
+    ///
+    /// Returns the width in pixels.
+    pub fn width(&self) -> i32 {
+        unsafe { pixGetWidth(self.handle) }
+    }
+
+    /// Gets the height of the image.
+    ///
+    /// # Returns
+    ///
+    /// Returns the height in pixels.
+    pub fn height(&self) -> i32 {
+        unsafe { pixGetHeight(self.handle) }
+    }
+
+    /// Gets the depth of the image.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of bits per pixel.
+    pub fn depth(&self) -> i32 {
+        unsafe { pixGetDepth(self.handle) }
+    }
+
+    /// Gets the number of samples per pixel.
+    ///
+    /// # Returns
+    ///
+    /// Returns 1 for grayscale and binary images, 3 for RGB and 4 for RGBA.
+    pub fn samples_per_pixel(&self) -> i32 {
+        unsafe { pixGetSpp(self.handle) }
+    }
+
+    /// Gets the number of 32-bit words in each row of pixel data.
+    ///
+    /// # Returns
+    ///
+    /// Returns the words per line.
+    pub fn words_per_line(&self) -> i32 {
+        unsafe { pixGetWpl(self.handle) }
+    }
+
+    /// Gets the resolution of the image.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of (x resolution, y resolution) in pixels per inch.
+    /// A value of 0 means the resolution is unknown.
+    pub fn resolution(&self) -> (i32, i32) {
+        unsafe { (pixGetXRes(self.handle), pixGetYRes(self.handle)) }
+    }
+
+    /// Sets the resolution of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_res` - Horizontal resolution in pixels per inch.
+    /// * `y_res` - Vertical resolution in pixels per inch.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if successful, or an error if a shared image could not
+    /// be copied.
+    pub fn set_resolution(&mut self, x_res: i32, y_res: i32) -> Result<()> {
+        self.make_unique()?;
+        unsafe { pixSetResolution(self.handle, x_res, y_res) };
+        Ok(())
+    }
+
+    /// Gets the value of a single pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Column of the pixel.
+    /// * `y` - Row of the pixel.
+    ///
+    /// # Returns
+    ///
+    /// Returns the raw pixel value if the coordinates are inside the image,
+    /// otherwise returns an error. 32 bpp pixels are packed as `0xRRGGBBAA`.
+    pub fn get_pixel(&self, x: i32, y: i32) -> Result<u32> {
+        if x < 0 || y < 0 || x >= self.width() || y >= self.height() {
+            return Err(TesseractError::InvalidParameterError);
+        }
+        let mut value = 0;
+        let result = unsafe { pixGetPixel(self.handle, x, y, &mut value) };
+        if result != 0 {
+            Err(TesseractError::InvalidParameterError)
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Sets the value of a single pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Column of the pixel.
+    /// * `y` - Row of the pixel.
+    /// * `value` - Raw pixel value; 32 bpp pixels are packed as `0xRRGGBBAA`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the coordinates are inside the image, otherwise returns an error.
+    pub fn set_pixel(&mut self, x: i32, y: i32, value: u32) -> Result<()> {
+        if x < 0 || y < 0 || x >= self.width() || y >= self.height() {
+            return Err(TesseractError::InvalidParameterError);
+        }
+        self.make_unique()?;
+        let result = unsafe { pixSetPixel(self.handle, x, y, value) };
+        if result != 0 {
+            Err(TesseractError::InvalidParameterError)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Gets the raw pixel data.
+    ///
+    /// Rows are `words_per_line()` 32-bit words long. Within each word, pixels
+    /// are stored most significant bits first, as Leptonica does on every platform.
+    ///
+    /// # Returns
+    ///
+    /// Returns the pixel words of all rows.
+    pub fn data(&self) -> &[u32] {
+        let data = unsafe { pixGetData(self.handle) };
+        if data.is_null() {
+            return &[];
+        }
+        let len = (self.words_per_line() * self.height()) as usize;
+        unsafe { std::slice::from_raw_parts(data, len) }
+    }
+
+    /// Replaces a shared image with a copy of its own before it is modified.
+    fn make_unique(&mut self) -> Result<()> {
+        if unsafe { pixGetRefcount(self.handle) } > 1 {
+            *self = self.deep_copy()?;
+        }
+        Ok(())
+    }
+}
+
+impl Clone for Pix {
+    /// Creates an independent copy of the image, including its pixel data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the copy cannot be allocated; see [`Pix::deep_copy`].
+    fn clone(&self) -> Self {
+        self.deep_copy().expect("Failed to copy the image")
+    }
+}
+
+impl std::fmt::Debug for Pix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pix")
+            .field("width", &self.width())
+            .field("height", &self.height())
+            .field("depth", &self.depth())
+            .finish()
+    }
+}
+
+impl Drop for Pix {
+    fn drop(&mut self) {
+        unsafe { pixDestroy(&mut self.handle) };
+    }
+}
+
+/// An owned Leptonica array of boxes (`BOXA`).
+pub struct Boxa {
+    handle: *mut c_void,
+}
+
+unsafe impl Send for Boxa {}
+unsafe impl Sync for Boxa {}
+
+impl Boxa {
+    /// Takes ownership of a raw Leptonica `BOXA` pointer.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid `BOXA*` owned by the caller. It is released
+    /// with `boxaDestroy` when the returned `Boxa` is dropped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Boxa` if the pointer is not null, otherwise returns an error.
+    pub unsafe fn from_raw(handle: *mut c_void) -> Result<Self> {
+        if handle.is_null() {
+            Err(TesseractError::NullPointerError)
+        } else {
+            Ok(Boxa { handle })
+        }
+    }
+
+    /// Gets the number of boxes.
+    pub fn len(&self) -> usize {
+        unsafe { boxaGetCount(self.handle) }.max(0) as usize
+    }
+
+    /// Returns `true` if the array holds no boxes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets a box.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the box.
+    ///
+    /// # Returns
+    ///
+    /// Returns the box, or `None` if `index` is out of range.
+    pub fn get(&self, index: usize) -> Option<BoundingBox> {
+        if index >= self.len() {
+            return None;
+        }
+        let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+        let result = unsafe {
+            boxaGetBoxGeometry(self.handle, index as c_int, &mut x, &mut y, &mut w, &mut h)
+        };
+        (result == 0).then(|| BoundingBox::new(x, y, x + w, y + h))
+    }
+
+    /// Iterates over the boxes.
+    pub fn iter(&self) -> impl Iterator<Item = BoundingBox> + '_ {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+}
+
+impl std::fmt::Debug for Boxa {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl Drop for Boxa {
+    fn drop(&mut self) {
+        unsafe { boxaDestroy(&mut self.handle) };
+    }
+}
+
+/// An owned Leptonica array of images (`PIXA`).
+pub struct Pixa {
+    handle: *mut c_void,
+}
+
+unsafe impl Send for Pixa {}
+unsafe impl Sync for Pixa {}
+
+/// Leptonica's `L_COPY` access flag: return a copy of the image.
+const L_COPY: c_int = 1;
+
+impl Pixa {
+    /// Takes ownership of a raw Leptonica `PIXA` pointer.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid `PIXA*` owned by the caller. It is released
+    /// with `pixaDestroy` when the returned `Pixa` is dropped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Pixa` if the pointer is not null, otherwise returns an error.
+    pub unsafe fn from_raw(handle: *mut c_void) -> Result<Self> {
+        if handle.is_null() {
+            Err(TesseractError::NullPointerError)
+        } else {
+            Ok(Pixa { handle })
+        }
+    }
+
+    /// Gets the number of images.
+    pub fn len(&self) -> usize {
+        unsafe { pixaGetCount(self.handle) }.max(0) as usize
+    }
+
+    /// Returns `true` if the array holds no images.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets an image.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the image.
+    ///
+    /// # Returns
+    ///
+    /// Returns a copy of the image, or `None` if `index` is out of range.
+    pub fn get(&self, index: usize) -> Option<Pix> {
+        if index >= self.len() {
+            return None;
+        }
+        let handle = unsafe { pixaGetPix(self.handle, index as c_int, L_COPY) };
+        unsafe { Pix::from_raw(handle) }.ok()
+    }
+
+    /// Iterates over the images.
+    pub fn iter(&self) -> impl Iterator<Item = Pix> + '_ {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+}
+
+impl std::fmt::Debug for Pixa {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl Drop for Pixa {
+    fn drop(&mut self) {
+        unsafe { pixaDestroy(&mut self.handle) };
+    }
+}
+
+// Leptonica is linked by the build script (bundled build or pkg-config), so
+// no `#[link]` attribute is needed here.
+#[cfg(feature = "build-tesseract")]
+extern "C" {
+    pub fn pixCreate(width: c_int, height: c_int, depth: c_int) -> *mut c_void;
+    pub fn pixClone(pix: *mut c_void) -> *mut c_void;
+    pub fn pixCopy(pixd: *mut c_void, pixs: *mut c_void) -> *mut c_void;
+    pub fn pixDestroy(ppix: *mut *mut c_void);
+    pub fn pixGetRefcount(pix: *mut c_void) -> c_int;
+    pub fn pixGetWidth(pix: *mut c_void) -> c_int;
+    pub fn pixGetHeight(pix: *mut c_void) -> c_int;
+    pub fn pixGetDepth(pix: *mut c_void) -> c_int;
+    pub fn pixGetSpp(pix: *mut c_void) -> c_int;
+    pub fn pixGetWpl(pix: *mut c_void) -> c_int;
+    pub fn pixGetXRes(pix: *mut c_void) -> c_int;
+    pub fn pixGetYRes(pix: *mut c_void) -> c_int;
+    pub fn pixSetResolution(pix: *mut c_void, xres: c_int, yres: c_int) -> c_int;
+    pub fn pixGetPixel(pix: *mut c_void, x: c_int, y: c_int, pval: *mut u32) -> c_int;
+    pub fn pixSetPixel(pix: *mut c_void, x: c_int, y: c_int, val: u32) -> c_int;
+    pub fn pixGetData(pix: *mut c_void) -> *mut u32;
+    pub fn pixRead(filename: *const c_char) -> *mut c_void;
+    pub fn pixReadMem(data: *const u8, size: usize) -> *mut c_void;
+    pub fn pixReadFromMultipageTiff(filename: *const c_char, poffset: *mut usize) -> *mut c_void;
+    pub fn findFileFormat(filename: *const c_char, pformat: *mut c_int) -> c_int;
+    pub fn boxaGetCount(boxa: *mut c_void) -> c_int;
+    pub fn boxaGetBoxGeometry(
+        boxa: *mut c_void,
+        index: c_int,
+        px: *mut c_int,
+        py: *mut c_int,
+        pw: *mut c_int,
+        ph: *mut c_int,
+    ) -> c_int;
+    pub fn boxaDestroy(pboxa: *mut *mut c_void);
+    pub fn pixaGetCount(pixa: *mut c_void) -> c_int;
+    pub fn pixaGetPix(pixa: *mut c_void, index: c_int, accesstype: c_int) -> *mut c_void;
+    pub fn pixaDestroy(ppixa: *mut *mut c_void);
+}
@@ -0,0 +1,325 @@
+use crate::api::TesseractConfiguration;
+use crate::enums::TessPageSegMode;
+use crate::error::{Result, TesseractError};
+use crate::{TesseractAPI, TesseractBuilder};
+use std::ops::Deref;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Usage statistics of a [`TesseractPool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PoolStats {
+    /// Maximum number of engines in the pool.
+    pub size: usize,
+    /// Engines waiting to be handed out.
+    pub idle: usize,
+    /// Engines currently handed out.
+    pub in_use: usize,
+    /// Engines initialized since the pool was created, replacements included.
+    pub created: usize,
+    /// Engines dropped instead of being returned to the pool.
+    pub discarded: usize,
+    /// Number of times an engine was handed out.
+    pub checkouts: usize,
+    /// Number of `try_get_timeout` calls that gave up.
+    pub timeouts: usize,
+}
+
+struct PoolState {
+    idle: Vec<TesseractAPI>,
+    /// Engines that exist or are being initialized: idle plus in use.
+    live: usize,
+    stats: PoolStats,
+}
+
+struct PoolInner {
+    builder: TesseractBuilder,
+    state: Mutex<PoolState>,
+    returned: Condvar,
+}
+
+impl PoolInner {
+    fn state(&self) -> Result<MutexGuard<'_, PoolState>> {
+        self.state
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)
+    }
+}
+
+/// Pool of engines initialized from one configuration.
+///
+/// Initializing an engine loads its traineddata, which takes hundreds of
+/// milliseconds; the pool does it once per engine and hands the engines out
+/// as [`PooledTesseract`] guards. When a guard is dropped its engine is
+/// cleared with [`TesseractAPI::clear`], its page segmentation mode is set
+/// back, diagnostics capture is turned off, and it is returned to the pool.
+///
+/// Engines are dropped and replaced on demand instead when their guard was
+/// [discarded](PooledTesseract::discard), their thread panicked, an engine
+/// operation (initialization, recognition or page processing) failed while
+/// they were lent, or the borrower changed their variables or
+/// re-initialized them: Tesseract cannot reset a variable to its default.
+///
+/// Clones share the same pool.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tesseract_rs::{TesseractAPI, TesseractPool};
+///
+/// let pool = TesseractPool::new(TesseractAPI::builder().language("eng"), 4)?;
+/// let api = pool.get()?;
+/// api.set_image_from_file("scan.png")?;
+/// let text = api.get_utf8_text()?;
+/// # Ok::<(), tesseract_rs::TesseractError>(())
+/// ```
+#[derive(Clone)]
+pub struct TesseractPool {
+    inner: Arc<PoolInner>,
+}
+
+impl TesseractPool {
+    /// Creates a pool and initializes its engines in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - Configuration of every engine in the pool.
+    /// * `size` - Number of engines.
+    ///
+    /// # Returns
+    ///
+    /// Returns the pool if every engine could be initialized, otherwise
+    /// returns the first initialization error.
+    pub fn new(builder: TesseractBuilder, size: usize) -> Result<Self> {
+        if size == 0 {
+            return Err(TesseractError::InvalidParameterError);
+        }
+        let engines = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..size)
+                .map(|_| {
+                    let builder = builder.clone();
+                    scope.spawn(move || builder.build())
+                })
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        Ok(TesseractPool {
+            inner: Arc::new(PoolInner {
+                builder,
+                state: Mutex::new(PoolState {
+                    idle: engines,
+                    live: size,
+                    stats: PoolStats {
+                        size,
+                        created: size,
+                        ..PoolStats::default()
+                    },
+                }),
+                returned: Condvar::new(),
+            }),
+        })
+    }
+
+    /// Takes an engine, waiting until one is available.
+    ///
+    /// # Returns
+    ///
+    /// Returns a guard giving access to the engine, or an error if a
+    /// replacement engine could not be initialized.
+    pub fn get(&self) -> Result<PooledTesseract> {
+        self.checkout(None)
+    }
+
+    /// Takes an engine, waiting at most `timeout` for one to become available.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait.
+    ///
+    /// # Returns
+    ///
+    /// Returns a guard giving access to the engine, `PoolTimeout` if none
+    /// became available in time, or an error if a replacement engine could
+    /// not be initialized.
+    pub fn try_get_timeout(&self, timeout: Duration) -> Result<PooledTesseract> {
+        self.checkout(Some(Instant::now() + timeout))
+    }
+
+    /// Gets the usage statistics of the pool.
+    ///
+    /// # Returns
+    ///
+    /// Returns a snapshot of the statistics.
+    pub fn stats(&self) -> Result<PoolStats> {
+        let state = self.inner.state()?;
+        Ok(PoolStats {
+            idle: state.idle.len(),
+            in_use: state.live - state.idle.len(),
+            ..state.stats
+        })
+    }
+
+    fn checkout(&self, deadline: Option<Instant>) -> Result<PooledTesseract> {
+        let mut state = self.inner.state()?;
+        loop {
+            if let Some(api) = state.idle.pop() {
+                state.stats.checkouts += 1;
+                drop(state);
+                return self.guard(api);
+            }
+            if state.live < state.stats.size {
+                // Replace a discarded engine, initializing it without the lock
+                state.live += 1;
+                drop(state);
+                let built = self.inner.builder.clone().build();
+                let mut state = self.inner.state()?;
+                return match built {
+                    Ok(api) => {
+                        state.stats.created += 1;
+                        state.stats.checkouts += 1;
+                        drop(state);
+                        self.guard(api)
+                    }
+                    Err(error) => {
+                        state.live -= 1;
+                        self.inner.returned.notify_one();
+                        Err(error)
+                    }
+                };
+            }
+            state = match deadline {
+                None => self
+                    .inner
+                    .returned
+                    .wait(state)
+                    .map_err(|_| TesseractError::MutexLockError)?,
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        state.stats.timeouts += 1;
+                        return Err(TesseractError::PoolTimeout);
+                    }
+                    self.inner
+                        .returned
+                        .wait_timeout(state, deadline - now)
+                        .map_err(|_| TesseractError::MutexLockError)?
+                        .0
+                }
+            };
+        }
+    }
+
+    fn guard(&self, api: TesseractAPI) -> Result<PooledTesseract> {
+        // Snapshot the engine's settings so they can be checked on return
+        let lent = api
+            .configuration()
+            .and_then(|config| Ok((config, api.get_page_seg_mode()?)));
+        let mut guard = PooledTesseract {
+            pool: Arc::clone(&self.inner),
+            api: Some(api),
+            config: TesseractConfiguration::default(),
+            psm: TessPageSegMode::PSM_AUTO,
+            discard: false,
+        };
+        match lent {
+            Ok((config, psm)) => {
+                guard.config = config;
+                guard.psm = psm;
+                Ok(guard)
+            }
+            Err(error) => {
+                // Dropping the guard drops the engine
+                guard.discard = true;
+                Err(error)
+            }
+        }
+    }
+}
+
+/// An engine borrowed from a [`TesseractPool`].
+///
+/// Dereferences to [`TesseractAPI`]. Dropping the guard resets the engine and
+/// returns it to the pool, or drops it if it cannot be reused; see
+/// [`TesseractPool`].
+pub struct PooledTesseract {
+    pool: Arc<PoolInner>,
+    api: Option<TesseractAPI>,
+    /// Configuration of the engine when it was lent.
+    config: TesseractConfiguration,
+    /// Page segmentation mode of the engine when it was lent.
+    psm: TessPageSegMode,
+    discard: bool,
+}
+
+impl PooledTesseract {
+    /// Drops the engine instead of returning it to the pool.
+    ///
+    /// Failed engine operations already discard the engine; use this after
+    /// other problems that may have left it in a bad state. The pool
+    /// initializes a replacement when it next runs out of engines.
+    pub fn discard(mut self) {
+        self.discard = true;
+    }
+
+    /// Resets a returned engine to the state it was lent in.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the engine can be handed out again.
+    fn reset(&self, api: &TesseractAPI) -> bool {
+        if api.take_failed() {
+            return false;
+        }
+        let reset = || -> Result<bool> {
+            if !api.configuration()?.same_engine(&self.config) {
+                return Ok(false);
+            }
+            api.clear()?;
+            api.clear_input_image()?;
+            api.set_capture_diagnostics(false)?;
+            if api.get_page_seg_mode()? != self.psm {
+                api.set_page_seg_mode(self.psm)?;
+            }
+            Ok(true)
+        };
+        reset().unwrap_or(false)
+    }
+}
+
+impl Deref for PooledTesseract {
+    type Target = TesseractAPI;
+
+    fn deref(&self) -> &TesseractAPI {
+        self.api.as_ref().expect("engine already returned")
+    }
+}
+
+impl Drop for PooledTesseract {
+    fn drop(&mut self) {
+        let api = match self.api.take() {
+            Some(api) => api,
+            None => return,
+        };
+        let keep = !self.discard && !std::thread::panicking() && self.reset(&api);
+        let mut state = match self.pool.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if keep {
+            state.idle.push(api);
+        } else {
+            state.live -= 1;
+            state.stats.discarded += 1;
+        }
+        drop(state);
+        self.pool.returned.notify_one();
+    }
+}
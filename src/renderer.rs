@@ -0,0 +1,417 @@
+//! Output formats written in Rust from the [`Document`](crate::Document) model.
+//!
+//! A [`Renderer`] receives every recognized [`Page`] of a document, either
+//! one image at a time through [`Renderer::add_image`] or for a whole file
+//! through [`TesseractAPI::render_pages`]. [`MarkdownRenderer`],
+//! [`CsvRenderer`] and, with the `serde` feature, [`JsonRenderer`] are
+//! provided; other formats only need to implement [`Renderer::add_page`].
+
+use crate::document::{Page, Word};
+use crate::enums::TessPolyBlockType;
+use crate::error::{Result, TesseractError};
+use crate::TesseractAPI;
+use std::io::Write;
+
+#[cfg(feature = "serde")]
+use crate::document::JSON_SCHEMA_VERSION;
+
+/// Output format fed with the recognized pages of a document.
+///
+/// Calls are made in the order `begin_document`, `add_page` for each page,
+/// `end_document`, like Tesseract's own renderers.
+pub trait Renderer {
+    /// Starts a new document.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title of the document.
+    fn begin_document(&mut self, title: &str) -> Result<()> {
+        let _ = title;
+        Ok(())
+    }
+
+    /// Adds a recognized page to the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The recognized page.
+    fn add_page(&mut self, page: &Page) -> Result<()>;
+
+    /// Finishes the document.
+    fn end_document(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Recognizes the image set on `api`, unless it already holds results,
+    /// and adds it to the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - The TesseractAPI instance.
+    fn add_image(&mut self, api: &TesseractAPI) -> Result<()> {
+        for page in &api.recognize_document()?.pages {
+            self.add_page(page)?;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Renderer + ?Sized> Renderer for &mut R {
+    fn begin_document(&mut self, title: &str) -> Result<()> {
+        (**self).begin_document(title)
+    }
+
+    fn add_page(&mut self, page: &Page) -> Result<()> {
+        (**self).add_page(page)
+    }
+
+    fn end_document(&mut self) -> Result<()> {
+        (**self).end_document()
+    }
+
+    fn add_image(&mut self, api: &TesseractAPI) -> Result<()> {
+        (**self).add_image(api)
+    }
+}
+
+fn io_error(source: std::io::Error) -> TesseractError {
+    TesseractError::IoError { path: None, source }
+}
+
+/// Writes the document as Markdown.
+///
+/// The title becomes a level 1 heading, heading blocks level 2 headings,
+/// list paragraphs list items, and pages are separated by horizontal rules.
+/// Characters with a meaning in Markdown are escaped.
+pub struct MarkdownRenderer<W: Write> {
+    writer: W,
+    pages: usize,
+}
+
+impl<W: Write> MarkdownRenderer<W> {
+    /// Creates a Markdown renderer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the Markdown text.
+    pub fn new(writer: W) -> Self {
+        MarkdownRenderer { writer, pages: 0 }
+    }
+
+    /// Returns the writer, e.g. the `Vec<u8>` holding the document.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> Renderer for MarkdownRenderer<W> {
+    fn begin_document(&mut self, title: &str) -> Result<()> {
+        self.pages = 0;
+        if !title.is_empty() {
+            writeln!(self.writer, "# {}\n", escape_markdown(title)).map_err(io_error)?;
+        }
+        Ok(())
+    }
+
+    fn add_page(&mut self, page: &Page) -> Result<()> {
+        if self.pages > 0 {
+            writeln!(self.writer, "---\n").map_err(io_error)?;
+        }
+        self.pages += 1;
+        for block in &page.blocks {
+            for paragraph in &block.paragraphs {
+                let lines: Vec<String> = paragraph
+                    .lines
+                    .iter()
+                    .map(|line| escape_markdown(&line.text()))
+                    .filter(|line| !line.is_empty())
+                    .collect();
+                if lines.is_empty() {
+                    continue;
+                }
+                let text = if block.block_type == TessPolyBlockType::PT_HEADING_TEXT {
+                    format!("## {}", lines.join(" "))
+                } else if paragraph.info.is_list_item {
+                    format!("- {}", lines.join("\n  "))
+                } else {
+                    lines.join("\n")
+                };
+                writeln!(self.writer, "{}\n", text).map_err(io_error)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn end_document(&mut self) -> Result<()> {
+        self.writer.flush().map_err(io_error)
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '#' | '<' | '>' | '|'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Writes one CSV row per word.
+///
+/// The columns are `page,block,paragraph,line,word,left,top,right,bottom,confidence,text`;
+/// the numbers are 1-based, like Tesseract's TSV output.
+pub struct CsvRenderer<W: Write> {
+    writer: W,
+    pages: usize,
+}
+
+impl<W: Write> CsvRenderer<W> {
+    /// Creates a CSV renderer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the CSV rows.
+    pub fn new(writer: W) -> Self {
+        CsvRenderer { writer, pages: 0 }
+    }
+
+    /// Returns the writer, e.g. the `Vec<u8>` holding the document.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_word(&mut self, position: [usize; 5], word: &Word) -> Result<()> {
+        let [page, block, paragraph, line, index] = position;
+        let bbox = &word.bounding_box;
+        writeln!(
+            self.writer,
+            "{},{},{},{},{},{},{},{},{},{:.2},{}",
+            page,
+            block,
+            paragraph,
+            line,
+            index,
+            bbox.left,
+            bbox.top,
+            bbox.right,
+            bbox.bottom,
+            word.confidence,
+            quote_csv(&word.text)
+        )
+        .map_err(io_error)
+    }
+}
+
+impl<W: Write> Renderer for CsvRenderer<W> {
+    fn begin_document(&mut self, _title: &str) -> Result<()> {
+        self.pages = 0;
+        writeln!(
+            self.writer,
+            "page,block,paragraph,line,word,left,top,right,bottom,confidence,text"
+        )
+        .map_err(io_error)
+    }
+
+    fn add_page(&mut self, page: &Page) -> Result<()> {
+        self.pages += 1;
+        for (b, block) in page.blocks.iter().enumerate() {
+            for (p, paragraph) in block.paragraphs.iter().enumerate() {
+                for (l, line) in paragraph.lines.iter().enumerate() {
+                    for (w, word) in line.words.iter().enumerate() {
+                        self.write_word([self.pages, b + 1, p + 1, l + 1, w + 1], word)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn end_document(&mut self) -> Result<()> {
+        self.writer.flush().map_err(io_error)
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn quote_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Writes the document as JSON, in the schema of [`Document::to_json`].
+///
+/// Pages are written as they are added, so the whole document is never held
+/// in memory. The output can be read back with [`Document::from_json`].
+///
+/// [`Document::to_json`]: crate::Document::to_json
+/// [`Document::from_json`]: crate::Document::from_json
+#[cfg(feature = "serde")]
+pub struct JsonRenderer<W: Write> {
+    writer: W,
+    pages: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<W: Write> JsonRenderer<W> {
+    /// Creates a JSON renderer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the JSON text.
+    pub fn new(writer: W) -> Self {
+        JsonRenderer { writer, pages: 0 }
+    }
+
+    /// Returns the writer, e.g. the `Vec<u8>` holding the document.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<W: Write> Renderer for JsonRenderer<W> {
+    fn begin_document(&mut self, _title: &str) -> Result<()> {
+        self.pages = 0;
+        write!(
+            self.writer,
+            "{{\"schema_version\":{},\"pages\":[",
+            JSON_SCHEMA_VERSION
+        )
+        .map_err(io_error)
+    }
+
+    fn add_page(&mut self, page: &Page) -> Result<()> {
+        if self.pages > 0 {
+            self.writer.write_all(b",").map_err(io_error)?;
+        }
+        self.pages += 1;
+        serde_json::to_writer(&mut self.writer, page)?;
+        Ok(())
+    }
+
+    fn end_document(&mut self) -> Result<()> {
+        self.writer.write_all(b"]}").map_err(io_error)?;
+        self.writer.flush().map_err(io_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::{Block, Line, Paragraph};
+    use crate::enums::TessParagraphJustification;
+    use crate::types::{BoundingBox, ParagraphInfo};
+
+    fn word(text: &str, left: i32) -> Word {
+        Word {
+            text: text.to_owned(),
+            bounding_box: BoundingBox::new(left, 10, left + 40, 30),
+            confidence: 91.5,
+            font: None,
+            language: Some("eng".to_owned()),
+            is_from_dictionary: true,
+            is_numeric: false,
+            symbols: Vec::new(),
+        }
+    }
+
+    fn paragraph(lines: &[&[&str]], is_list_item: bool) -> Paragraph {
+        Paragraph {
+            bounding_box: BoundingBox::default(),
+            info: ParagraphInfo {
+                justification: TessParagraphJustification::JUSTIFICATION_LEFT,
+                is_list_item,
+                is_crown: false,
+                first_line_indent: 0,
+            },
+            lines: lines
+                .iter()
+                .map(|words| Line {
+                    bounding_box: BoundingBox::default(),
+                    baseline: None,
+                    words: words
+                        .iter()
+                        .enumerate()
+                        .map(|(i, text)| word(text, i as i32 * 50))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn page() -> Page {
+        Page {
+            width: 200,
+            height: 100,
+            blocks: vec![
+                Block {
+                    block_type: TessPolyBlockType::PT_HEADING_TEXT,
+                    bounding_box: BoundingBox::default(),
+                    paragraphs: vec![paragraph(&[&["Results"]], false)],
+                },
+                Block {
+                    block_type: TessPolyBlockType::PT_FLOWING_TEXT,
+                    bounding_box: BoundingBox::default(),
+                    paragraphs: vec![
+                        paragraph(&[&["Costs", "*rose*"], &["by", "5%,"]], false),
+                        paragraph(&[&["item", "\"one\""]], true),
+                    ],
+                },
+            ],
+        }
+    }
+
+    fn render<R: Renderer>(mut renderer: R, pages: usize) -> R {
+        renderer.begin_document("Report").unwrap();
+        for _ in 0..pages {
+            renderer.add_page(&page()).unwrap();
+        }
+        renderer.end_document().unwrap();
+        renderer
+    }
+
+    #[test]
+    fn test_markdown_renderer() {
+        let markdown = render(MarkdownRenderer::new(Vec::new()), 2).into_inner();
+        let page = "## Results\n\nCosts \\*rose\\*\nby 5%,\n\n- item \"one\"\n\n";
+        assert_eq!(
+            String::from_utf8(markdown).unwrap(),
+            format!("# Report\n\n{}---\n\n{}", page, page)
+        );
+    }
+
+    #[test]
+    fn test_csv_renderer() {
+        let csv = render(CsvRenderer::new(Vec::new()), 2).into_inner();
+        let csv = String::from_utf8(csv).unwrap();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 1 + 2 * 7);
+        assert_eq!(
+            rows[0],
+            "page,block,paragraph,line,word,left,top,right,bottom,confidence,text"
+        );
+        assert_eq!(rows[1], "1,1,1,1,1,0,10,40,30,91.50,Results");
+        assert_eq!(rows[5], "1,2,1,2,2,50,10,90,30,91.50,\"5%,\"");
+        assert_eq!(rows[7], "1,2,2,1,2,50,10,90,30,91.50,\"\"\"one\"\"\"");
+        assert!(rows[8].starts_with("2,1,1,1,1,"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_renderer() {
+        let json = render(JsonRenderer::new(Vec::new()), 2).into_inner();
+        let document = crate::Document::from_json(std::str::from_utf8(&json).unwrap()).unwrap();
+        assert_eq!(document.pages, vec![page(), page()]);
+
+        let empty = render(JsonRenderer::new(Vec::new()), 0).into_inner();
+        let document = crate::Document::from_json(std::str::from_utf8(&empty).unwrap()).unwrap();
+        assert!(document.pages.is_empty());
+    }
+}
@@ -1,8 +1,12 @@
 use crate::api::TessDeleteText;
 use crate::enums::TessPageIteratorLevel;
 use crate::error::{Result, TesseractError};
-use crate::ChoiceIterator;
+use crate::page_iterator::IteratorPosition;
+use crate::types::{BoundingBox, FontAttributes, WordBox};
+use crate::{ChoiceIterator, PageIterator};
 use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::os::raw::{c_char, c_float, c_int, c_void};
 use std::sync::{Arc, Mutex};
 
@@ -92,8 +96,8 @@ impl ResultIterator {
     ///
     /// # Returns
     ///
-    /// Returns the font attributes as a tuple if successful, otherwise returns an error.
-    pub fn word_font_attributes(&self) -> Result<(bool, bool, bool, bool, bool, bool, i32, i32)> {
+    /// Returns the font attributes if successful, otherwise returns an error.
+    pub fn word_font_attributes(&self) -> Result<FontAttributes> {
         let handle = self
             .handle
             .lock()
@@ -124,16 +128,16 @@ impl ResultIterator {
         if result == 0 {
             Err(TesseractError::InvalidParameterError)
         } else {
-            Ok((
-                is_bold != 0,
-                is_italic != 0,
-                is_underlined != 0,
-                is_monospace != 0,
-                is_serif != 0,
-                is_smallcaps != 0,
+            Ok(FontAttributes {
+                is_bold: is_bold != 0,
+                is_italic: is_italic != 0,
+                is_underlined: is_underlined != 0,
+                is_monospace: is_monospace != 0,
+                is_serif: is_serif != 0,
+                is_smallcaps: is_smallcaps != 0,
                 pointsize,
                 font_id,
-            ))
+            })
         }
     }
 
@@ -223,13 +227,17 @@ impl ResultIterator {
     ///
     /// # Returns
     ///
-    /// Returns a tuple of (text, left, top, right, bottom, confidence) if successful
-    pub fn get_word_with_bounds(&self) -> Result<(String, i32, i32, i32, i32, f32)> {
+    /// Returns the word's text, bounding box and confidence if successful
+    pub fn get_word_with_bounds(&self) -> Result<WordBox> {
         let text = self.get_utf8_text(TessPageIteratorLevel::RIL_WORD)?;
-        let (left, top, right, bottom) = self.get_bounding_box(TessPageIteratorLevel::RIL_WORD)?;
+        let bounding_box = self.get_bounding_box(TessPageIteratorLevel::RIL_WORD)?;
         let confidence = self.confidence(TessPageIteratorLevel::RIL_WORD)?;
 
-        Ok((text, left, top, right, bottom, confidence))
+        Ok(WordBox {
+            text,
+            bounding_box,
+            confidence,
+        })
     }
 
     /// Advances the iterator to the next word.
@@ -245,17 +253,13 @@ impl ResultIterator {
     /// Should be called before next() to ensure valid data.
     ///
     /// # Returns
-    /// Returns a tuple of (text, left, top, right, bottom, confidence) if successful
-    pub fn get_current_word(&self) -> Result<(String, i32, i32, i32, i32, f32)> {
-        let text = self.get_utf8_text(TessPageIteratorLevel::RIL_WORD)?;
-        let (left, top, right, bottom) = self.get_bounding_box(TessPageIteratorLevel::RIL_WORD)?;
-        let confidence = self.confidence(TessPageIteratorLevel::RIL_WORD)?;
-
-        Ok((text, left, top, right, bottom, confidence))
+    /// Returns the word's text, bounding box and confidence if successful
+    pub fn get_current_word(&self) -> Result<WordBox> {
+        self.get_word_with_bounds()
     }
 
     /// Gets the bounding box for the current element.
-    pub fn get_bounding_box(&self, level: TessPageIteratorLevel) -> Result<(i32, i32, i32, i32)> {
+    pub fn get_bounding_box(&self, level: TessPageIteratorLevel) -> Result<BoundingBox> {
         let mut left = 0;
         let mut top = 0;
         let mut right = 0;
@@ -280,7 +284,7 @@ impl ResultIterator {
         if result == 0 {
             Err(TesseractError::InvalidParameterError)
         } else {
-            Ok((left, top, right, bottom))
+            Ok(BoundingBox::new(left, top, right, bottom))
         }
     }
 
@@ -304,6 +308,111 @@ impl ResultIterator {
             Ok(ChoiceIterator::new(choice_iter))
         }
     }
+
+    /// Gets a page iterator view of this iterator.
+    ///
+    /// The view shares this iterator's position, so the layout queries of
+    /// [`PageIterator`] (block type, baseline, paragraph info, ...) describe
+    /// the current element, and moving either one moves both.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `PageIteratorView` borrowing this iterator.
+    pub fn as_page_iterator(&self) -> PageIteratorView<'_> {
+        PageIteratorView {
+            iterator: PageIterator::result_view(Arc::clone(&self.handle)),
+            _result: PhantomData,
+        }
+    }
+
+    /// Creates an independent iterator at the same position.
+    ///
+    /// Copying is cheap: the recognition results are shared, only the
+    /// position is duplicated, so a copy can be used to look ahead.
+    ///
+    /// # Returns
+    ///
+    /// Returns the copy if successful, otherwise returns an error.
+    pub fn try_clone(&self) -> Result<Self> {
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let copy = unsafe { TessResultIteratorCopy(*handle) };
+        if copy.is_null() {
+            Err(TesseractError::NullPointerError)
+        } else {
+            Ok(ResultIterator::new(copy))
+        }
+    }
+
+    /// Saves the current position so it can be restored later.
+    ///
+    /// # Returns
+    ///
+    /// Returns the saved position if successful, otherwise returns an error.
+    pub fn save_position(&self) -> Result<IteratorPosition<ResultIterator>> {
+        Ok(IteratorPosition {
+            iterator: self.try_clone()?,
+        })
+    }
+
+    /// Moves back to a saved position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position saved from an iterator over the same page.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if successful, otherwise returns an error.
+    pub fn restore_position(&self, position: &IteratorPosition<ResultIterator>) -> Result<()> {
+        let copy = {
+            let saved = position
+                .iterator
+                .handle
+                .lock()
+                .map_err(|_| TesseractError::MutexLockError)?;
+            unsafe { TessResultIteratorCopy(*saved) }
+        };
+        if copy.is_null() {
+            return Err(TesseractError::NullPointerError);
+        }
+        let mut handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let previous = std::mem::replace(&mut *handle, copy);
+        unsafe { TessResultIteratorDelete(previous) };
+        Ok(())
+    }
+}
+
+impl Clone for ResultIterator {
+    /// Creates an independent iterator at the same position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator cannot be copied; see [`ResultIterator::try_clone`].
+    fn clone(&self) -> Self {
+        self.try_clone()
+            .expect("Failed to copy the result iterator")
+    }
+}
+
+/// A [`ResultIterator`] seen as a [`PageIterator`], returned by
+/// [`ResultIterator::as_page_iterator`].
+pub struct PageIteratorView<'a> {
+    iterator: PageIterator,
+    _result: PhantomData<&'a ResultIterator>,
+}
+
+impl Deref for PageIteratorView<'_> {
+    type Target = PageIterator;
+
+    fn deref(&self) -> &PageIterator {
+        &self.iterator
+    }
 }
 
 impl Drop for ResultIterator {
@@ -347,4 +456,6 @@ extern "C" {
         bottom: *mut c_int,
     ) -> c_int;
     pub fn TessResultIteratorGetChoiceIterator(handle: *mut c_void) -> *mut c_void;
+    pub fn TessResultIteratorGetPageIterator(handle: *mut c_void) -> *mut c_void;
+    pub fn TessResultIteratorCopy(handle: *mut c_void) -> *mut c_void;
 }
@@ -1,18 +1,37 @@
 use crate::error::{Result, TesseractError};
+use crate::tempdir::PrivateDir;
 use crate::TesseractAPI;
 use std::ffi::{CStr, CString};
+use std::io::Write;
+use std::ops::Deref;
 use std::os::raw::{c_char, c_int, c_void};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+/// Renderer writing recognition results to a file.
+///
+/// Renderers can be chained with [`insert`](Self::insert) so that one
+/// [`add_image`](Self::add_image) call writes every format. The first
+/// renderer of a chain owns the others, as in Tesseract.
 pub struct TessResultRenderer {
-    handle: Arc<Mutex<*mut c_void>>,
+    pub(crate) handle: Arc<Mutex<*mut c_void>>,
 }
 
 unsafe impl Send for TessResultRenderer {}
 unsafe impl Sync for TessResultRenderer {}
 
 impl TessResultRenderer {
+    fn from_handle(handle: *mut c_void) -> Result<Self> {
+        if handle.is_null() {
+            Err(TesseractError::NullPointerError)
+        } else {
+            Ok(TessResultRenderer {
+                handle: Arc::new(Mutex::new(handle)),
+            })
+        }
+    }
+
     /// Creates a new instance of the TessResultRenderer.
     ///
     /// # Arguments
@@ -23,15 +42,10 @@ impl TessResultRenderer {
     ///
     /// Returns the new instance of the TessResultRenderer.
     pub fn new_text_renderer(outputbase: &str) -> Result<Self> {
-        let outputbase = CString::new(outputbase).unwrap();
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
         let handle = unsafe { TessTextRendererCreate(outputbase.as_ptr()) };
-        if handle.is_null() {
-            Err(TesseractError::NullPointerError)
-        } else {
-            Ok(TessResultRenderer {
-                handle: Arc::new(Mutex::new(handle)),
-            })
-        }
+        Self::from_handle(handle)
     }
 
     /// Creates a new instance of the TessResultRenderer for HOCR.
@@ -44,15 +58,136 @@ impl TessResultRenderer {
     ///
     /// Returns the new instance of the TessResultRenderer.
     pub fn new_hocr_renderer(outputbase: &str) -> Result<Self> {
-        let outputbase = CString::new(outputbase).unwrap();
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
         let handle = unsafe { TessHOcrRendererCreate(outputbase.as_ptr()) };
-        if handle.is_null() {
-            Err(TesseractError::NullPointerError)
-        } else {
-            Ok(TessResultRenderer {
-                handle: Arc::new(Mutex::new(handle)),
-            })
-        }
+        Self::from_handle(handle)
+    }
+
+    /// Creates a new instance of the TessResultRenderer for HOCR, optionally
+    /// with font information.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputbase` - Output base path.
+    /// * `font_info` - Whether to add `x_font` and `x_fsize` to the words.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new instance of the TessResultRenderer.
+    pub fn new_hocr_renderer_with_font_info(outputbase: &str, font_info: bool) -> Result<Self> {
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = unsafe { TessHOcrRendererCreate2(outputbase.as_ptr(), font_info as c_int) };
+        Self::from_handle(handle)
+    }
+
+    /// Creates a new instance of the TessResultRenderer for ALTO XML.
+    ///
+    /// Writes `<outputbase>.xml`.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputbase` - Output base path.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new instance of the TessResultRenderer.
+    pub fn new_alto_renderer(outputbase: &str) -> Result<Self> {
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = unsafe { TessAltoRendererCreate(outputbase.as_ptr()) };
+        Self::from_handle(handle)
+    }
+
+    /// Creates a new instance of the TessResultRenderer for PAGE XML.
+    ///
+    /// Writes `<outputbase>.page.xml`.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputbase` - Output base path.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new instance of the TessResultRenderer.
+    pub fn new_page_renderer(outputbase: &str) -> Result<Self> {
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = unsafe { TessPAGERendererCreate(outputbase.as_ptr()) };
+        Self::from_handle(handle)
+    }
+
+    /// Creates a new instance of the TessResultRenderer for tab-separated values.
+    ///
+    /// Writes `<outputbase>.tsv`.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputbase` - Output base path.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new instance of the TessResultRenderer.
+    pub fn new_tsv_renderer(outputbase: &str) -> Result<Self> {
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = unsafe { TessTsvRendererCreate(outputbase.as_ptr()) };
+        Self::from_handle(handle)
+    }
+
+    /// Creates a new instance of the TessResultRenderer for UNLV text.
+    ///
+    /// Writes `<outputbase>.unlv`.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputbase` - Output base path.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new instance of the TessResultRenderer.
+    pub fn new_unlv_renderer(outputbase: &str) -> Result<Self> {
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = unsafe { TessUnlvRendererCreate(outputbase.as_ptr()) };
+        Self::from_handle(handle)
+    }
+
+    /// Creates a new instance of the TessResultRenderer for WordStr box files.
+    ///
+    /// Writes `<outputbase>.box`.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputbase` - Output base path.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new instance of the TessResultRenderer.
+    pub fn new_wordstr_box_renderer(outputbase: &str) -> Result<Self> {
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = unsafe { TessWordStrBoxRendererCreate(outputbase.as_ptr()) };
+        Self::from_handle(handle)
+    }
+
+    /// Creates a new instance of the TessResultRenderer for LSTM training box files.
+    ///
+    /// Writes `<outputbase>.box`.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputbase` - Output base path.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new instance of the TessResultRenderer.
+    pub fn new_lstm_box_renderer(outputbase: &str) -> Result<Self> {
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
+        let handle = unsafe { TessLSTMBoxRendererCreate(outputbase.as_ptr()) };
+        Self::from_handle(handle)
     }
 
     /// Creates a new instance of the TessResultRenderer for PDF.
@@ -67,18 +202,13 @@ impl TessResultRenderer {
     ///
     /// Returns the new instance of the TessResultRenderer.
     pub fn new_pdf_renderer(outputbase: &str, datadir: &str, textonly: bool) -> Result<Self> {
-        let outputbase = CString::new(outputbase).unwrap();
-        let datadir = CString::new(datadir).unwrap();
+        let outputbase =
+            CString::new(outputbase).map_err(|_| TesseractError::InvalidParameterError)?;
+        let datadir = CString::new(datadir).map_err(|_| TesseractError::InvalidParameterError)?;
         let handle = unsafe {
             TessPDFRendererCreate(outputbase.as_ptr(), datadir.as_ptr(), textonly as c_int)
         };
-        if handle.is_null() {
-            Err(TesseractError::NullPointerError)
-        } else {
-            Ok(TessResultRenderer {
-                handle: Arc::new(Mutex::new(handle)),
-            })
-        }
+        Self::from_handle(handle)
     }
 
     /// Begins a new document.
@@ -91,7 +221,7 @@ impl TessResultRenderer {
     ///
     /// Returns `true` if the document was created successfully, otherwise returns `false`.
     pub fn begin_document(&self, title: &str) -> Result<bool> {
-        let title = CString::new(title).unwrap();
+        let title = CString::new(title).map_err(|_| TesseractError::InvalidParameterError)?;
         let handle = self
             .handle
             .lock()
@@ -101,6 +231,10 @@ impl TessResultRenderer {
 
     /// Adds an image to the document.
     ///
+    /// The image is forwarded to every chained renderer. It is recognized
+    /// once, unless `api` already holds results, and every renderer writes
+    /// those results in its own format.
+    ///
     /// # Arguments
     ///
     /// * `api` - The TesseractAPI instance.
@@ -143,13 +277,7 @@ impl TessResultRenderer {
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
-        let ext_ptr = unsafe { TessResultRendererExtention(*handle) };
-        if ext_ptr.is_null() {
-            Err(TesseractError::NullPointerError)
-        } else {
-            let c_str = unsafe { CStr::from_ptr(ext_ptr) };
-            Ok(c_str.to_str()?.to_owned())
-        }
+        extension(*handle)
     }
 
     /// Gets the title of the document.
@@ -162,13 +290,7 @@ impl TessResultRenderer {
             .handle
             .lock()
             .map_err(|_| TesseractError::MutexLockError)?;
-        let title_ptr = unsafe { TessResultRendererTitle(*handle) };
-        if title_ptr.is_null() {
-            Err(TesseractError::NullPointerError)
-        } else {
-            let c_str = unsafe { CStr::from_ptr(title_ptr) };
-            Ok(c_str.to_str()?.to_owned())
-        }
+        title(*handle)
     }
 
     /// Gets the number of images in the document.
@@ -183,12 +305,304 @@ impl TessResultRenderer {
             .map_err(|_| TesseractError::MutexLockError)?;
         Ok(unsafe { TessResultRendererImageNum(*handle) })
     }
+
+    /// Creates a PDF renderer that writes into memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `datadir` - Data directory path, containing `pdf.ttf`.
+    /// * `textonly` - Whether to include text only.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `MemoryRenderer` whose `end_document` returns the PDF bytes.
+    pub fn pdf_to_vec(datadir: &str, textonly: bool) -> Result<MemoryRenderer> {
+        MemoryRenderer::new(|outputbase| Self::new_pdf_renderer(outputbase, datadir, textonly))
+    }
+
+    /// Creates a hOCR renderer that writes into memory.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `MemoryRenderer` whose `end_document` returns the hOCR bytes.
+    pub fn hocr_to_vec() -> Result<MemoryRenderer> {
+        MemoryRenderer::new(Self::new_hocr_renderer)
+    }
+
+    /// Creates a text renderer that writes into memory.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `MemoryRenderer` whose `end_document` returns the UTF-8 text.
+    pub fn text_to_vec() -> Result<MemoryRenderer> {
+        MemoryRenderer::new(Self::new_text_renderer)
+    }
+
+    /// Chains another renderer after this one.
+    ///
+    /// `begin_document`, `add_image` and `end_document` on this renderer are
+    /// forwarded along the chain, so every format is written from a single
+    /// recognition. `next` is inserted directly after this renderer, ahead
+    /// of any renderers inserted before it, and is deleted with the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - Renderer (or head of a chain) to insert.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the renderer was inserted, otherwise returns an error.
+    pub fn insert(&self, next: TessResultRenderer) -> Result<()> {
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let mut next_handle = next
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        unsafe { TessResultRendererInsert(*handle, *next_handle) };
+        // The chain now owns the inserted renderer.
+        *next_handle = std::ptr::null_mut();
+        Ok(())
+    }
+
+    /// Gets the renderer following this one in its chain.
+    ///
+    /// # Returns
+    ///
+    /// Returns the next renderer, or `None` at the end of the chain.
+    pub fn next(&self) -> Result<Option<ChainedRenderer<'_>>> {
+        let handle = self
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let next = unsafe { TessResultRendererNext(*handle) };
+        Ok((!next.is_null()).then_some(ChainedRenderer {
+            head: self,
+            handle: next,
+        }))
+    }
+}
+
+/// Renderer owned by the head of a chain, see [`TessResultRenderer::next`].
+///
+/// Calls lock the head renderer, so they are serialized with calls on the
+/// rest of the chain.
+pub struct ChainedRenderer<'a> {
+    head: &'a TessResultRenderer,
+    handle: *mut c_void,
+}
+
+unsafe impl Send for ChainedRenderer<'_> {}
+unsafe impl Sync for ChainedRenderer<'_> {}
+
+impl<'a> ChainedRenderer<'a> {
+    /// Gets the extension of the document.
+    ///
+    /// # Returns
+    ///
+    /// Returns the extension as a `String` if successful, otherwise returns an error.
+    pub fn get_extension(&self) -> Result<String> {
+        let _head = self
+            .head
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        extension(self.handle)
+    }
+
+    /// Gets the title of the document.
+    ///
+    /// # Returns
+    ///
+    /// Returns the title as a `String` if successful, otherwise returns an error.
+    pub fn get_title(&self) -> Result<String> {
+        let _head = self
+            .head
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        title(self.handle)
+    }
+
+    /// Gets the number of images in the document.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of images as an `i32`.
+    pub fn get_image_num(&self) -> Result<i32> {
+        let _head = self
+            .head
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        Ok(unsafe { TessResultRendererImageNum(self.handle) })
+    }
+
+    /// Gets the renderer following this one in the chain.
+    ///
+    /// # Returns
+    ///
+    /// Returns the next renderer, or `None` at the end of the chain.
+    pub fn next(&self) -> Result<Option<ChainedRenderer<'a>>> {
+        let _head = self
+            .head
+            .handle
+            .lock()
+            .map_err(|_| TesseractError::MutexLockError)?;
+        let next = unsafe { TessResultRendererNext(self.handle) };
+        Ok((!next.is_null()).then_some(ChainedRenderer {
+            head: self.head,
+            handle: next,
+        }))
+    }
+}
+
+/// Renderer collecting its output in memory instead of a file of the
+/// caller's choosing.
+///
+/// Tesseract renderers can only write files, so the output goes to a
+/// temporary directory that only the current user can access and that is
+/// removed when the renderer is dropped. All
+/// [`TessResultRenderer`] methods are available through `Deref`, so a
+/// `&MemoryRenderer` can also be passed to
+/// [`TesseractAPI::process_pages_with_renderer`].
+///
+/// [`TesseractAPI::process_pages_with_renderer`]: crate::TesseractAPI::process_pages_with_renderer
+pub struct MemoryRenderer {
+    renderer: Option<TessResultRenderer>,
+    output: PathBuf,
+    dir: PrivateDir,
+}
+
+impl MemoryRenderer {
+    /// Creates a renderer that writes into memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `create` - Creates the renderer from an output base path, e.g.
+    ///   `TessResultRenderer::new_alto_renderer`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `MemoryRenderer` if successful, otherwise returns an error.
+    pub fn new<F>(create: F) -> Result<Self>
+    where
+        F: FnOnce(&str) -> Result<TessResultRenderer>,
+    {
+        // Removes the directory if anything below fails.
+        let mut memory = MemoryRenderer {
+            renderer: None,
+            output: PathBuf::new(),
+            dir: PrivateDir::new("tesseract-rs-render")?,
+        };
+        let outputbase = memory.dir.path().join("output");
+        let renderer = create(
+            outputbase
+                .to_str()
+                .ok_or_else(|| TesseractError::non_utf8_path(&outputbase))?,
+        )?;
+        memory.output = outputbase.with_extension(renderer.get_extension()?);
+        memory.renderer = Some(renderer);
+        Ok(memory)
+    }
+
+    /// Ends the document and returns it.
+    ///
+    /// # Returns
+    ///
+    /// Returns the bytes of the finished document, or `RenderError` if the
+    /// renderer failed.
+    pub fn end_document(self) -> Result<Vec<u8>> {
+        if !self.renderer().end_document()? {
+            return Err(self.render_error());
+        }
+        self.into_bytes()
+    }
+
+    /// Ends the document and writes it to `sink`.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - Destination of the finished document.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the document was rendered and written, otherwise returns an error.
+    pub fn end_document_to<W: Write>(self, sink: &mut W) -> Result<()> {
+        let document = self.end_document()?;
+        sink.write_all(&document)
+            .map_err(|source| TesseractError::IoError { path: None, source })
+    }
+
+    /// Returns the output of a document that has already been ended, e.g. by
+    /// `process_pages_with_renderer`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the bytes written by the renderer.
+    pub fn into_bytes(mut self) -> Result<Vec<u8>> {
+        // Deleting the renderer closes and flushes its output file.
+        drop(self.renderer.take());
+        std::fs::read(&self.output).map_err(|e| TesseractError::io(&self.output, e))
+    }
+
+    fn renderer(&self) -> &TessResultRenderer {
+        self.renderer
+            .as_ref()
+            .expect("renderer is only taken when consuming self")
+    }
+
+    fn render_error(&self) -> TesseractError {
+        TesseractError::RenderError {
+            extension: self.renderer().get_extension().unwrap_or_default(),
+        }
+    }
+}
+
+impl Deref for MemoryRenderer {
+    type Target = TessResultRenderer;
+
+    fn deref(&self) -> &TessResultRenderer {
+        self.renderer()
+    }
+}
+
+impl Drop for MemoryRenderer {
+    fn drop(&mut self) {
+        // The renderer must close its output before the directory goes.
+        drop(self.renderer.take());
+    }
+}
+
+fn extension(handle: *mut c_void) -> Result<String> {
+    let ext_ptr = unsafe { TessResultRendererExtention(handle) };
+    if ext_ptr.is_null() {
+        Err(TesseractError::NullPointerError)
+    } else {
+        let c_str = unsafe { CStr::from_ptr(ext_ptr) };
+        Ok(c_str.to_str()?.to_owned())
+    }
+}
+
+fn title(handle: *mut c_void) -> Result<String> {
+    let title_ptr = unsafe { TessResultRendererTitle(handle) };
+    if title_ptr.is_null() {
+        Err(TesseractError::NullPointerError)
+    } else {
+        let c_str = unsafe { CStr::from_ptr(title_ptr) };
+        Ok(c_str.to_str()?.to_owned())
+    }
 }
 
 impl Drop for TessResultRenderer {
     fn drop(&mut self) {
         if let Ok(handle) = self.handle.lock() {
-            unsafe { TessDeleteResultRenderer(*handle) };
+            // Null once the renderer has been inserted into another chain.
+            if !handle.is_null() {
+                unsafe { TessDeleteResultRenderer(*handle) };
+            }
         }
     }
 }
@@ -198,6 +612,13 @@ impl Drop for TessResultRenderer {
 extern "C" {
     pub fn TessTextRendererCreate(outputbase: *const c_char) -> *mut c_void;
     pub fn TessHOcrRendererCreate(outputbase: *const c_char) -> *mut c_void;
+    pub fn TessHOcrRendererCreate2(outputbase: *const c_char, font_info: c_int) -> *mut c_void;
+    pub fn TessAltoRendererCreate(outputbase: *const c_char) -> *mut c_void;
+    pub fn TessPAGERendererCreate(outputbase: *const c_char) -> *mut c_void;
+    pub fn TessTsvRendererCreate(outputbase: *const c_char) -> *mut c_void;
+    pub fn TessUnlvRendererCreate(outputbase: *const c_char) -> *mut c_void;
+    pub fn TessWordStrBoxRendererCreate(outputbase: *const c_char) -> *mut c_void;
+    pub fn TessLSTMBoxRendererCreate(outputbase: *const c_char) -> *mut c_void;
     pub fn TessPDFRendererCreate(
         outputbase: *const c_char,
         datadir: *const c_char,
@@ -210,4 +631,6 @@ extern "C" {
     pub fn TessResultRendererExtention(renderer: *mut c_void) -> *const c_char;
     pub fn TessResultRendererTitle(renderer: *mut c_void) -> *const c_char;
     pub fn TessResultRendererImageNum(renderer: *mut c_void) -> c_int;
+    pub fn TessResultRendererInsert(renderer: *mut c_void, next: *mut c_void);
+    pub fn TessResultRendererNext(renderer: *mut c_void) -> *mut c_void;
 }
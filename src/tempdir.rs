@@ -0,0 +1,127 @@
+//! Private scratch directories for files exchanged with Tesseract by path.
+
+use crate::error::{Result, TesseractError};
+use std::fs::{DirBuilder, File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Attempts at finding an unused directory name before giving up.
+const MAX_ATTEMPTS: usize = 16;
+
+/// A directory in the system temporary directory that only the current user
+/// can access. It is removed with its contents when dropped.
+///
+/// The directory is created exclusively, so a file or symlink planted under
+/// the same name makes creation pick another name instead of reusing it.
+#[derive(Debug)]
+pub(crate) struct PrivateDir {
+    path: PathBuf,
+}
+
+impl PrivateDir {
+    /// Creates a new directory whose name starts with `prefix`.
+    pub(crate) fn new(prefix: &str) -> Result<Self> {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let temp_dir = std::env::temp_dir();
+        let mut builder = DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+
+        let mut attempt = 0;
+        loop {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.subsec_nanos())
+                .unwrap_or(0);
+            let path = temp_dir.join(format!(
+                "{}-{}-{}-{:08x}",
+                prefix,
+                std::process::id(),
+                NEXT_ID.fetch_add(1, Ordering::Relaxed),
+                nanos
+            ));
+            match builder.create(&path) {
+                Ok(()) => return Ok(PrivateDir { path }),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists && attempt < MAX_ATTEMPTS => {
+                    attempt += 1;
+                }
+                Err(e) => return Err(TesseractError::io(&path, e)),
+            }
+        }
+    }
+
+    /// Returns the path of the directory.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Creates a file in the directory, failing if it already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the file.
+    ///
+    /// # Returns
+    ///
+    /// Returns the path of the file and the file, opened for reading and writing.
+    pub(crate) fn create_file(&self, name: &str) -> Result<(PathBuf, File)> {
+        let path = self.path.join(name);
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| TesseractError::io(&path, e))?;
+        Ok((path, file))
+    }
+}
+
+impl Drop for PrivateDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_private_dir_is_removed_on_drop() {
+        let dir = PrivateDir::new("tesseract-rs-test").unwrap();
+        let path = dir.path.clone();
+        assert!(path.is_dir());
+        dir.create_file("output.txt").unwrap();
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_private_dir_names_are_unique() {
+        let first = PrivateDir::new("tesseract-rs-test").unwrap();
+        let second = PrivateDir::new("tesseract-rs-test").unwrap();
+        assert_ne!(first.path, second.path);
+    }
+
+    #[test]
+    fn test_create_file_is_exclusive() {
+        let dir = PrivateDir::new("tesseract-rs-test").unwrap();
+        dir.create_file("output.txt").unwrap();
+        assert!(matches!(
+            dir.create_file("output.txt"),
+            Err(TesseractError::IoError { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_private_dir_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = PrivateDir::new("tesseract-rs-test").unwrap();
+        let mode = std::fs::metadata(&dir.path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+    }
+}
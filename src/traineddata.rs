@@ -0,0 +1,225 @@
+//! Minimal reader for the header of `.traineddata` files.
+//!
+//! A traineddata file starts with the number of components (`i32`) followed by
+//! one `i64` offset per component, where `-1` marks a missing component. Only
+//! this table is read; it tells which OCR engines a language file supports.
+
+use crate::enums::OcrEngineMode;
+use crate::error::{Result, TesseractError};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Index of the legacy engine's classifier templates (`TESSDATA_INTTEMP`).
+const LEGACY_COMPONENT: usize = 3;
+/// Index of the LSTM network (`TESSDATA_LSTM`).
+const LSTM_COMPONENT: usize = 17;
+/// Upper bound used by Tesseract to detect byte-swapped headers.
+const MAX_COMPONENTS: i32 = 1000;
+
+/// Engine components present in a traineddata file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct EngineComponents {
+    pub legacy: bool,
+    pub lstm: bool,
+}
+
+impl EngineComponents {
+    /// Parses the component table at the start of a traineddata file.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let count_bytes: [u8; 4] = data.get(..4)?.try_into().ok()?;
+        let mut count = i32::from_le_bytes(count_bytes);
+        let swapped = !(0..=MAX_COMPONENTS).contains(&count);
+        if swapped {
+            count = i32::from_be_bytes(count_bytes);
+            if !(0..=MAX_COMPONENTS).contains(&count) {
+                return None;
+            }
+        }
+        let count = count as usize;
+        let table = data.get(4..4 + count * 8)?;
+        let present = |index: usize| {
+            if index >= count {
+                return false;
+            }
+            let bytes: [u8; 8] = table[index * 8..index * 8 + 8].try_into().unwrap();
+            let offset = if swapped {
+                i64::from_be_bytes(bytes)
+            } else {
+                i64::from_le_bytes(bytes)
+            };
+            offset >= 0
+        };
+        Some(EngineComponents {
+            legacy: present(LEGACY_COMPONENT),
+            lstm: present(LSTM_COMPONENT),
+        })
+    }
+
+    /// Reads the component table of a traineddata file on disk.
+    pub fn from_file(path: &Path) -> Option<Self> {
+        let mut file = File::open(path).ok()?;
+        let mut header = Vec::new();
+        file.by_ref()
+            .take(4 + MAX_COMPONENTS as u64 * 8)
+            .read_to_end(&mut header)
+            .ok()?;
+        Self::from_bytes(&header)
+    }
+
+    /// Whether these components can run the given engine mode.
+    pub fn supports(&self, oem: OcrEngineMode) -> bool {
+        (!oem.needs_legacy() || self.legacy) && (!oem.needs_lstm() || self.lstm)
+    }
+}
+
+/// Directory Tesseract reads traineddata from: `datapath`, or
+/// `TESSDATA_PREFIX` when no datapath is given.
+fn tessdata_base(datapath: &str) -> Option<PathBuf> {
+    if datapath.is_empty() {
+        std::env::var_os("TESSDATA_PREFIX").map(PathBuf::from)
+    } else {
+        Some(PathBuf::from(datapath))
+    }
+}
+
+/// Splits a `+`-separated language list, skipping `~`-excluded languages.
+fn languages(list: &str) -> impl Iterator<Item = &str> {
+    list.split('+')
+        .filter(|language| !language.is_empty() && !language.starts_with('~'))
+}
+
+/// Locates `<language>.traineddata` the way Tesseract does: directly in
+/// `datapath`, in its `tessdata` subdirectory, or under `TESSDATA_PREFIX`
+/// when no datapath is given.
+pub(crate) fn find_traineddata(datapath: &str, language: &str) -> Option<PathBuf> {
+    let base = tessdata_base(datapath)?;
+    let filename = format!("{}.traineddata", language);
+    [base.join(&filename), base.join("tessdata").join(&filename)]
+        .into_iter()
+        .find(|path| path.is_file())
+}
+
+/// Checks that every language in a `+`-separated list has a traineddata file.
+///
+/// Nothing is checked when neither a datapath nor `TESSDATA_PREFIX` is set,
+/// since Tesseract then falls back to its compiled-in location.
+pub(crate) fn check_languages(datapath: &str, list: &str) -> Result<()> {
+    let base = match tessdata_base(datapath) {
+        Some(base) => base,
+        None => return Ok(()),
+    };
+    for language in languages(list) {
+        if find_traineddata(datapath, language).is_none() {
+            return Err(TesseractError::MissingTraineddata {
+                language: language.to_owned(),
+                path: base.join(format!("{}.traineddata", language)),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that every language in a `+`-separated list supports `oem`.
+///
+/// Languages whose traineddata cannot be found or parsed are skipped, leaving
+/// the final word to Tesseract.
+pub(crate) fn check_engine_mode(datapath: &str, list: &str, oem: OcrEngineMode) -> Result<()> {
+    if oem == OcrEngineMode::Default {
+        return Ok(());
+    }
+    for language in languages(list) {
+        let components = find_traineddata(datapath, language)
+            .and_then(|path| EngineComponents::from_file(&path));
+        if let Some(components) = components {
+            if !components.supports(oem) {
+                return Err(TesseractError::UnsupportedEngineMode {
+                    language: language.to_owned(),
+                    oem,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(offsets: &[i64]) -> Vec<u8> {
+        let mut data = (offsets.len() as i32).to_le_bytes().to_vec();
+        for offset in offsets {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_lstm_only_traineddata() {
+        let mut offsets = vec![-1i64; 24];
+        offsets[0] = 196;
+        offsets[LSTM_COMPONENT] = 400;
+        let components = EngineComponents::from_bytes(&header(&offsets)).unwrap();
+        assert_eq!(
+            components,
+            EngineComponents {
+                legacy: false,
+                lstm: true
+            }
+        );
+        assert!(components.supports(OcrEngineMode::LstmOnly));
+        assert!(components.supports(OcrEngineMode::Default));
+        assert!(!components.supports(OcrEngineMode::TesseractOnly));
+        assert!(!components.supports(OcrEngineMode::Combined));
+    }
+
+    #[test]
+    fn test_legacy_only_traineddata() {
+        // Pre-4.0 files have fewer entries and no LSTM component at all
+        let mut offsets = vec![-1i64; 15];
+        offsets[LEGACY_COMPONENT] = 128;
+        let components = EngineComponents::from_bytes(&header(&offsets)).unwrap();
+        assert!(components.legacy);
+        assert!(!components.lstm);
+        assert!(components.supports(OcrEngineMode::TesseractOnly));
+        assert!(!components.supports(OcrEngineMode::LstmOnly));
+    }
+
+    #[test]
+    fn test_byte_swapped_header() {
+        let mut data = 24i32.to_be_bytes().to_vec();
+        for index in 0..24 {
+            let offset: i64 = if index == LEGACY_COMPONENT { 64 } else { -1 };
+            data.extend_from_slice(&offset.to_be_bytes());
+        }
+        let components = EngineComponents::from_bytes(&data).unwrap();
+        assert!(components.legacy);
+        assert!(!components.lstm);
+    }
+
+    #[test]
+    fn test_language_list() {
+        let list: Vec<_> = languages("eng+~deu++tur").collect();
+        assert_eq!(list, ["eng", "tur"]);
+    }
+
+    #[test]
+    fn test_missing_traineddata() {
+        let dir = std::env::temp_dir();
+        match check_languages(dir.to_str().unwrap(), "zzz_missing") {
+            Err(TesseractError::MissingTraineddata { language, path }) => {
+                assert_eq!(language, "zzz_missing");
+                assert_eq!(path, dir.join("zzz_missing.traineddata"));
+            }
+            other => panic!("Expected MissingTraineddata, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_truncated_header() {
+        assert!(EngineComponents::from_bytes(&[]).is_none());
+        let data = header(&[-1; 24]);
+        assert!(EngineComponents::from_bytes(&data[..40]).is_none());
+    }
+}
@@ -0,0 +1,460 @@
+//! Plain value types returned by the iterators and [`TesseractAPI`].
+//!
+//! Each type converts from and into the tuple the corresponding method
+//! returned before these types were introduced, so existing code can keep
+//! destructuring with `.into()`.
+//!
+//! [`TesseractAPI`]: crate::TesseractAPI
+
+use crate::enums::{
+    TessOrientation, TessParagraphJustification, TessTextlineOrder, TessWritingDirection,
+};
+use crate::pix::Pix;
+use std::time::Duration;
+
+/// Axis-aligned rectangle in image coordinates, `right` and `bottom` exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingBox {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl BoundingBox {
+    /// Creates a bounding box from its edges.
+    pub fn new(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        BoundingBox {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Width of the box, `0` if it is empty.
+    pub fn width(&self) -> i32 {
+        (self.right - self.left).max(0)
+    }
+
+    /// Height of the box, `0` if it is empty.
+    pub fn height(&self) -> i32 {
+        (self.bottom - self.top).max(0)
+    }
+
+    /// Returns `true` if the box has no area.
+    pub fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+
+    /// Returns `true` if `other` lies entirely within this box.
+    pub fn contains(&self, other: &BoundingBox) -> bool {
+        self.left <= other.left
+            && self.top <= other.top
+            && self.right >= other.right
+            && self.bottom >= other.bottom
+    }
+
+    /// Gets the overlap of two boxes.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Box to intersect with.
+    ///
+    /// # Returns
+    ///
+    /// Returns the overlapping area, or `None` if the boxes do not overlap.
+    pub fn intersect(&self, other: &BoundingBox) -> Option<BoundingBox> {
+        let intersection = BoundingBox {
+            left: self.left.max(other.left),
+            top: self.top.max(other.top),
+            right: self.right.min(other.right),
+            bottom: self.bottom.min(other.bottom),
+        };
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// Gets the smallest box enclosing both boxes.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Box to merge with.
+    ///
+    /// # Returns
+    ///
+    /// Returns the enclosing box. An empty box contributes nothing.
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        BoundingBox {
+            left: self.left.min(other.left),
+            top: self.top.min(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+        }
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for BoundingBox {
+    fn from((left, top, right, bottom): (i32, i32, i32, i32)) -> Self {
+        BoundingBox::new(left, top, right, bottom)
+    }
+}
+
+impl From<BoundingBox> for (i32, i32, i32, i32) {
+    fn from(bbox: BoundingBox) -> Self {
+        (bbox.left, bbox.top, bbox.right, bbox.bottom)
+    }
+}
+
+/// Baseline of a text line, from `(x1, y1)` to `(x2, y2)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Baseline {
+    pub x1: i32,
+    pub y1: i32,
+    pub x2: i32,
+    pub y2: i32,
+}
+
+impl From<(i32, i32, i32, i32)> for Baseline {
+    fn from((x1, y1, x2, y2): (i32, i32, i32, i32)) -> Self {
+        Baseline { x1, y1, x2, y2 }
+    }
+}
+
+impl From<Baseline> for (i32, i32, i32, i32) {
+    fn from(baseline: Baseline) -> Self {
+        (baseline.x1, baseline.y1, baseline.x2, baseline.y2)
+    }
+}
+
+/// Font attributes of a word, as estimated by the legacy engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontAttributes {
+    pub is_bold: bool,
+    pub is_italic: bool,
+    pub is_underlined: bool,
+    pub is_monospace: bool,
+    pub is_serif: bool,
+    pub is_smallcaps: bool,
+    /// Font size in printer's points (1/72 inch).
+    pub pointsize: i32,
+    pub font_id: i32,
+}
+
+impl From<(bool, bool, bool, bool, bool, bool, i32, i32)> for FontAttributes {
+    fn from(
+        (
+            is_bold,
+            is_italic,
+            is_underlined,
+            is_monospace,
+            is_serif,
+            is_smallcaps,
+            pointsize,
+            font_id,
+        ): (bool, bool, bool, bool, bool, bool, i32, i32),
+    ) -> Self {
+        FontAttributes {
+            is_bold,
+            is_italic,
+            is_underlined,
+            is_monospace,
+            is_serif,
+            is_smallcaps,
+            pointsize,
+            font_id,
+        }
+    }
+}
+
+impl From<FontAttributes> for (bool, bool, bool, bool, bool, bool, i32, i32) {
+    fn from(font: FontAttributes) -> Self {
+        (
+            font.is_bold,
+            font.is_italic,
+            font.is_underlined,
+            font.is_monospace,
+            font.is_serif,
+            font.is_smallcaps,
+            font.pointsize,
+            font.font_id,
+        )
+    }
+}
+
+/// Orientation of a block, as reported by [`PageIterator::orientation`].
+///
+/// [`PageIterator::orientation`]: crate::PageIterator::orientation
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientationInfo {
+    pub orientation: TessOrientation,
+    pub writing_direction: TessWritingDirection,
+    pub textline_order: TessTextlineOrder,
+    /// Counter-clockwise rotation in radians that levels the block once
+    /// `orientation` has been corrected.
+    pub deskew_angle: f32,
+}
+
+impl
+    From<(
+        TessOrientation,
+        TessWritingDirection,
+        TessTextlineOrder,
+        f32,
+    )> for OrientationInfo
+{
+    fn from(
+        (orientation, writing_direction, textline_order, deskew_angle): (
+            TessOrientation,
+            TessWritingDirection,
+            TessTextlineOrder,
+            f32,
+        ),
+    ) -> Self {
+        OrientationInfo {
+            orientation,
+            writing_direction,
+            textline_order,
+            deskew_angle,
+        }
+    }
+}
+
+impl From<OrientationInfo>
+    for (
+        TessOrientation,
+        TessWritingDirection,
+        TessTextlineOrder,
+        f32,
+    )
+{
+    fn from(info: OrientationInfo) -> Self {
+        (
+            info.orientation,
+            info.writing_direction,
+            info.textline_order,
+            info.deskew_angle,
+        )
+    }
+}
+
+/// Layout information of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphInfo {
+    pub justification: TessParagraphJustification,
+    pub is_list_item: bool,
+    /// Whether the first line is not indented like the following ones,
+    /// typically the first paragraph after a heading.
+    pub is_crown: bool,
+    /// Indentation of the first line relative to the following ones, in pixels.
+    pub first_line_indent: i32,
+}
+
+impl From<(TessParagraphJustification, bool, bool, i32)> for ParagraphInfo {
+    fn from(
+        (justification, is_list_item, is_crown, first_line_indent): (
+            TessParagraphJustification,
+            bool,
+            bool,
+            i32,
+        ),
+    ) -> Self {
+        ParagraphInfo {
+            justification,
+            is_list_item,
+            is_crown,
+            first_line_indent,
+        }
+    }
+}
+
+impl From<ParagraphInfo> for (TessParagraphJustification, bool, bool, i32) {
+    fn from(info: ParagraphInfo) -> Self {
+        (
+            info.justification,
+            info.is_list_item,
+            info.is_crown,
+            info.first_line_indent,
+        )
+    }
+}
+
+/// Result of orientation and script detection, see [`TesseractAPI::detect_os`].
+///
+/// [`TesseractAPI::detect_os`]: crate::TesseractAPI::detect_os
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OsdResult {
+    /// Clockwise rotation of the page in degrees: 0, 90, 180 or 270.
+    pub orientation_degrees: i32,
+    pub orientation_confidence: f32,
+    /// Name of the detected script, e.g. `"Latin"`.
+    pub script: String,
+    pub script_confidence: f32,
+}
+
+impl From<(i32, f32, String, f32)> for OsdResult {
+    fn from(
+        (orientation_degrees, orientation_confidence, script, script_confidence): (
+            i32,
+            f32,
+            String,
+            f32,
+        ),
+    ) -> Self {
+        OsdResult {
+            orientation_degrees,
+            orientation_confidence,
+            script,
+            script_confidence,
+        }
+    }
+}
+
+impl From<OsdResult> for (i32, f32, String, f32) {
+    fn from(osd: OsdResult) -> Self {
+        (
+            osd.orientation_degrees,
+            osd.orientation_confidence,
+            osd.script,
+            osd.script_confidence,
+        )
+    }
+}
+
+/// Text of a word with its bounding box and confidence.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WordBox {
+    pub text: String,
+    pub bounding_box: BoundingBox,
+    /// Confidence from 0 to 100.
+    pub confidence: f32,
+}
+
+impl From<(String, i32, i32, i32, i32, f32)> for WordBox {
+    fn from(
+        (text, left, top, right, bottom, confidence): (String, i32, i32, i32, i32, f32),
+    ) -> Self {
+        WordBox {
+            text,
+            bounding_box: BoundingBox::new(left, top, right, bottom),
+            confidence,
+        }
+    }
+}
+
+impl From<WordBox> for (String, i32, i32, i32, i32, f32) {
+    fn from(word: WordBox) -> Self {
+        let bbox = word.bounding_box;
+        (
+            word.text,
+            bbox.left,
+            bbox.top,
+            bbox.right,
+            bbox.bottom,
+            word.confidence,
+        )
+    }
+}
+
+/// Image of a page element, as cut out by [`PageIterator::binary_image`] or
+/// [`PageIterator::image`].
+///
+/// [`PageIterator::binary_image`]: crate::PageIterator::binary_image
+/// [`PageIterator::image`]: crate::PageIterator::image
+#[derive(Debug, Clone)]
+pub struct ImageCrop {
+    /// The cropped pixels.
+    pub image: Pix,
+    /// Left edge of the crop in page coordinates.
+    pub left: i32,
+    /// Top edge of the crop in page coordinates.
+    pub top: i32,
+}
+
+/// Outcome of one page of [`TesseractAPI::process_pages_with_renderer`] or
+/// [`TesseractAPI::process_page`].
+///
+/// [`TesseractAPI::process_pages_with_renderer`]: crate::TesseractAPI::process_pages_with_renderer
+/// [`TesseractAPI::process_page`]: crate::TesseractAPI::process_page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageResult {
+    /// Zero-based index of the page in the input.
+    pub page: usize,
+    /// File the page was read from.
+    pub source: String,
+    /// Whether the page was recognized and handed to the renderer. `false`
+    /// if the page could not be read, or recognition failed or ran past the
+    /// timeout.
+    pub succeeded: bool,
+    /// Time spent recognizing and rendering the page.
+    pub elapsed: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounding_box_dimensions() {
+        let bbox = BoundingBox::new(10, 20, 50, 80);
+        assert_eq!(bbox.width(), 40);
+        assert_eq!(bbox.height(), 60);
+        assert!(!bbox.is_empty());
+        assert!(BoundingBox::new(10, 20, 5, 80).is_empty());
+        assert_eq!(BoundingBox::new(10, 20, 5, 80).width(), 0);
+    }
+
+    #[test]
+    fn test_bounding_box_intersect() {
+        let a = BoundingBox::new(0, 0, 10, 10);
+        let b = BoundingBox::new(5, 5, 20, 20);
+        assert_eq!(a.intersect(&b), Some(BoundingBox::new(5, 5, 10, 10)));
+        assert_eq!(b.intersect(&a), a.intersect(&b));
+        assert_eq!(a.intersect(&BoundingBox::new(10, 0, 20, 10)), None);
+        assert!(a.contains(&a.intersect(&b).unwrap()));
+    }
+
+    #[test]
+    fn test_bounding_box_union() {
+        let a = BoundingBox::new(0, 0, 10, 10);
+        let b = BoundingBox::new(5, 5, 20, 20);
+        assert_eq!(a.union(&b), BoundingBox::new(0, 0, 20, 20));
+        assert_eq!(a.union(&BoundingBox::default()), a);
+        assert_eq!(BoundingBox::default().union(&b), b);
+        assert!(a.union(&b).contains(&a));
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        let bbox: BoundingBox = (1, 2, 3, 4).into();
+        assert_eq!(<(i32, i32, i32, i32)>::from(bbox), (1, 2, 3, 4));
+
+        let word: WordBox = ("word".to_string(), 1, 2, 3, 4, 90.0).into();
+        assert_eq!(word.bounding_box, bbox);
+        let (text, left, _, _, bottom, confidence) = word.into();
+        assert_eq!(
+            (text.as_str(), left, bottom, confidence),
+            ("word", 1, 4, 90.0)
+        );
+
+        let osd = OsdResult::from((90, 5.0, "Latin".to_string(), 1.5));
+        assert_eq!(osd.script, "Latin");
+        assert_eq!(<(i32, f32, String, f32)>::from(osd).0, 90);
+
+        let font = FontAttributes::from((true, false, false, false, true, false, 12, 3));
+        assert!(font.is_bold && font.is_serif);
+        assert_eq!(font.pointsize, 12);
+    }
+}
@@ -0,0 +1,46 @@
+//! XML helpers shared by the hOCR and ALTO modules.
+
+use crate::error::{Result, TesseractError};
+
+/// Parses an XML document, allowing the `DOCTYPE` declarations that hOCR
+/// and some ALTO producers write.
+///
+/// # Arguments
+///
+/// * `text` - XML text.
+/// * `format` - Name of the format, used in error messages.
+///
+/// # Returns
+///
+/// Returns the document tree, or `ParseError` if the text is not well-formed.
+pub(crate) fn parse<'a>(text: &'a str, format: &'static str) -> Result<roxmltree::Document<'a>> {
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..roxmltree::ParsingOptions::default()
+    };
+    roxmltree::Document::parse_with_options(text, options).map_err(|e| error(format, e))
+}
+
+/// Builds a `ParseError` for the given format.
+pub(crate) fn error(format: &'static str, message: impl std::fmt::Display) -> TesseractError {
+    TesseractError::ParseError {
+        format,
+        message: message.to_string(),
+    }
+}
+
+/// Escapes text for use in XML content and attribute values.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -89,3 +89,83 @@ pub fn create_api_with_image() -> TesseractAPI {
     .expect("Failed to set image");
     api
 }
+
+/// Saves a test image as PGM, which Leptonica reads without extra codecs.
+pub fn write_pgm(image: &str, name: &str) -> PathBuf {
+    let source = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("test_images")
+        .join(image);
+    let path = std::env::temp_dir().join(format!("tesseract_test_{}.pgm", name));
+    image::open(source)
+        .expect("Failed to open test image")
+        .to_luma8()
+        .save(&path)
+        .expect("Failed to write PGM");
+    path
+}
+
+/// Saves test images as the pages of an uncompressed grayscale multi-page TIFF.
+pub fn write_tiff(name: &str, images: &[&str]) -> PathBuf {
+    let mut tiff = b"II*\0".to_vec();
+    // Offset of the first IFD, filled in below like every "next IFD" field.
+    let mut next_ifd_field = tiff.len();
+    tiff.extend_from_slice(&0u32.to_le_bytes());
+
+    for image in images {
+        let source = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("test_images")
+            .join(image);
+        let page = image::open(source)
+            .expect("Failed to open test image")
+            .to_luma8();
+        let (width, height) = page.dimensions();
+        let strip_offset = tiff.len() as u32;
+        tiff.extend_from_slice(page.as_raw());
+        if tiff.len() % 2 == 1 {
+            tiff.push(0);
+        }
+
+        let ifd_offset = tiff.len() as u32;
+        tiff[next_ifd_field..next_ifd_field + 4].copy_from_slice(&ifd_offset.to_le_bytes());
+        // (tag, type, value): type 3 is SHORT, type 4 is LONG.
+        let entries: [(u16, u16, u32); 9] = [
+            (256, 4, width),
+            (257, 4, height),
+            (258, 3, 8),
+            (259, 3, 1),
+            (262, 3, 1),
+            (273, 4, strip_offset),
+            (277, 3, 1),
+            (278, 4, height),
+            (279, 4, width * height),
+        ];
+        tiff.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        for (tag, kind, value) in entries {
+            tiff.extend_from_slice(&tag.to_le_bytes());
+            tiff.extend_from_slice(&kind.to_le_bytes());
+            tiff.extend_from_slice(&1u32.to_le_bytes());
+            if kind == 3 {
+                tiff.extend_from_slice(&(value as u16).to_le_bytes());
+                tiff.extend_from_slice(&[0, 0]);
+            } else {
+                tiff.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        next_ifd_field = tiff.len();
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+    }
+
+    let path = std::env::temp_dir().join(format!("tesseract_test_{}.tif", name));
+    std::fs::write(&path, tiff).expect("Failed to write TIFF");
+    path
+}
+
+/// Writes a page list file, as accepted by `process_pages`.
+pub fn write_list(name: &str, pages: &[&PathBuf]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tesseract_test_{}.txt", name));
+    let list: Vec<String> = pages.iter().map(|p| p.display().to_string()).collect();
+    std::fs::write(&path, list.join("\n")).expect("Failed to write page list");
+    path
+}
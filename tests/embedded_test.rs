@@ -1,6 +1,8 @@
 #[cfg(feature = "embed-tessdata")]
 mod embedded_tests {
-    use tesseract_rs::{embedded_languages, get_embedded_tessdata, TesseractAPI};
+    use tesseract_rs::{
+        embedded_languages, get_embedded_tessdata, OcrEngineMode, TesseractAPI, TesseractError,
+    };
 
     #[test]
     fn test_embedded_languages_available() {
@@ -53,16 +55,38 @@ mod embedded_tests {
         );
     }
 
+    #[test]
+    fn test_api_init_embedded_with_oem() {
+        let api = TesseractAPI::new();
+        api.init_embedded_with_oem("eng", OcrEngineMode::LstmOnly)
+            .expect("Should initialize embedded English data with LSTM");
+        assert_eq!(api.oem().unwrap(), OcrEngineMode::LstmOnly);
+
+        // The embedded tessdata_best models carry no legacy engine data
+        let api = TesseractAPI::new();
+        let result = api.init_embedded_with_oem("eng", OcrEngineMode::TesseractOnly);
+        assert!(matches!(
+            result,
+            Err(TesseractError::UnsupportedEngineMode { .. })
+        ));
+    }
+
     #[test]
     fn test_api_init_embedded_nonexistent() {
         let api = TesseractAPI::new();
 
         // Test initialization with non-existent language
         let result = api.init_embedded("nonexistent");
-        assert!(
-            result.is_err(),
-            "Should fail to initialize with non-existent language"
-        );
+        match result {
+            Err(TesseractError::MissingLanguage {
+                language,
+                available,
+            }) => {
+                assert_eq!(language, "nonexistent");
+                assert!(available.contains(&"eng".to_string()));
+            }
+            other => panic!("Expected MissingLanguage, got {:?}", other),
+        }
     }
 
     #[test]
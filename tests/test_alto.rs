@@ -0,0 +1,45 @@
+#[cfg(feature = "alto")]
+mod common;
+
+#[cfg(feature = "alto")]
+mod alto_tests {
+    use super::common::*;
+    use tesseract_rs::{AltoDocument, AltoProcessing, AltoSoftware, TesseractAPI};
+
+    const SAMPLE_TEXT: &str = "This is a sample text for OCR testing.";
+
+    #[test]
+    fn test_parse_tesseract_alto() {
+        let api = create_api_with_image();
+        let alto = AltoDocument::parse(&api.get_alto_text(0).unwrap()).expect("parse failed");
+        assert_eq!(alto.pages.len(), 1);
+        assert!(alto.pages[0].width > 0.0);
+        assert!(
+            alto.text().contains(SAMPLE_TEXT),
+            "Unexpected text: {}",
+            alto.text()
+        );
+    }
+
+    #[test]
+    fn test_write_alto_with_processing() {
+        let api = create_api_with_image();
+        let mut alto = AltoDocument::parse(&api.get_alto_text(0).unwrap()).unwrap();
+        alto.description.source_image = Some("sample_text.png".into());
+        alto.description.processing.push(AltoProcessing {
+            id: "OCR_0".into(),
+            category: Some("contentGeneration".into()),
+            software: Some(AltoSoftware {
+                name: Some("tesseract".into()),
+                version: Some(TesseractAPI::version()),
+                ..AltoSoftware::default()
+            }),
+            ..AltoProcessing::default()
+        });
+
+        let written = alto.to_alto();
+        assert!(written.contains("http://www.loc.gov/standards/alto/ns-v4#"));
+        assert!(written.contains("<fileName>sample_text.png</fileName>"));
+        assert_eq!(AltoDocument::parse(&written).unwrap(), alto);
+    }
+}
@@ -1,7 +1,9 @@
 mod common;
 use common::*;
 
-use tesseract_rs::{ChoiceIterator, TessPageSegMode, TesseractAPI};
+use tesseract_rs::{
+    ChoiceIterator, OcrEngineMode, Pix, TessPageSegMode, TesseractAPI, TesseractError,
+};
 
 // ---------------------------------------------------------------------------
 // 1. version()
@@ -455,7 +457,11 @@ fn test_init_2() {
     let tessdata_dir = get_tessdata_dir();
     let api = TesseractAPI::new();
     // OEM 3 = default (LSTM + legacy if available)
-    let result = api.init_2(tessdata_dir.to_str().unwrap(), "eng", 3);
+    let result = api.init_2(
+        tessdata_dir.to_str().unwrap(),
+        "eng",
+        OcrEngineMode::Default,
+    );
     assert!(result.is_ok(), "init_2 should succeed with valid params");
 }
 
@@ -624,10 +630,10 @@ fn test_get_thresholded_image() {
     let pix = api
         .get_thresholded_image()
         .expect("get_thresholded_image failed");
-    assert!(
-        !pix.is_null(),
-        "thresholded image pointer should not be null"
-    );
+    let (_, width, height) = load_test_image("sample_text.png").expect("Failed to load test image");
+    assert_eq!(pix.width(), width as i32);
+    assert_eq!(pix.height(), height as i32);
+    assert_eq!(pix.depth(), 1, "thresholded image should be binary");
 }
 
 // ---------------------------------------------------------------------------
@@ -642,11 +648,11 @@ fn test_get_thresholded_image() {
 //     api.set_page_seg_mode(TessPageSegMode::PSM_AUTO_OSD)
 //         .expect("set_page_seg_mode failed");
 //     let result = api.detect_os();
-//     if let Ok((orient_deg, orient_conf, script_name, script_conf)) = result {
-//         assert!((-360..=360).contains(&orient_deg));
-//         assert!(orient_conf.is_finite());
-//         assert!(!script_name.is_empty());
-//         assert!(script_conf.is_finite());
+//     if let Ok(osd) = result {
+//         assert!((-360..=360).contains(&osd.orientation_degrees));
+//         assert!(osd.orientation_confidence.is_finite());
+//         assert!(!osd.script.is_empty());
+//         assert!(osd.script_confidence.is_finite());
 //     }
 // }
 
@@ -671,20 +677,23 @@ fn test_get_page_iterator() {
 fn test_set_and_get_input_image() {
     let api = create_api_with_image();
     api.recognize().expect("recognize failed");
-    // After recognize, get_thresholded_image gives us a valid Pix pointer
+    // After recognize, get_thresholded_image gives us a valid Pix
     let pix = api
         .get_thresholded_image()
         .expect("get_thresholded_image failed");
 
     // Set it as the input image
-    api.set_input_image(pix).expect("set_input_image failed");
+    api.set_input_image(&pix).expect("set_input_image failed");
 
     // Get it back
     let retrieved = api.get_input_image().expect("get_input_image failed");
-    assert!(
-        !retrieved.is_null(),
-        "get_input_image should return non-null after set_input_image"
-    );
+    assert_eq!(retrieved.as_ptr(), pix.as_ptr());
+
+    // The engine's reference stays valid after ours is dropped
+    drop(pix);
+    drop(retrieved);
+    let retrieved = api.get_input_image().expect("get_input_image failed");
+    assert!(retrieved.width() > 0);
 }
 
 // ---------------------------------------------------------------------------
@@ -773,7 +782,12 @@ fn test_process_pages_invalid_file() {
 fn test_init_1() {
     let tessdata_dir = get_tessdata_dir();
     let api = TesseractAPI::new();
-    let result = api.init_1(tessdata_dir.to_str().unwrap(), "eng", 3, &[]);
+    let result = api.init_1(
+        tessdata_dir.to_str().unwrap(),
+        "eng",
+        OcrEngineMode::Default,
+        &[],
+    );
     assert!(
         result.is_ok(),
         "init_1 should succeed with valid params and empty configs"
@@ -787,7 +801,12 @@ fn test_init_1() {
 fn test_init_4() {
     let tessdata_dir = get_tessdata_dir();
     let api = TesseractAPI::new();
-    let result = api.init_4(tessdata_dir.to_str().unwrap(), "eng", 3, &[]);
+    let result = api.init_4(
+        tessdata_dir.to_str().unwrap(),
+        "eng",
+        OcrEngineMode::Default,
+        &[],
+    );
     assert!(
         result.is_ok(),
         "init_4 should succeed with valid params and empty configs"
@@ -804,7 +823,7 @@ fn test_init_5_with_traineddata_file() {
     let traineddata_path = tessdata_dir.join("eng.traineddata");
     let data = std::fs::read(&traineddata_path).expect("Failed to read eng.traineddata");
     let api = TesseractAPI::new();
-    let result = api.init_5(&data, data.len() as i32, "eng", 3, &[]);
+    let result = api.init_5(&data, data.len() as i32, "eng", OcrEngineMode::Default, &[]);
     assert!(
         result.is_ok(),
         "init_5 should succeed with valid traineddata bytes: {:?}",
@@ -813,17 +832,20 @@ fn test_init_5_with_traineddata_file() {
 }
 
 // ---------------------------------------------------------------------------
-// 55. set_image_2() with null pointer — exercises the code path
+// 55. set_image_2() with a blank Pix created in Rust
 // ---------------------------------------------------------------------------
 #[test]
-fn test_set_image_2_null() {
+fn test_set_image_2_blank_pix() {
     let api = create_initialized_api();
-    // Passing a null pointer should not crash — it's just a void FFI call.
-    let result = api.set_image_2(std::ptr::null_mut());
+    let pix = Pix::new(64, 32, 8).expect("Pix::new failed");
+    let result = api.set_image_2(&pix);
     assert!(
         result.is_ok(),
-        "set_image_2 with null should return Ok (void FFI call)"
+        "set_image_2 should accept a Rust-created Pix"
     );
+    drop(pix);
+    // Tesseract copied the image, so recognition still works after the drop
+    api.recognize().expect("recognize failed");
 }
 
 // ---------------------------------------------------------------------------
@@ -1004,8 +1026,12 @@ fn test_get_init_languages_as_string_uninitialized() {
 fn test_get_loaded_languages_after_init_2() {
     let tessdata_dir = get_tessdata_dir();
     let api = TesseractAPI::new();
-    api.init_2(tessdata_dir.to_str().unwrap(), "eng", 3)
-        .expect("init_2 failed");
+    api.init_2(
+        tessdata_dir.to_str().unwrap(),
+        "eng",
+        OcrEngineMode::Default,
+    )
+    .expect("init_2 failed");
     let langs = api
         .get_loaded_languages()
         .expect("get_loaded_languages failed");
@@ -1023,8 +1049,13 @@ fn test_get_loaded_languages_after_init_2() {
 fn test_get_available_languages_after_init_1() {
     let tessdata_dir = get_tessdata_dir();
     let api = TesseractAPI::new();
-    api.init_1(tessdata_dir.to_str().unwrap(), "eng", 3, &[])
-        .expect("init_1 failed");
+    api.init_1(
+        tessdata_dir.to_str().unwrap(),
+        "eng",
+        OcrEngineMode::Default,
+        &[],
+    )
+    .expect("init_1 failed");
     let langs = api
         .get_available_languages()
         .expect("get_available_languages failed");
@@ -1123,7 +1154,12 @@ fn test_init_invalid_language() {
 fn test_init_1_invalid_language() {
     let tessdata_dir = get_tessdata_dir();
     let api = TesseractAPI::new();
-    let result = api.init_1(tessdata_dir.to_str().unwrap(), "zzz_nonexistent", 3, &[]);
+    let result = api.init_1(
+        tessdata_dir.to_str().unwrap(),
+        "zzz_nonexistent",
+        OcrEngineMode::Default,
+        &[],
+    );
     assert!(
         result.is_err(),
         "init_1 should fail with a non-existent language"
@@ -1137,7 +1173,12 @@ fn test_init_1_invalid_language() {
 fn test_init_4_invalid_language() {
     let tessdata_dir = get_tessdata_dir();
     let api = TesseractAPI::new();
-    let result = api.init_4(tessdata_dir.to_str().unwrap(), "zzz_nonexistent", 3, &[]);
+    let result = api.init_4(
+        tessdata_dir.to_str().unwrap(),
+        "zzz_nonexistent",
+        OcrEngineMode::Default,
+        &[],
+    );
     assert!(
         result.is_err(),
         "init_4 should fail with a non-existent language"
@@ -1201,12 +1242,12 @@ fn test_set_image_2_with_valid_pix() {
     let pix = api
         .get_thresholded_image()
         .expect("get_thresholded_image failed");
-    // Now use this pix pointer with set_image_2
+    // Now use this pix with set_image_2
     let api2 = create_initialized_api();
-    let result = api2.set_image_2(pix);
+    let result = api2.set_image_2(&pix);
     assert!(
         result.is_ok(),
-        "set_image_2 should succeed with a valid pix pointer"
+        "set_image_2 should succeed with a valid pix"
     );
 }
 
@@ -1481,3 +1522,235 @@ fn test_get_iterators_error_no_image() {
         "get_iterators() should fail when no image is set"
     );
 }
+
+// ---------------------------------------------------------------------------
+// 99. set_image_from_memory() with data that is not an image
+// ---------------------------------------------------------------------------
+#[test]
+fn test_set_image_from_memory_invalid_data() {
+    let api = create_api_without_image();
+    let result = api.set_image_from_memory(b"definitely not an image");
+    assert!(matches!(result, Err(TesseractError::InvalidImageData)));
+}
+
+// ---------------------------------------------------------------------------
+// 100. set_image_from_file() with a missing file
+// ---------------------------------------------------------------------------
+#[test]
+fn test_set_image_from_file_missing() {
+    let api = create_api_without_image();
+    let result = api.set_image_from_file("/nonexistent/image.png");
+    assert!(matches!(result, Err(TesseractError::IoError { .. })));
+}
+
+// ---------------------------------------------------------------------------
+// 101-102. Encoded PNG input (needs a Leptonica built with codecs)
+// ---------------------------------------------------------------------------
+#[cfg(feature = "image-codecs")]
+mod codec_tests {
+    use super::*;
+
+    const SAMPLE_PATH: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/test_images/sample_text.png"
+    );
+
+    #[test]
+    fn test_set_image_from_memory_png() {
+        let api = create_initialized_api();
+        let data = std::fs::read(SAMPLE_PATH).expect("Failed to read sample image");
+        api.set_image_from_memory(&data)
+            .expect("set_image_from_memory failed");
+        let text = api.get_utf8_text().expect("get_utf8_text failed");
+        assert!(
+            text.contains("This is a sample text for OCR testing."),
+            "Unexpected text: {}",
+            text
+        );
+    }
+
+    #[test]
+    fn test_set_image_from_file_png() {
+        let api = create_initialized_api();
+        api.set_image_from_file(SAMPLE_PATH)
+            .expect("set_image_from_file failed");
+        assert_eq!(api.get_input_name().unwrap(), SAMPLE_PATH);
+        let text = api.get_utf8_text().expect("get_utf8_text failed");
+        assert!(
+            text.contains("This is a sample text for OCR testing."),
+            "Unexpected text: {}",
+            text
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 103. oem() reports the requested engine mode
+// ---------------------------------------------------------------------------
+#[test]
+fn test_oem_lstm_only() {
+    let tessdata_dir = get_tessdata_dir();
+    let api = TesseractAPI::new();
+    api.init_2(
+        tessdata_dir.to_str().unwrap(),
+        "eng",
+        OcrEngineMode::LstmOnly,
+    )
+    .expect("init_2 failed");
+    assert_eq!(api.oem().unwrap(), OcrEngineMode::LstmOnly);
+}
+
+// ---------------------------------------------------------------------------
+// 104. oem() resolves the default mode from the traineddata
+// ---------------------------------------------------------------------------
+#[test]
+fn test_oem_default_resolves_to_lstm() {
+    // The test tessdata comes from tessdata_best, which only has LSTM models
+    let api = create_initialized_api();
+    assert_eq!(api.oem().unwrap(), OcrEngineMode::LstmOnly);
+}
+
+// ---------------------------------------------------------------------------
+// 105. Legacy engine modes are rejected for LSTM-only traineddata
+// ---------------------------------------------------------------------------
+#[test]
+fn test_init_unsupported_engine_mode() {
+    let tessdata_dir = get_tessdata_dir();
+    let datapath = tessdata_dir.to_str().unwrap();
+    let api = TesseractAPI::new();
+    for oem in [OcrEngineMode::TesseractOnly, OcrEngineMode::Combined] {
+        assert!(matches!(
+            api.init_2(datapath, "eng", oem),
+            Err(TesseractError::UnsupportedEngineMode { .. })
+        ));
+        assert!(matches!(
+            api.init_1(datapath, "eng", oem, &[]),
+            Err(TesseractError::UnsupportedEngineMode { .. })
+        ));
+        assert!(matches!(
+            api.init_4(datapath, "eng", oem, &[]),
+            Err(TesseractError::UnsupportedEngineMode { .. })
+        ));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 106. init_5() checks the engine mode against the in-memory traineddata
+// ---------------------------------------------------------------------------
+#[test]
+fn test_init_5_unsupported_engine_mode() {
+    let data = std::fs::read(get_tessdata_dir().join("eng.traineddata"))
+        .expect("Failed to read eng.traineddata");
+    let api = TesseractAPI::new();
+    let result = api.init_5(
+        &data,
+        data.len() as i32,
+        "eng",
+        OcrEngineMode::TesseractOnly,
+        &[],
+    );
+    assert!(matches!(
+        result,
+        Err(TesseractError::UnsupportedEngineMode { .. })
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// 107. init() names the missing traineddata file
+// ---------------------------------------------------------------------------
+#[test]
+fn test_init_missing_traineddata() {
+    let tessdata_dir = get_tessdata_dir();
+    let api = TesseractAPI::new();
+    match api.init(&tessdata_dir, "eng+zzz_missing") {
+        Err(TesseractError::MissingTraineddata { language, path }) => {
+            assert_eq!(language, "zzz_missing");
+            assert_eq!(path, tessdata_dir.join("zzz_missing.traineddata"));
+        }
+        other => panic!("Expected MissingTraineddata, got {:?}", other),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 108. Variable errors carry the variable name and value
+// ---------------------------------------------------------------------------
+#[test]
+fn test_variable_error_context() {
+    let api = create_initialized_api();
+    match api.set_variable("not_a_real_variable_xyz", "42") {
+        Err(TesseractError::SetVariableError { name, value }) => {
+            assert_eq!(name, "not_a_real_variable_xyz");
+            assert_eq!(value, "42");
+        }
+        other => panic!("Expected SetVariableError, got {:?}", other),
+    }
+    match api.get_int_variable("not_a_real_variable_xyz") {
+        Err(TesseractError::GetVariableError { name }) => {
+            assert_eq!(name, "not_a_real_variable_xyz")
+        }
+        other => panic!("Expected GetVariableError, got {:?}", other),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 109. process_pages() errors carry the input path
+// ---------------------------------------------------------------------------
+#[test]
+fn test_process_pages_error_context() {
+    let api = create_initialized_api();
+    match api.process_pages("/nonexistent/image.png", None, 5000) {
+        Err(TesseractError::ProcessPagesError { path, .. }) => {
+            assert_eq!(path, "/nonexistent/image.png")
+        }
+        other => panic!("Expected ProcessPagesError, got {:?}", other),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 110. try_clone() after the numbered init methods
+// ---------------------------------------------------------------------------
+#[test]
+fn test_try_clone_after_numbered_init() {
+    let tessdata_dir = get_tessdata_dir();
+    let datapath = tessdata_dir.to_str().unwrap();
+
+    let api = TesseractAPI::new();
+    api.init_2(datapath, "eng", OcrEngineMode::LstmOnly)
+        .expect("init_2 failed");
+    let clone = api.try_clone().expect("try_clone failed");
+    assert_eq!(clone.get_init_languages_as_string().unwrap(), "eng");
+    assert_eq!(clone.oem().unwrap(), OcrEngineMode::LstmOnly);
+
+    let api = TesseractAPI::new();
+    api.init_4(datapath, "eng", OcrEngineMode::LstmOnly, &[])
+        .expect("init_4 failed");
+    let clone = api.try_clone().expect("try_clone failed");
+    assert_eq!(clone.get_init_languages_as_string().unwrap(), "eng");
+}
+
+// ---------------------------------------------------------------------------
+// 111. A failed init() does not replace the recorded configuration
+// ---------------------------------------------------------------------------
+#[test]
+fn test_try_clone_after_failed_init() {
+    let api = create_initialized_api();
+    assert!(api.init(get_tessdata_dir(), "eng+zzz_missing").is_err());
+    let clone = api.try_clone().expect("try_clone failed");
+    assert_eq!(clone.get_init_languages_as_string().unwrap(), "eng");
+}
+
+// ---------------------------------------------------------------------------
+// 112. init_5() rejects a data_size outside the data
+// ---------------------------------------------------------------------------
+#[test]
+fn test_init_5_data_size_out_of_range() {
+    let data = std::fs::read(get_tessdata_dir().join("eng.traineddata"))
+        .expect("Failed to read eng.traineddata");
+    let api = TesseractAPI::new();
+    for size in [-1, data.len() as i32 + 1] {
+        assert!(matches!(
+            api.init_5(&data, size, "eng", OcrEngineMode::Default, &[]),
+            Err(TesseractError::InvalidParameterError)
+        ));
+    }
+}
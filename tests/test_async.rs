@@ -0,0 +1,119 @@
+#[cfg(feature = "tokio")]
+mod common;
+
+#[cfg(feature = "tokio")]
+mod async_tests {
+    use super::common::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tesseract_rs::{AsyncTesseract, TesseractAPI};
+    use tokio::sync::Semaphore;
+
+    const SAMPLE_TEXT: &str = "This is a sample text for OCR testing.";
+
+    async fn set_sample_image(ocr: &AsyncTesseract, copies: usize) {
+        let (image_data, width, height) =
+            load_test_image("sample_text.png").expect("Failed to load test image");
+        ocr.set_image(
+            image_data.repeat(copies),
+            width as i32,
+            (height as usize * copies) as i32,
+            3,
+            3 * width as i32,
+        )
+        .await
+        .expect("Failed to set image");
+    }
+
+    #[tokio::test]
+    async fn test_async_get_utf8_text() {
+        let ocr = AsyncTesseract::new(TesseractAPI::new());
+        ocr.init(get_tessdata_dir(), "eng")
+            .await
+            .expect("init failed");
+        set_sample_image(&ocr, 1).await;
+
+        let text = ocr.get_utf8_text().await.expect("get_utf8_text failed");
+        assert!(text.contains(SAMPLE_TEXT), "Unexpected text: {}", text);
+
+        let hocr = ocr.get_hocr_text(0).await.expect("get_hocr_text failed");
+        assert!(hocr.contains("ocrx_word"));
+        let confidence = ocr.mean_text_conf().await.unwrap();
+        assert!((0..=100).contains(&confidence));
+    }
+
+    #[tokio::test]
+    async fn test_async_text_after_layout_analysis() {
+        let ocr = AsyncTesseract::new(TesseractAPI::new());
+        ocr.init(get_tessdata_dir(), "eng")
+            .await
+            .expect("init failed");
+        set_sample_image(&ocr, 1).await;
+        ocr.run(|api| api.analyse_layout().map(drop))
+            .await
+            .expect("analyse_layout failed");
+
+        let text = ocr.get_utf8_text().await.expect("get_utf8_text failed");
+        assert!(text.contains(SAMPLE_TEXT), "Unexpected text: {}", text);
+    }
+
+    #[tokio::test]
+    async fn test_async_recognize_then_read() {
+        let ocr = AsyncTesseract::new(create_initialized_api());
+        set_sample_image(&ocr, 1).await;
+        ocr.recognize().await.expect("recognize failed");
+        let text = ocr.get_utf8_text().await.unwrap();
+        assert!(text.contains(SAMPLE_TEXT), "Unexpected text: {}", text);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_future_cancels_recognition() {
+        const COPIES: usize = 20;
+        let ocr = AsyncTesseract::new(create_initialized_api());
+        set_sample_image(&ocr, COPIES).await;
+
+        let result = tokio::time::timeout(Duration::from_millis(20), ocr.recognize()).await;
+        assert!(result.is_err(), "Recognition finished before the timeout");
+
+        // Waits for the cancelled recognition to give up its permit
+        let text = ocr.run(|api| api.get_utf8_text()).await.unwrap();
+        assert!(
+            text.matches(SAMPLE_TEXT).count() < COPIES,
+            "Recognition was not cancelled: {}",
+            text
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_shared_limit_bounds_concurrency() {
+        let limit = Arc::new(Semaphore::new(1));
+        let engines: Vec<_> = (0..3)
+            .map(|_| AsyncTesseract::with_limit(TesseractAPI::new(), Arc::clone(&limit)))
+            .collect();
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let tasks: Vec<_> = engines
+            .into_iter()
+            .map(|ocr| {
+                let running = Arc::clone(&running);
+                let peak = Arc::clone(&peak);
+                tokio::spawn(async move {
+                    ocr.run(move |_| {
+                        let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(now, Ordering::SeqCst);
+                        std::thread::sleep(Duration::from_millis(20));
+                        running.fetch_sub(1, Ordering::SeqCst);
+                        Ok(())
+                    })
+                    .await
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+        assert_eq!(peak.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,207 @@
+mod common;
+use common::*;
+
+use tesseract_rs::{OcrEngineMode, TessPageSegMode, TesseractAPI, TesseractError};
+
+fn recognize_sample(api: &TesseractAPI) -> String {
+    let (image_data, width, height) =
+        load_test_image("sample_text.png").expect("Failed to load test image");
+    api.set_image(
+        &image_data,
+        width as i32,
+        height as i32,
+        3,
+        3 * width as i32,
+    )
+    .expect("Failed to set image");
+    api.get_utf8_text().expect("get_utf8_text failed")
+}
+
+#[test]
+fn test_builder_defaults_to_english() {
+    let api = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .build()
+        .expect("build failed");
+    assert_eq!(api.get_init_languages_as_string().unwrap(), "eng");
+    let text = recognize_sample(&api);
+    assert!(
+        text.contains("This is a sample text for OCR testing."),
+        "Unexpected text: {}",
+        text
+    );
+}
+
+#[test]
+fn test_builder_multiple_languages() {
+    let api = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .languages(&["eng", "tur"])
+        .build()
+        .expect("build failed");
+    assert_eq!(api.get_init_languages_as_string().unwrap(), "eng+tur");
+}
+
+#[test]
+fn test_builder_oem_and_psm() {
+    let api = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .language("eng")
+        .oem(OcrEngineMode::LstmOnly)
+        .psm(TessPageSegMode::PSM_SINGLE_BLOCK)
+        .build()
+        .expect("build failed");
+    assert_eq!(api.oem().unwrap(), OcrEngineMode::LstmOnly);
+    assert_eq!(
+        api.get_page_seg_mode().unwrap(),
+        TessPageSegMode::PSM_SINGLE_BLOCK
+    );
+}
+
+#[test]
+fn test_builder_init_variable() {
+    // load_system_dawg is init-only: setting it afterwards is rejected
+    let api = create_initialized_api();
+    assert!(api.set_variable("load_system_dawg", "0").is_err());
+
+    let api = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .init_variable("load_system_dawg", "0")
+        .build()
+        .expect("build failed");
+    assert!(!api.get_bool_variable("load_system_dawg").unwrap());
+}
+
+#[test]
+fn test_builder_variable() {
+    let api = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .variable("tessedit_char_whitelist", "0123456789")
+        .build()
+        .expect("build failed");
+    assert_eq!(
+        api.get_string_variable("tessedit_char_whitelist").unwrap(),
+        "0123456789"
+    );
+}
+
+#[test]
+fn test_builder_config_file() {
+    let config_path = std::env::temp_dir().join("tesseract_rs_builder_test.config");
+    std::fs::write(&config_path, "tessedit_char_blacklist xyz\n").unwrap();
+
+    let api = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .config_file(config_path.to_str().unwrap())
+        .build()
+        .expect("build failed");
+    assert_eq!(
+        api.get_string_variable("tessedit_char_blacklist").unwrap(),
+        "xyz"
+    );
+    let _ = std::fs::remove_file(config_path);
+}
+
+#[test]
+fn test_builder_traineddata_from_memory() {
+    let data = std::fs::read(get_tessdata_dir().join("eng.traineddata"))
+        .expect("Failed to read eng.traineddata");
+    let api = TesseractAPI::builder()
+        .traineddata(data)
+        .language("eng")
+        .build()
+        .expect("build failed");
+    let text = recognize_sample(&api);
+    assert!(
+        text.contains("This is a sample text for OCR testing."),
+        "Unexpected text: {}",
+        text
+    );
+}
+
+#[test]
+fn test_builder_unsupported_engine_mode() {
+    let result = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .oem(OcrEngineMode::TesseractOnly)
+        .build();
+    assert!(matches!(
+        result,
+        Err(TesseractError::UnsupportedEngineMode { .. })
+    ));
+}
+
+#[test]
+fn test_builder_invalid_language() {
+    let result = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .language("zzz_nonexistent")
+        .build();
+    assert!(matches!(
+        result,
+        Err(TesseractError::MissingTraineddata { .. })
+    ));
+
+    let result = TesseractAPI::builder().language("").build();
+    assert!(matches!(result, Err(TesseractError::InvalidParameterError)));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_builder_non_utf8_datapath() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let result = TesseractAPI::builder()
+        .datapath(OsStr::from_bytes(b"/tmp/tess\xffdata"))
+        .build();
+    assert!(matches!(
+        result,
+        Err(TesseractError::IoError { path: Some(_), .. })
+    ));
+}
+
+#[test]
+fn test_builder_try_clone_reproduces_configuration() {
+    let api = TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .languages(&["eng", "tur"])
+        .oem(OcrEngineMode::LstmOnly)
+        .psm(TessPageSegMode::PSM_SINGLE_LINE)
+        .init_variable("load_system_dawg", "0")
+        .variable("tessedit_char_whitelist", "abc")
+        .build()
+        .expect("build failed");
+
+    let clone = api.try_clone().expect("try_clone failed");
+    assert_eq!(clone.get_init_languages_as_string().unwrap(), "eng+tur");
+    assert_eq!(clone.oem().unwrap(), OcrEngineMode::LstmOnly);
+    assert_eq!(
+        clone.get_page_seg_mode().unwrap(),
+        TessPageSegMode::PSM_SINGLE_LINE
+    );
+    assert!(!clone.get_bool_variable("load_system_dawg").unwrap());
+    assert_eq!(
+        clone
+            .get_string_variable("tessedit_char_whitelist")
+            .unwrap(),
+        "abc"
+    );
+}
+
+#[test]
+fn test_builder_try_clone_from_memory() {
+    let data = std::fs::read(get_tessdata_dir().join("eng.traineddata"))
+        .expect("Failed to read eng.traineddata");
+    let api = TesseractAPI::builder()
+        .traineddata(data)
+        .build()
+        .expect("build failed");
+    let clone = api.try_clone().expect("try_clone failed");
+    let text = recognize_sample(&clone);
+    assert!(
+        text.contains("This is a sample text for OCR testing."),
+        "Unexpected text: {}",
+        text
+    );
+}
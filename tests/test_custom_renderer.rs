@@ -0,0 +1,120 @@
+mod common;
+use common::*;
+use tesseract_rs::{CsvRenderer, MarkdownRenderer, Page, Renderer, Result};
+
+/// A custom format: one line per page with its number of words.
+#[derive(Default)]
+struct WordCounts {
+    title: String,
+    counts: Vec<usize>,
+    ended: bool,
+}
+
+impl Renderer for WordCounts {
+    fn begin_document(&mut self, title: &str) -> Result<()> {
+        self.title = title.to_owned();
+        Ok(())
+    }
+
+    fn add_page(&mut self, page: &Page) -> Result<()> {
+        self.counts.push(page.words().count());
+        Ok(())
+    }
+
+    fn end_document(&mut self) -> Result<()> {
+        self.ended = true;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_custom_renderer_with_render_pages() {
+    let sample = write_pgm("sample_text.png", "custom_sample");
+    let list = write_list("custom_list", &[&sample, &sample]);
+    let api = create_initialized_api();
+    let mut counts = WordCounts::default();
+
+    let results = api
+        .render_pages(list.to_str().unwrap(), &mut counts, 10000)
+        .expect("render_pages failed");
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.succeeded));
+    assert_eq!(counts.title, list.to_str().unwrap());
+    assert_eq!(counts.counts, [8, 8]);
+    assert!(counts.ended);
+
+    let _ = std::fs::remove_file(sample);
+    let _ = std::fs::remove_file(list);
+}
+
+#[test]
+fn test_render_pages_skips_unreadable_page() {
+    let sample = write_pgm("sample_text.png", "custom_unreadable");
+    let missing = std::path::PathBuf::from("/nonexistent/page.pgm");
+    let list = write_list("custom_unreadable_list", &[&missing, &sample]);
+    let api = create_initialized_api();
+    let mut counts = WordCounts::default();
+
+    let results = api
+        .render_pages(list.to_str().unwrap(), &mut counts, 0)
+        .expect("render_pages failed");
+    assert_eq!(results.len(), 2);
+    assert!(!results[0].succeeded);
+    assert_eq!(results[0].source, missing.display().to_string());
+    assert!(results[1].succeeded);
+    assert_eq!(counts.counts, [8]);
+    assert!(counts.ended);
+
+    let _ = std::fs::remove_file(sample);
+    let _ = std::fs::remove_file(list);
+}
+
+#[test]
+fn test_markdown_renderer_add_image() {
+    let api = create_api_with_image();
+    let mut renderer = MarkdownRenderer::new(Vec::new());
+    renderer.begin_document("Sample").unwrap();
+    renderer.add_image(&api).unwrap();
+    renderer.end_document().unwrap();
+    let markdown = String::from_utf8(renderer.into_inner()).unwrap();
+    assert!(
+        markdown.starts_with("# Sample\n\nThis is a sample text for OCR testing.\n"),
+        "{}",
+        markdown
+    );
+}
+
+#[test]
+fn test_csv_renderer_with_render_pages() {
+    let sample = write_pgm("sample_text.png", "csv_sample");
+    let api = create_initialized_api();
+    let mut renderer = CsvRenderer::new(Vec::new());
+    api.render_pages(sample.to_str().unwrap(), &mut renderer, 0)
+        .expect("render_pages failed");
+    let csv = String::from_utf8(renderer.into_inner()).unwrap();
+    let words: Vec<&str> = csv
+        .lines()
+        .skip(1)
+        .map(|row| row.rsplit(',').next().unwrap())
+        .collect();
+    assert_eq!(words.join(" "), "This is a sample text for OCR testing.");
+    assert!(csv.lines().skip(1).all(|row| row.starts_with("1,")));
+    let _ = std::fs::remove_file(sample);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_json_renderer_matches_document() {
+    use tesseract_rs::{Document, JsonRenderer};
+
+    let api = create_api_with_image();
+    let mut renderer = JsonRenderer::new(Vec::new());
+    renderer.begin_document("Sample").unwrap();
+    renderer.add_image(&api).unwrap();
+    renderer.end_document().unwrap();
+    let json = String::from_utf8(renderer.into_inner()).unwrap();
+    assert_eq!(
+        Document::from_json(&json).unwrap(),
+        api.recognize_document().unwrap()
+    );
+}
@@ -0,0 +1,160 @@
+mod common;
+use common::*;
+
+use std::sync::{Arc, Mutex};
+use tesseract_rs::{
+    clear_diagnostic_handler, set_diagnostic_handler, DiagnosticSource, TesseractAPI,
+};
+
+// Tesseract's output is process-wide: messages of concurrent tests could be
+// attributed to each other's operations.
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// An engine about to recognize with an implausible resolution, which makes
+/// Tesseract print a warning.
+fn create_api_with_bad_resolution() -> TesseractAPI {
+    let api = create_api_with_image();
+    api.set_source_resolution(10)
+        .expect("set_source_resolution failed");
+    api
+}
+
+fn mentions_resolution(messages: &[String]) -> bool {
+    messages
+        .iter()
+        .any(|message| message.contains("resolution"))
+}
+
+#[test]
+fn test_capture_diagnostics_of_last_operation() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let api = create_api_with_bad_resolution();
+    api.set_capture_diagnostics(true)
+        .expect("set_capture_diagnostics failed");
+
+    api.recognize().expect("recognize failed");
+    let messages = api.last_diagnostics().unwrap();
+    assert!(
+        mentions_resolution(&messages),
+        "Expected a resolution warning, got {:?}",
+        messages
+    );
+
+    // Recognition is already done, so this operation prints nothing
+    api.get_utf8_text().expect("get_utf8_text failed");
+    assert!(api.last_diagnostics().unwrap().is_empty());
+}
+
+#[test]
+fn test_diagnostics_not_captured_by_default() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let api = create_api_with_bad_resolution();
+    api.recognize().expect("recognize failed");
+    assert!(api.last_diagnostics().unwrap().is_empty());
+}
+
+#[test]
+fn test_capture_diagnostics_disabled_again() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let api = create_api_with_bad_resolution();
+    api.set_capture_diagnostics(true).unwrap();
+    api.set_capture_diagnostics(false).unwrap();
+    api.recognize().expect("recognize failed");
+    assert!(api.last_diagnostics().unwrap().is_empty());
+}
+
+#[test]
+fn test_try_clone_keeps_capture_setting() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let api = create_initialized_api();
+    api.set_capture_diagnostics(true).unwrap();
+    let clone = api.try_clone().expect("try_clone failed");
+
+    let (image_data, width, height) =
+        load_test_image("sample_text.png").expect("Failed to load test image");
+    clone
+        .set_image(
+            &image_data,
+            width as i32,
+            height as i32,
+            3,
+            3 * width as i32,
+        )
+        .unwrap();
+    clone.set_source_resolution(10).unwrap();
+    clone.recognize().expect("recognize failed");
+    assert!(mentions_resolution(&clone.last_diagnostics().unwrap()));
+}
+
+#[test]
+fn test_diagnostic_handler() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&received);
+    set_diagnostic_handler(move |source, message| {
+        sink.lock().unwrap().push((source, message.to_owned()));
+    })
+    .expect("set_diagnostic_handler failed");
+
+    let api = create_api_with_bad_resolution();
+    api.recognize().expect("recognize failed");
+    clear_diagnostic_handler().expect("clear_diagnostic_handler failed");
+
+    let received = received.lock().unwrap();
+    assert!(
+        received.iter().any(|(source, message)| {
+            *source == DiagnosticSource::Tesseract && message.contains("resolution")
+        }),
+        "Expected a resolution warning, got {:?}",
+        received
+    );
+    assert!(received.iter().all(|(_, message)| !message.ends_with('\n')));
+}
+
+#[test]
+fn test_diagnostic_handler_set_again_after_clear() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    // Tesseract opens its output file on the first message; switching the
+    // redirection off and on again afterwards must not lose later messages.
+    set_diagnostic_handler(|_, _| {}).expect("set_diagnostic_handler failed");
+    create_api_with_bad_resolution()
+        .recognize()
+        .expect("recognize failed");
+    clear_diagnostic_handler().expect("clear_diagnostic_handler failed");
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&received);
+    set_diagnostic_handler(move |_, message| {
+        sink.lock().unwrap().push(message.to_owned());
+    })
+    .expect("set_diagnostic_handler failed");
+    let api = create_api_with_bad_resolution();
+    api.recognize().expect("recognize failed");
+    clear_diagnostic_handler().expect("clear_diagnostic_handler failed");
+
+    let received = received.lock().unwrap();
+    assert!(
+        mentions_resolution(&received),
+        "Expected a resolution warning, got {:?}",
+        received
+    );
+}
+
+#[test]
+fn test_capture_diagnostics_enabled_again() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let api = create_api_with_bad_resolution();
+    api.set_capture_diagnostics(true).unwrap();
+    api.recognize().expect("recognize failed");
+    api.set_capture_diagnostics(false).unwrap();
+
+    let api = create_api_with_bad_resolution();
+    api.set_capture_diagnostics(true).unwrap();
+    api.recognize().expect("recognize failed");
+    let messages = api.last_diagnostics().unwrap();
+    assert!(
+        mentions_resolution(&messages),
+        "Expected a resolution warning, got {:?}",
+        messages
+    );
+}
@@ -0,0 +1,102 @@
+mod common;
+use common::*;
+use tesseract_rs::{TessPolyBlockType, TesseractAPI, TesseractError};
+
+const SAMPLE_TEXT: &str = "This is a sample text for OCR testing.";
+
+#[test]
+fn test_recognize_document_text() {
+    let api = create_api_with_image();
+    let document = api.recognize_document().expect("recognize_document failed");
+    assert_eq!(document.pages.len(), 1);
+    let text = document.text();
+    assert!(text.contains(SAMPLE_TEXT), "Unexpected text: {}", text);
+    assert_eq!(
+        text.split_whitespace().collect::<Vec<_>>(),
+        api.get_utf8_text()
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_recognize_document_hierarchy() {
+    let api = create_api_with_image();
+    let document = api.recognize_document().unwrap();
+    let page = &document.pages[0];
+    assert!(page.width > 0 && page.height > 0);
+    assert!(!page.blocks.is_empty());
+
+    for block in &page.blocks {
+        if block.paragraphs.is_empty() {
+            continue;
+        }
+        assert_eq!(block.block_type, TessPolyBlockType::PT_FLOWING_TEXT);
+        for paragraph in &block.paragraphs {
+            assert!(block.bounding_box.contains(&paragraph.bounding_box));
+            for line in &paragraph.lines {
+                assert!(paragraph.bounding_box.contains(&line.bounding_box));
+                assert!(line.baseline.is_some());
+                assert!(!line.words.is_empty());
+                for word in &line.words {
+                    assert!(line.bounding_box.contains(&word.bounding_box));
+                    assert!((0.0..=100.0).contains(&word.confidence));
+                    assert_eq!(word.language.as_deref(), Some("eng"));
+                    let symbols: String = word.symbols.iter().map(|s| s.text.as_str()).collect();
+                    assert_eq!(symbols, word.text);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_recognize_document_word_flags() {
+    let api = create_api_with_image();
+    let document = api.recognize_document().unwrap();
+    let sample = document
+        .words()
+        .find(|word| word.text == "sample")
+        .expect("Word 'sample' not found");
+    assert!(!sample.is_numeric);
+    assert!(sample
+        .symbols
+        .iter()
+        .all(|s| !s.is_subscript && !s.is_dropcap));
+}
+
+#[test]
+fn test_recognize_document_reuses_recognition() {
+    let api = create_api_with_image();
+    api.recognize().unwrap();
+    let document = api.recognize_document().unwrap();
+    assert!(document.text().contains(SAMPLE_TEXT));
+}
+
+#[test]
+fn test_recognize_document_after_layout_analysis() {
+    let api = create_api_with_image();
+    api.analyse_layout().unwrap();
+    let document = api.recognize_document().unwrap();
+    assert!(document.text().contains(SAMPLE_TEXT));
+}
+
+#[test]
+fn test_recognize_document_blank_image() {
+    let api = create_initialized_api();
+    let image = vec![255u8; 100 * 100];
+    api.set_image(&image, 100, 100, 1, 100).unwrap();
+    let document = api.recognize_document().unwrap();
+    assert_eq!(document.words().count(), 0);
+    assert_eq!(document.pages[0].width, 100);
+}
+
+#[test]
+fn test_recognize_document_without_image() {
+    let api = TesseractAPI::new();
+    assert!(matches!(
+        api.recognize_document(),
+        Err(TesseractError::UninitializedError) | Err(TesseractError::OcrError { .. })
+    ));
+}
@@ -57,8 +57,8 @@ fn test_e2e_word_iteration_with_bounds() {
 
     let mut words = Vec::new();
     loop {
-        if let Ok((text, left, top, right, bottom, confidence)) = iter.get_word_with_bounds() {
-            words.push((text, left, top, right, bottom, confidence));
+        if let Ok(word) = iter.get_word_with_bounds() {
+            words.push(word);
         }
         if !iter.next(TessPageIteratorLevel::RIL_WORD).unwrap_or(false) {
             break;
@@ -68,10 +68,23 @@ fn test_e2e_word_iteration_with_bounds() {
     assert!(!words.is_empty(), "Should have found words");
 
     // Verify bounding boxes are reasonable
-    for (text, left, top, right, bottom, conf) in &words {
-        assert!(right > left, "Word '{}' has invalid width", text);
-        assert!(bottom > top, "Word '{}' has invalid height", text);
-        assert!(*conf >= 0.0, "Word '{}' has negative confidence", text);
+    for word in &words {
+        let text = &word.text;
+        assert!(
+            word.bounding_box.width() > 0,
+            "Word '{}' has invalid width",
+            text
+        );
+        assert!(
+            word.bounding_box.height() > 0,
+            "Word '{}' has invalid height",
+            text
+        );
+        assert!(
+            word.confidence >= 0.0,
+            "Word '{}' has negative confidence",
+            text
+        );
     }
 }
 
@@ -0,0 +1,62 @@
+#[cfg(feature = "hocr")]
+mod common;
+
+#[cfg(feature = "hocr")]
+mod hocr_tests {
+    use super::common::*;
+    use tesseract_rs::HocrDocument;
+
+    const SAMPLE_TEXT: &str = "This is a sample text for OCR testing.";
+
+    #[test]
+    fn test_parse_tesseract_hocr() {
+        let api = create_api_with_image();
+        let hocr = HocrDocument::parse(&api.get_hocr_text(0).unwrap()).expect("parse failed");
+        assert_eq!(hocr.pages.len(), 1);
+        assert!(
+            hocr.text().contains(SAMPLE_TEXT),
+            "Unexpected text: {}",
+            hocr.text()
+        );
+
+        let document = api.recognize_document().unwrap();
+        let words: Vec<_> = hocr
+            .pages
+            .iter()
+            .flat_map(|page| &page.areas)
+            .flat_map(|area| &area.paragraphs)
+            .flat_map(|paragraph| &paragraph.lines)
+            .flat_map(|line| &line.words)
+            .collect();
+        assert_eq!(words.len(), document.words().count());
+        for (hocr_word, word) in words.iter().zip(document.words()) {
+            assert_eq!(hocr_word.text, word.text);
+            assert_eq!(hocr_word.bbox, word.bounding_box);
+            assert!(hocr_word.confidence.is_some());
+        }
+        let line = &hocr.pages[0].areas[0].paragraphs[0].lines[0];
+        assert!(line.baseline.is_some());
+    }
+
+    #[test]
+    fn test_edit_and_regenerate_hocr() {
+        let api = create_api_with_image();
+        let mut hocr = HocrDocument::parse(&api.get_hocr_text(0).unwrap()).unwrap();
+        let word = hocr
+            .pages
+            .iter_mut()
+            .flat_map(|page| &mut page.areas)
+            .flat_map(|area| &mut area.paragraphs)
+            .flat_map(|paragraph| &mut paragraph.lines)
+            .flat_map(|line| &mut line.words)
+            .find(|word| word.text == "sample")
+            .expect("Word 'sample' not found");
+        word.text = "example".into();
+
+        let written = hocr.to_hocr();
+        assert!(written.starts_with("<?xml"));
+        let reparsed = HocrDocument::parse(&written).unwrap();
+        assert_eq!(reparsed, hocr);
+        assert!(reparsed.text().contains("This is a example text"));
+    }
+}
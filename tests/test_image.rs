@@ -0,0 +1,71 @@
+#[cfg(feature = "image")]
+mod common;
+
+#[cfg(feature = "image")]
+mod image_tests {
+    use super::common::*;
+
+    use image::{DynamicImage, ImageBuffer, Rgba};
+    use std::path::PathBuf;
+
+    const EXPECTED: &str = "This is a sample text for OCR testing.";
+
+    fn open_sample() -> DynamicImage {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("test_images")
+            .join("sample_text.png");
+        image::open(path).expect("Failed to open sample image")
+    }
+
+    #[test]
+    fn test_set_dynamic_image() {
+        let api = create_initialized_api();
+        api.set_dynamic_image(&open_sample())
+            .expect("set_dynamic_image failed");
+        let text = api.get_utf8_text().expect("get_utf8_text failed");
+        assert!(text.contains(EXPECTED), "Unexpected text: {}", text);
+    }
+
+    #[test]
+    fn test_set_dynamic_image_16_bit() {
+        let api = create_initialized_api();
+        let image = DynamicImage::ImageRgb16(open_sample().to_rgb16());
+        api.set_dynamic_image(&image)
+            .expect("set_dynamic_image failed");
+        let text = api.get_utf8_text().expect("get_utf8_text failed");
+        assert!(text.contains(EXPECTED), "Unexpected text: {}", text);
+    }
+
+    #[test]
+    fn test_set_rgba_image_transparent_background() {
+        // Black text on a fully transparent (and black) background only reads
+        // correctly once the alpha channel is taken into account.
+        let gray = open_sample().to_luma8();
+        let rgba = ImageBuffer::from_fn(gray.width(), gray.height(), |x, y| {
+            let alpha = 255 - gray.get_pixel(x, y).0[0];
+            Rgba([0u8, 0, 0, alpha])
+        });
+        let api = create_initialized_api();
+        api.set_rgba_image(&rgba).expect("set_rgba_image failed");
+        let text = api.get_utf8_text().expect("get_utf8_text failed");
+        assert!(text.contains(EXPECTED), "Unexpected text: {}", text);
+    }
+
+    #[test]
+    fn test_set_gray_image() {
+        let api = create_initialized_api();
+        api.set_gray_image(&open_sample().to_luma8())
+            .expect("set_gray_image failed");
+        let text = api.get_utf8_text().expect("get_utf8_text failed");
+        assert!(text.contains(EXPECTED), "Unexpected text: {}", text);
+    }
+
+    #[test]
+    fn test_set_dynamic_image_with_resolution() {
+        let api = create_initialized_api();
+        api.set_dynamic_image_with_resolution(&open_sample(), 300)
+            .expect("set_dynamic_image_with_resolution failed");
+        assert_eq!(api.get_source_y_resolution().unwrap(), 300);
+    }
+}
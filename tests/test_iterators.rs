@@ -1,6 +1,9 @@
 mod common;
 use common::*;
-use tesseract_rs::{TessPageIteratorLevel, TessPolyBlockType};
+use tesseract_rs::{
+    Baseline, BoundingBox, FontAttributes, OrientationInfo, ParagraphInfo, TessPageIteratorLevel,
+    TessPolyBlockType,
+};
 
 // ===========================================================================
 // ResultIterator tests
@@ -173,9 +176,10 @@ fn test_result_iterator_get_bounding_box() {
     let api = create_api_with_image();
     api.recognize().expect("recognize failed");
     let ri = api.get_iterator().expect("get_iterator failed");
-    let (left, top, right, bottom) = ri
+    let bbox = ri
         .get_bounding_box(TessPageIteratorLevel::RIL_WORD)
         .expect("get_bounding_box failed");
+    let (left, top, right, bottom) = bbox.into();
     assert!(
         left < right,
         "left ({}) should be < right ({})",
@@ -197,16 +201,13 @@ fn test_result_iterator_get_bounding_box_block() {
     let api = create_api_with_image();
     api.recognize().expect("recognize failed");
     let ri = api.get_iterator().expect("get_iterator failed");
-    let (left, top, right, bottom) = ri
+    let bbox = ri
         .get_bounding_box(TessPageIteratorLevel::RIL_BLOCK)
         .expect("get_bounding_box at block level failed");
     assert!(
-        right > left && bottom > top,
-        "Block bounding box should have positive area: ({}, {}, {}, {})",
-        left,
-        top,
-        right,
-        bottom
+        bbox.width() > 0 && bbox.height() > 0,
+        "Block bounding box should have positive area: {:?}",
+        bbox
     );
 }
 
@@ -217,7 +218,8 @@ fn test_result_iterator_get_word_with_bounds() {
     let ri = api.get_iterator().expect("get_iterator failed");
     let (text, left, top, right, bottom, confidence) = ri
         .get_word_with_bounds()
-        .expect("get_word_with_bounds failed");
+        .expect("get_word_with_bounds failed")
+        .into();
     assert!(!text.is_empty(), "Word text should not be empty");
     assert!(
         left < right,
@@ -252,25 +254,25 @@ fn test_result_iterator_get_current_word() {
     let api = create_api_with_image();
     api.recognize().expect("recognize failed");
     let ri = api.get_iterator().expect("get_iterator failed");
-    let (text, left, top, right, bottom, confidence) =
-        ri.get_current_word().expect("get_current_word failed");
-    assert!(!text.is_empty(), "Current word text should not be empty");
+    let word = ri.get_current_word().expect("get_current_word failed");
     assert!(
-        left < right,
-        "left ({}) should be < right ({})",
-        left,
-        right
+        !word.text.is_empty(),
+        "Current word text should not be empty"
     );
     assert!(
-        top < bottom,
-        "top ({}) should be < bottom ({})",
-        top,
-        bottom
+        !word.bounding_box.is_empty(),
+        "Current word bounding box should not be empty: {:?}",
+        word.bounding_box
     );
     assert!(
-        confidence >= 0.0 && confidence <= 100.0,
+        (0.0..=100.0).contains(&word.confidence),
         "Confidence should be 0-100, got {}",
-        confidence
+        word.confidence
+    );
+    assert_eq!(
+        word,
+        ri.get_word_with_bounds()
+            .expect("get_word_with_bounds failed")
     );
 }
 
@@ -283,7 +285,7 @@ fn test_result_iterator_word_font_attributes() {
     // word_font_attributes may fail if font info is not available (e.g., LSTM-only mode)
     // so we just verify it returns a result without panicking
     match result {
-        Ok((
+        Ok(FontAttributes {
             is_bold,
             is_italic,
             is_underlined,
@@ -292,7 +294,7 @@ fn test_result_iterator_word_font_attributes() {
             is_smallcaps,
             pointsize,
             font_id,
-        )) => {
+        }) => {
             // Basic sanity checks on returned values
             assert!(
                 pointsize >= 0,
@@ -383,7 +385,12 @@ fn test_page_iterator_bounding_box() {
     let api = create_api_with_image();
     api.recognize().expect("recognize failed");
     let pi = api.analyse_layout().expect("analyse_layout failed");
-    let (left, top, right, bottom) = pi
+    let BoundingBox {
+        left,
+        top,
+        right,
+        bottom,
+    } = pi
         .bounding_box(TessPageIteratorLevel::RIL_WORD)
         .expect("bounding_box failed");
     assert!(
@@ -407,17 +414,19 @@ fn test_page_iterator_bounding_box_block() {
     let api = create_api_with_image();
     api.recognize().expect("recognize failed");
     let pi = api.analyse_layout().expect("analyse_layout failed");
-    let (left, top, right, bottom) = pi
+    let bbox = pi
         .bounding_box(TessPageIteratorLevel::RIL_BLOCK)
         .expect("bounding_box at block level failed");
     assert!(
-        right > left && bottom > top,
-        "Block bounding box should have positive area: ({}, {}, {}, {})",
-        left,
-        top,
-        right,
-        bottom
+        bbox.width() > 0 && bbox.height() > 0,
+        "Block bounding box should have positive area: {:?}",
+        bbox
     );
+    let word_bbox = pi
+        .bounding_box(TessPageIteratorLevel::RIL_WORD)
+        .expect("bounding_box failed");
+    assert_eq!(bbox.intersect(&word_bbox), Some(word_bbox));
+    assert_eq!(bbox.union(&word_bbox), bbox);
 }
 
 #[test]
@@ -442,7 +451,7 @@ fn test_page_iterator_baseline() {
     let api = create_api_with_image();
     api.recognize().expect("recognize failed");
     let pi = api.analyse_layout().expect("analyse_layout failed");
-    let (x1, y1, x2, y2) = pi
+    let Baseline { x1, y1, x2, y2 } = pi
         .baseline(TessPageIteratorLevel::RIL_TEXTLINE as i32)
         .expect("baseline failed");
     // Baseline should span some horizontal distance for a line of text
@@ -459,7 +468,12 @@ fn test_page_iterator_orientation() {
     let pi = api.analyse_layout().expect("analyse_layout failed");
     let result = pi.orientation();
     match result {
-        Ok((orientation, writing_direction, textline_order, deskew_angle)) => {
+        Ok(OrientationInfo {
+            orientation,
+            writing_direction,
+            textline_order,
+            deskew_angle,
+        }) => {
             // For normal left-to-right English text
             assert_eq!(
                 orientation,
@@ -498,7 +512,12 @@ fn test_page_iterator_paragraph_info() {
     let pi = api.analyse_layout().expect("analyse_layout failed");
     let result = pi.paragraph_info();
     match result {
-        Ok((justification, is_list_item, is_crown, first_line_indent)) => {
+        Ok(ParagraphInfo {
+            justification,
+            is_list_item,
+            is_crown,
+            first_line_indent,
+        }) => {
             // Just verify the values are sane
             let _ = justification; // any justification is valid
             let _ = is_list_item;
@@ -566,6 +585,230 @@ fn test_page_iterator_begin_resets() {
     );
 }
 
+#[test]
+fn test_page_iterator_binary_image() {
+    let api = create_api_with_image();
+    api.recognize().expect("recognize failed");
+    let pi = api.analyse_layout().expect("analyse_layout failed");
+    let bbox = pi
+        .bounding_box(TessPageIteratorLevel::RIL_WORD)
+        .expect("bounding_box failed");
+    let crop = pi
+        .binary_image(TessPageIteratorLevel::RIL_WORD)
+        .expect("binary_image failed");
+    assert_eq!(crop.image.depth(), 1);
+    assert_eq!((crop.left, crop.top), (bbox.left, bbox.top));
+    assert_eq!(crop.image.width(), bbox.width());
+    assert_eq!(crop.image.height(), bbox.height());
+}
+
+#[test]
+fn test_page_iterator_image_with_padding() {
+    let api = create_api_with_image();
+    api.recognize().expect("recognize failed");
+    let original = api.get_input_image().expect("get_input_image failed");
+    let pi = api.analyse_layout().expect("analyse_layout failed");
+    let bbox = pi
+        .bounding_box(TessPageIteratorLevel::RIL_TEXTLINE)
+        .expect("bounding_box failed");
+    let crop = pi
+        .image(TessPageIteratorLevel::RIL_TEXTLINE, 4, &original)
+        .expect("image failed");
+    assert_eq!(crop.image.depth(), original.depth());
+    let covered = BoundingBox::new(
+        crop.left,
+        crop.top,
+        crop.left + crop.image.width(),
+        crop.top + crop.image.height(),
+    );
+    assert!(
+        covered.contains(&bbox),
+        "{:?} should contain {:?}",
+        covered,
+        bbox
+    );
+    assert!(covered.width() > bbox.width());
+}
+
+// ===========================================================================
+// ResultIterator seen as a PageIterator
+// ===========================================================================
+
+#[test]
+fn test_result_iterator_as_page_iterator() {
+    let api = create_api_with_image();
+    api.recognize().expect("recognize failed");
+    let ri = api.get_iterator().expect("get_iterator failed");
+    let pi = ri.as_page_iterator();
+
+    assert!(pi
+        .is_at_beginning_of(TessPageIteratorLevel::RIL_TEXTLINE)
+        .expect("is_at_beginning_of failed"));
+    assert_eq!(
+        pi.bounding_box(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        ri.get_bounding_box(TessPageIteratorLevel::RIL_WORD)
+            .unwrap()
+    );
+    let bt = pi.block_type().expect("block_type failed");
+    assert!(matches!(
+        bt,
+        TessPolyBlockType::PT_FLOWING_TEXT
+            | TessPolyBlockType::PT_HEADING_TEXT
+            | TessPolyBlockType::PT_PULLOUT_TEXT
+            | TessPolyBlockType::PT_CAPTION_TEXT
+    ));
+    pi.baseline(TessPageIteratorLevel::RIL_TEXTLINE as i32)
+        .expect("baseline failed");
+}
+
+#[test]
+fn test_result_iterator_page_view_shares_position() {
+    let api = create_api_with_image();
+    api.recognize().expect("recognize failed");
+    let ri = api.get_iterator().expect("get_iterator failed");
+
+    // Moving the result iterator moves the view, and the other way round.
+    assert!(ri.next(TessPageIteratorLevel::RIL_WORD).unwrap());
+    assert_eq!(
+        ri.get_utf8_text(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        "is"
+    );
+    assert!(!ri
+        .as_page_iterator()
+        .is_at_beginning_of(TessPageIteratorLevel::RIL_TEXTLINE)
+        .unwrap());
+    assert!(ri
+        .as_page_iterator()
+        .next(TessPageIteratorLevel::RIL_WORD)
+        .unwrap());
+    assert_eq!(
+        ri.get_utf8_text(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        "a"
+    );
+
+    // Rewinding through the view rewinds the result iterator.
+    while ri.next(TessPageIteratorLevel::RIL_WORD).unwrap() {}
+    ri.as_page_iterator().begin().unwrap();
+    assert_eq!(
+        ri.get_utf8_text(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        "This"
+    );
+}
+
+// ===========================================================================
+// Copies and saved positions
+// ===========================================================================
+
+#[test]
+fn test_result_iterator_try_clone_looks_ahead() {
+    let api = create_api_with_image();
+    api.recognize().expect("recognize failed");
+    let ri = api.get_iterator().expect("get_iterator failed");
+
+    let ahead = ri.try_clone().expect("try_clone failed");
+    assert!(ahead.next(TessPageIteratorLevel::RIL_WORD).unwrap());
+    assert_eq!(
+        ahead
+            .get_utf8_text(TessPageIteratorLevel::RIL_WORD)
+            .unwrap(),
+        "is"
+    );
+    assert!(!ahead
+        .as_page_iterator()
+        .is_at_beginning_of(TessPageIteratorLevel::RIL_TEXTLINE)
+        .unwrap());
+    // The original did not move.
+    assert_eq!(
+        ri.get_utf8_text(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        "This"
+    );
+
+    drop(ri);
+    let copy = ahead.clone();
+    assert_eq!(
+        copy.get_utf8_text(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        "is"
+    );
+}
+
+#[test]
+fn test_result_iterator_restore_position() {
+    let api = create_api_with_image();
+    api.recognize().expect("recognize failed");
+    let ri = api.get_iterator().expect("get_iterator failed");
+
+    assert!(ri.next(TessPageIteratorLevel::RIL_WORD).unwrap());
+    let saved = ri.save_position().expect("save_position failed");
+    let view = ri.as_page_iterator();
+    while view.next(TessPageIteratorLevel::RIL_WORD).unwrap() {}
+
+    ri.restore_position(&saved)
+        .expect("restore_position failed");
+    assert_eq!(
+        ri.get_utf8_text(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        "is"
+    );
+    // The view follows the restored position, which can be restored again.
+    assert!(view.next(TessPageIteratorLevel::RIL_WORD).unwrap());
+    ri.restore_position(&saved)
+        .expect("restore_position failed");
+    assert_eq!(
+        ri.get_utf8_text(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        "is"
+    );
+}
+
+#[test]
+fn test_page_iterator_save_and_restore_position() {
+    let api = create_api_with_image();
+    api.recognize().expect("recognize failed");
+    let pi = api.analyse_layout().expect("analyse_layout failed");
+
+    let first = pi.bounding_box(TessPageIteratorLevel::RIL_WORD).unwrap();
+    let saved = pi.save_position().expect("save_position failed");
+    assert!(pi.next(TessPageIteratorLevel::RIL_WORD).unwrap());
+    let copy = pi.clone();
+    assert_ne!(
+        pi.bounding_box(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        first
+    );
+
+    pi.restore_position(&saved)
+        .expect("restore_position failed");
+    assert_eq!(
+        pi.bounding_box(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        first
+    );
+    assert_ne!(
+        copy.bounding_box(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        first
+    );
+}
+
+#[test]
+fn test_page_iterator_view_cannot_restore() {
+    let api = create_api_with_image();
+    api.recognize().expect("recognize failed");
+    let ri = api.get_iterator().expect("get_iterator failed");
+    let view = ri.as_page_iterator();
+
+    // A copy of the view is an independent page iterator.
+    let saved = view.save_position().expect("save_position failed");
+    let copy = view.try_clone().expect("try_clone failed");
+    assert!(copy.next(TessPageIteratorLevel::RIL_WORD).unwrap());
+    assert_eq!(
+        ri.get_utf8_text(TessPageIteratorLevel::RIL_WORD).unwrap(),
+        "This"
+    );
+    copy.restore_position(&saved)
+        .expect("restore_position failed");
+
+    assert!(matches!(
+        view.restore_position(&saved),
+        Err(tesseract_rs::TesseractError::InvalidParameterError)
+    ));
+}
+
 // ===========================================================================
 // Iteration pattern: collect all words from sample text
 // ===========================================================================
@@ -579,17 +822,17 @@ fn test_iterator_collect_all_words() {
     let mut words: Vec<String> = Vec::new();
 
     // Get the first word
-    let (text, _left, _top, _right, _bottom, _confidence) = ri
+    let word = ri
         .get_current_word()
         .expect("get_current_word failed for first word");
-    words.push(text.trim().to_string());
+    words.push(word.text.trim().to_string());
 
     // Iterate through remaining words
     while ri.next_word().expect("next_word failed") {
-        let (text, _left, _top, _right, _bottom, _confidence) = ri
+        let word = ri
             .get_current_word()
             .expect("get_current_word failed during iteration");
-        words.push(text.trim().to_string());
+        words.push(word.text.trim().to_string());
         // Safety: prevent infinite loop
         if words.len() > 100 {
             panic!("Too many words collected, likely an infinite loop");
@@ -625,9 +868,11 @@ fn test_iterator_word_bounding_boxes_are_ordered() {
     let mut first = true;
 
     loop {
-        let (_, left, _top, _right, _bottom, _) = ri
+        let left = ri
             .get_word_with_bounds()
-            .expect("get_word_with_bounds failed");
+            .expect("get_word_with_bounds failed")
+            .bounding_box
+            .left;
 
         if let Some(pl) = prev_left {
             // Words on the same line should generally progress left to right
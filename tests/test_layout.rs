@@ -0,0 +1,105 @@
+mod common;
+use common::*;
+use tesseract_rs::{LayoutComponent, TessPageIteratorLevel, TesseractAPI, TesseractError};
+
+fn assert_crops_match_boxes(components: &[LayoutComponent]) {
+    for component in components {
+        assert_eq!(component.image.width(), component.bounding_box.width());
+        assert_eq!(component.image.height(), component.bounding_box.height());
+    }
+}
+
+#[test]
+fn test_get_regions() {
+    let api = create_api_with_image();
+    let regions = api.get_regions().expect("get_regions failed");
+    assert!(!regions.is_empty());
+    assert_crops_match_boxes(&regions);
+    assert!(regions
+        .iter()
+        .all(|region| region.block_id.is_none() && region.paragraph_id.is_none()));
+}
+
+#[test]
+fn test_get_textlines() {
+    let api = create_api_with_image();
+    let lines = api.get_textlines().expect("get_textlines failed");
+    assert_eq!(lines.len(), 1);
+    assert_crops_match_boxes(&lines);
+    assert_eq!(lines[0].block_id, Some(0));
+    assert_eq!(lines[0].paragraph_id, None);
+
+    let lines = api
+        .get_textlines_1(true, 4)
+        .expect("get_textlines_1 failed");
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].block_id, Some(0));
+    assert_eq!(lines[0].paragraph_id, Some(0));
+    // Crops from the original image keep its depth instead of being binarized.
+    assert!(lines[0].image.depth() > 1);
+}
+
+#[test]
+fn test_get_strips() {
+    let api = create_api_with_image();
+    let strips = api.get_strips().expect("get_strips failed");
+    assert!(!strips.is_empty());
+    assert!(strips.iter().all(|strip| strip.block_id.is_some()));
+}
+
+#[test]
+fn test_get_words() {
+    let api = create_api_with_image();
+    let words = api.get_words().expect("get_words failed");
+    assert_eq!(words.len(), 8);
+    assert_crops_match_boxes(&words);
+    assert!(words
+        .windows(2)
+        .all(|pair| pair[0].bounding_box.left < pair[1].bounding_box.left));
+}
+
+#[test]
+fn test_get_connected_components() {
+    let api = create_api_with_image();
+    let components = api
+        .get_connected_components()
+        .expect("get_connected_components failed");
+    // Every letter is at least one component.
+    assert!(components.len() > api.get_words().unwrap().len());
+    assert_crops_match_boxes(&components);
+}
+
+#[test]
+fn test_get_component_images() {
+    let api = create_api_with_image();
+    let words = api
+        .get_component_images(TessPageIteratorLevel::RIL_WORD, true)
+        .expect("get_component_images failed");
+    let boxes: Vec<_> = words.iter().map(|word| word.bounding_box).collect();
+    let expected: Vec<_> = api
+        .get_words()
+        .unwrap()
+        .iter()
+        .map(|word| word.bounding_box)
+        .collect();
+    assert_eq!(boxes, expected);
+    assert!(words.iter().all(|word| word.block_id == Some(0)));
+
+    let words = api
+        .get_component_images_1(TessPageIteratorLevel::RIL_WORD, true, false, 0)
+        .expect("get_component_images_1 failed");
+    assert_eq!(words.len(), 8);
+    assert_crops_match_boxes(&words);
+    assert!(words.iter().all(|word| word.paragraph_id == Some(0)));
+}
+
+#[test]
+fn test_layout_without_image() {
+    let api = create_initialized_api();
+    assert!(matches!(
+        api.get_regions(),
+        Err(TesseractError::AnalyseLayoutError)
+    ));
+    assert!(api.get_textlines().is_err());
+    assert!(TesseractAPI::new().get_words().is_err());
+}
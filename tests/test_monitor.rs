@@ -1,4 +1,10 @@
-use tesseract_rs::TessMonitor;
+mod common;
+use common::*;
+
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tesseract_rs::{CancellationToken, TessMonitor, TesseractError};
 
 #[test]
 fn test_monitor_create() {
@@ -27,3 +33,104 @@ fn test_monitor_set_deadline_and_check() {
     let progress = monitor.get_progress().unwrap();
     assert!(progress >= 0);
 }
+
+#[test]
+fn test_recognize_with_monitor() {
+    let api = create_api_with_image();
+    let monitor = TessMonitor::new();
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reports);
+    monitor
+        .set_progress_callback(move |progress| sink.lock().unwrap().push(progress))
+        .unwrap();
+
+    api.recognize_with_monitor(&monitor)
+        .expect("recognize_with_monitor failed");
+    let text = api.get_utf8_text().unwrap();
+    assert!(
+        text.contains("This is a sample text for OCR testing."),
+        "Unexpected text: {}",
+        text
+    );
+
+    let reports = reports.lock().unwrap();
+    assert!(!reports.is_empty(), "Progress callback was never called");
+    assert!(reports.iter().all(|progress| (0..=100).contains(progress)));
+    assert!(reports.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_recognize_with_cancelled_token() {
+    let api = create_api_with_image();
+    let monitor = TessMonitor::new();
+    let token = CancellationToken::new();
+    monitor.set_cancellation_token(token.clone()).unwrap();
+    token.cancel();
+
+    let result = api.recognize_with_monitor(&monitor);
+    assert!(
+        matches!(result, Err(TesseractError::Cancelled)),
+        "Expected Cancelled, got {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_cancel_from_another_thread() {
+    let api = create_api_with_image();
+    let monitor = TessMonitor::new();
+    let token = CancellationToken::new();
+    monitor.set_cancellation_token(token.clone()).unwrap();
+
+    // Hold the recognition at its first word until the other thread cancels
+    let (started_tx, started_rx) = mpsc::channel();
+    let waiting_token = token.clone();
+    monitor
+        .set_progress_callback(move |_| {
+            let _ = started_tx.send(());
+            let deadline = Instant::now() + Duration::from_secs(10);
+            while !waiting_token.is_cancelled() && Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(1));
+            }
+        })
+        .unwrap();
+
+    let canceller = thread::spawn(move || {
+        started_rx
+            .recv_timeout(Duration::from_secs(30))
+            .expect("Recognition never started");
+        token.cancel();
+    });
+    let result = api.recognize_with_monitor(&monitor);
+    canceller.join().unwrap();
+    assert!(
+        matches!(result, Err(TesseractError::Cancelled)),
+        "Expected Cancelled, got {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_cancel_callback() {
+    let api = create_api_with_image();
+    let monitor = TessMonitor::new();
+    monitor.set_cancel_callback(|_words| true).unwrap();
+    assert!(matches!(
+        api.recognize_with_monitor(&monitor),
+        Err(TesseractError::Cancelled)
+    ));
+}
+
+#[test]
+fn test_recognize_with_expired_deadline() {
+    let api = create_api_with_image();
+    let monitor = TessMonitor::new();
+    monitor.set_deadline(1).unwrap();
+    thread::sleep(Duration::from_millis(5));
+    let result = api.recognize_with_monitor(&monitor);
+    assert!(
+        matches!(result, Err(TesseractError::DeadlineExceeded)),
+        "Expected DeadlineExceeded, got {:?}",
+        result
+    );
+}
@@ -0,0 +1,149 @@
+mod common;
+use common::*;
+
+use tesseract_rs::{ParameterType, ParameterValue, TesseractAPI, TesseractError};
+
+#[test]
+fn test_parameters_lists_all_types() {
+    let api = create_initialized_api();
+    let parameters = api.parameters().expect("parameters failed");
+    assert!(
+        parameters.len() > 100,
+        "Expected hundreds of parameters, got {}",
+        parameters.len()
+    );
+
+    let find = |name: &str| {
+        parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+            .unwrap_or_else(|| panic!("{} not listed", name))
+    };
+    assert!(matches!(
+        find("tessedit_pageseg_mode").value,
+        ParameterValue::Int(_)
+    ));
+    assert!(matches!(
+        find("tessedit_create_hocr").value,
+        ParameterValue::Bool(false)
+    ));
+    assert!(matches!(
+        find("textord_noise_sizelimit").value,
+        ParameterValue::Double(_)
+    ));
+    assert!(matches!(
+        find("tessedit_char_whitelist").value,
+        ParameterValue::String(_)
+    ));
+    assert!(!find("tessedit_pageseg_mode").description.is_empty());
+}
+
+#[test]
+fn test_parameters_reflect_current_values() {
+    let api = create_initialized_api();
+    api.set_variable("tessedit_char_blacklist", "xyz").unwrap();
+    let parameters = api.parameters().unwrap();
+    let blacklist = parameters
+        .iter()
+        .find(|parameter| parameter.name == "tessedit_char_blacklist")
+        .unwrap();
+    assert_eq!(blacklist.value, ParameterValue::String("xyz".into()));
+}
+
+#[test]
+fn test_parameters_uninitialized() {
+    let api = TesseractAPI::new();
+    assert!(matches!(
+        api.parameters(),
+        Err(TesseractError::UninitializedError)
+    ));
+}
+
+#[test]
+fn test_parameter_type() {
+    let api = create_initialized_api();
+    assert_eq!(
+        api.parameter_type("tessedit_pageseg_mode").unwrap(),
+        ParameterType::Int
+    );
+    assert_eq!(
+        api.parameter_type("tessedit_create_hocr").unwrap(),
+        ParameterType::Bool
+    );
+    assert_eq!(
+        api.parameter_type("textord_noise_sizelimit").unwrap(),
+        ParameterType::Double
+    );
+    assert_eq!(
+        api.parameter_type("tessedit_char_whitelist").unwrap(),
+        ParameterType::String
+    );
+}
+
+#[test]
+fn test_typed_param_roundtrip() {
+    let api = create_initialized_api();
+
+    api.set_param::<i32>("tessedit_pageseg_mode", 6).unwrap();
+    assert_eq!(api.get_param::<i32>("tessedit_pageseg_mode").unwrap(), 6);
+
+    api.set_param::<bool>("tessedit_create_hocr", true).unwrap();
+    assert!(api.get_param::<bool>("tessedit_create_hocr").unwrap());
+
+    api.set_param::<f64>("textord_noise_sizelimit", 0.25)
+        .unwrap();
+    let value = api.get_param::<f64>("textord_noise_sizelimit").unwrap();
+    assert!((value - 0.25).abs() < 1e-9);
+
+    api.set_param::<String>("tessedit_char_whitelist", "0123456789")
+        .unwrap();
+    assert_eq!(
+        api.get_param::<String>("tessedit_char_whitelist").unwrap(),
+        "0123456789"
+    );
+}
+
+#[test]
+fn test_typed_param_unknown_name() {
+    let api = create_initialized_api();
+    match api.set_param::<i32>("not_a_real_parameter", 1) {
+        Err(TesseractError::UnknownParameter(name)) => {
+            assert_eq!(name, "not_a_real_parameter")
+        }
+        other => panic!("Expected UnknownParameter, got {:?}", other),
+    }
+    assert!(matches!(
+        api.get_param::<bool>("not_a_real_parameter"),
+        Err(TesseractError::UnknownParameter(_))
+    ));
+}
+
+#[test]
+fn test_typed_param_type_mismatch() {
+    let api = create_initialized_api();
+    match api.set_param::<bool>("tessedit_pageseg_mode", true) {
+        Err(TesseractError::ParameterTypeMismatch {
+            name,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(name, "tessedit_pageseg_mode");
+            assert_eq!(expected, ParameterType::Bool);
+            assert_eq!(actual, ParameterType::Int);
+        }
+        other => panic!("Expected ParameterTypeMismatch, got {:?}", other),
+    }
+    assert!(matches!(
+        api.get_param::<String>("tessedit_create_hocr"),
+        Err(TesseractError::ParameterTypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_typed_param_init_only() {
+    let api = create_initialized_api();
+    match api.set_param::<bool>("load_system_dawg", false) {
+        Err(TesseractError::ReadOnlyParameter(name)) => assert_eq!(name, "load_system_dawg"),
+        other => panic!("Expected ReadOnlyParameter, got {:?}", other),
+    }
+}
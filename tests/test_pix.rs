@@ -0,0 +1,167 @@
+mod common;
+use common::*;
+use tesseract_rs::{Pix, TesseractError};
+
+#[test]
+fn test_pix_create() {
+    let pix = Pix::new(40, 20, 8).expect("Pix::new failed");
+    assert_eq!(pix.width(), 40);
+    assert_eq!(pix.height(), 20);
+    assert_eq!(pix.depth(), 8);
+    assert_eq!(pix.samples_per_pixel(), 1);
+}
+
+#[test]
+fn test_pix_create_invalid_dimensions() {
+    assert!(matches!(
+        Pix::new(0, 20, 8),
+        Err(TesseractError::InvalidDimensions)
+    ));
+    assert!(matches!(
+        Pix::new(20, -1, 8),
+        Err(TesseractError::InvalidDimensions)
+    ));
+}
+
+#[test]
+fn test_pix_create_invalid_depth() {
+    assert!(matches!(
+        Pix::new(10, 10, 3),
+        Err(TesseractError::InvalidParameterError)
+    ));
+}
+
+#[test]
+fn test_pix_from_raw_null() {
+    let result = unsafe { Pix::from_raw(std::ptr::null_mut()) };
+    assert!(matches!(result, Err(TesseractError::NullPointerError)));
+}
+
+#[test]
+fn test_pix_set_and_get_pixel() {
+    let mut pix = Pix::new(10, 10, 8).unwrap();
+    assert_eq!(pix.get_pixel(3, 4).unwrap(), 0);
+    pix.set_pixel(3, 4, 200).unwrap();
+    assert_eq!(pix.get_pixel(3, 4).unwrap(), 200);
+}
+
+#[test]
+fn test_pix_pixel_out_of_bounds() {
+    let mut pix = Pix::new(10, 10, 8).unwrap();
+    assert!(pix.get_pixel(10, 0).is_err());
+    assert!(pix.get_pixel(0, -1).is_err());
+    assert!(pix.set_pixel(-1, 0, 1).is_err());
+}
+
+#[test]
+fn test_pix_clone_is_independent() {
+    let mut pix = Pix::new(10, 10, 8).unwrap();
+    pix.set_pixel(1, 1, 42).unwrap();
+    let clone = pix.clone();
+    assert_ne!(pix.as_ptr(), clone.as_ptr());
+    assert_eq!(clone.get_pixel(1, 1).unwrap(), 42);
+    pix.set_pixel(1, 1, 7).unwrap();
+    assert_eq!(clone.get_pixel(1, 1).unwrap(), 42);
+    drop(pix);
+    assert_eq!(clone.width(), 10);
+}
+
+#[test]
+fn test_pix_shared_with_engine_is_copied_on_write() {
+    let api = create_initialized_api();
+    let mut pix = Pix::new(10, 10, 8).unwrap();
+    api.set_input_image(&pix).unwrap();
+    let mut input = api.get_input_image().unwrap();
+
+    // Both writes go to private copies, not to the engine's image.
+    pix.set_pixel(1, 1, 42).unwrap();
+    input.set_pixel(2, 2, 42).unwrap();
+    input.set_resolution(300, 300).unwrap();
+    let engine_image = api.get_input_image().unwrap();
+    assert_eq!(engine_image.get_pixel(1, 1).unwrap(), 0);
+    assert_eq!(engine_image.get_pixel(2, 2).unwrap(), 0);
+    assert_ne!(engine_image.resolution(), (300, 300));
+    assert_eq!(input.get_pixel(2, 2).unwrap(), 42);
+}
+
+#[test]
+fn test_pix_deep_copy_is_independent() {
+    let mut pix = Pix::new(10, 10, 8).unwrap();
+    let copy = pix.deep_copy().unwrap();
+    assert_ne!(pix.as_ptr(), copy.as_ptr());
+    pix.set_pixel(1, 1, 42).unwrap();
+    assert_eq!(copy.get_pixel(1, 1).unwrap(), 0);
+}
+
+#[test]
+fn test_pix_resolution() {
+    let mut pix = Pix::new(10, 10, 1).unwrap();
+    pix.set_resolution(300, 200).unwrap();
+    assert_eq!(pix.resolution(), (300, 200));
+}
+
+#[test]
+fn test_pix_data_length() {
+    let pix = Pix::new(33, 5, 8).unwrap();
+    // 33 bytes per row round up to 9 words
+    assert_eq!(pix.words_per_line(), 9);
+    assert_eq!(pix.data().len(), 9 * 5);
+}
+
+#[test]
+fn test_pix_into_raw_roundtrip() {
+    let pix = Pix::new(10, 10, 8).unwrap();
+    let raw = pix.into_raw();
+    let pix = unsafe { Pix::from_raw(raw) }.unwrap();
+    assert_eq!(pix.width(), 10);
+}
+
+#[test]
+fn test_thresholded_image_outlives_api() {
+    let pix = {
+        let api = create_api_with_image();
+        api.recognize().expect("recognize failed");
+        api.get_thresholded_image()
+            .expect("get_thresholded_image failed")
+    };
+    assert!(pix.width() > 0);
+    assert!(!pix.data().is_empty());
+}
+
+#[test]
+fn test_pix_is_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Pix>();
+}
+
+#[test]
+fn test_pix_read_from_memory_pnm() {
+    // PNM decoding is built into Leptonica, so this works without codecs
+    let data = b"P5\n3 2\n255\n\x00\x80\xff\x10\x20\x30";
+    let pix = Pix::read_from_memory(data).expect("read_from_memory failed");
+    assert_eq!(pix.width(), 3);
+    assert_eq!(pix.height(), 2);
+    assert_eq!(pix.depth(), 8);
+    assert_eq!(pix.get_pixel(1, 0).unwrap(), 0x80);
+    assert_eq!(pix.get_pixel(2, 1).unwrap(), 0x30);
+}
+
+#[test]
+fn test_pix_read_from_memory_invalid() {
+    assert!(matches!(
+        Pix::read_from_memory(&[]),
+        Err(TesseractError::InvalidImageData)
+    ));
+    assert!(matches!(
+        Pix::read_from_memory(b"not an image"),
+        Err(TesseractError::InvalidImageData)
+    ));
+}
+
+#[test]
+fn test_pix_read_from_file_missing() {
+    assert!(matches!(
+        Pix::read_from_file("/nonexistent/image.png"),
+        Err(TesseractError::IoError { .. })
+    ));
+}
@@ -0,0 +1,180 @@
+mod common;
+use common::*;
+
+use std::time::Duration;
+use tesseract_rs::{
+    TessPageSegMode, TesseractAPI, TesseractBuilder, TesseractError, TesseractPool,
+};
+
+const SAMPLE_TEXT: &str = "This is a sample text for OCR testing.";
+
+fn builder() -> TesseractBuilder {
+    TesseractAPI::builder()
+        .datapath(get_tessdata_dir())
+        .language("eng")
+}
+
+fn recognize_sample(api: &TesseractAPI) -> String {
+    let (image_data, width, height) =
+        load_test_image("sample_text.png").expect("Failed to load test image");
+    api.set_image(
+        &image_data,
+        width as i32,
+        height as i32,
+        3,
+        3 * width as i32,
+    )
+    .expect("Failed to set image");
+    api.get_utf8_text().expect("get_utf8_text failed")
+}
+
+#[test]
+fn test_pool_hands_out_initialized_engines() {
+    let pool = TesseractPool::new(builder(), 2).expect("Failed to create pool");
+    let stats = pool.stats().unwrap();
+    assert_eq!(stats.size, 2);
+    assert_eq!(stats.idle, 2);
+    assert_eq!(stats.created, 2);
+
+    let api = pool.get().expect("get failed");
+    assert!(recognize_sample(&api).contains(SAMPLE_TEXT));
+    assert_eq!(pool.stats().unwrap().in_use, 1);
+    drop(api);
+
+    let stats = pool.stats().unwrap();
+    assert_eq!(stats.idle, 2);
+    assert_eq!(stats.in_use, 0);
+    assert_eq!(stats.checkouts, 1);
+}
+
+#[test]
+fn test_pool_timeout_when_exhausted() {
+    let pool = TesseractPool::new(builder(), 1).unwrap();
+    let first = pool.get().unwrap();
+    assert!(matches!(
+        pool.try_get_timeout(Duration::from_millis(50)),
+        Err(TesseractError::PoolTimeout)
+    ));
+    assert_eq!(pool.stats().unwrap().timeouts, 1);
+
+    drop(first);
+    assert!(pool.try_get_timeout(Duration::from_millis(50)).is_ok());
+}
+
+#[test]
+fn test_pool_clears_returned_engines() {
+    let pool = TesseractPool::new(builder(), 1).unwrap();
+    {
+        let api = pool.get().unwrap();
+        recognize_sample(&api);
+    }
+    let api = pool.get().unwrap();
+    assert!(
+        api.get_utf8_text().is_err(),
+        "The previous image should have been cleared"
+    );
+}
+
+#[test]
+fn test_pool_restores_page_seg_mode() {
+    let pool = TesseractPool::new(builder().psm(TessPageSegMode::PSM_AUTO), 1).unwrap();
+    {
+        let api = pool.get().unwrap();
+        api.set_page_seg_mode(TessPageSegMode::PSM_SINGLE_LINE)
+            .unwrap();
+        api.set_capture_diagnostics(true).unwrap();
+    }
+    let api = pool.get().unwrap();
+    assert_eq!(api.get_page_seg_mode().unwrap(), TessPageSegMode::PSM_AUTO);
+    assert!(api.last_diagnostics().unwrap().is_empty());
+    assert_eq!(pool.stats().unwrap().discarded, 0);
+}
+
+#[test]
+fn test_pool_discards_reconfigured_engines() {
+    let pool = TesseractPool::new(builder(), 1).unwrap();
+    pool.get()
+        .unwrap()
+        .set_variable("tessedit_char_whitelist", "0123456789")
+        .unwrap();
+    assert_eq!(pool.stats().unwrap().discarded, 1);
+
+    let api = pool.get().unwrap();
+    assert_eq!(
+        api.get_string_variable("tessedit_char_whitelist").unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn test_pool_discards_engines_after_failed_operations() {
+    let pool = TesseractPool::new(builder(), 1).unwrap();
+    {
+        let api = pool.get().unwrap();
+        assert!(api.recognize().is_err(), "No image was set");
+    }
+    let stats = pool.stats().unwrap();
+    assert_eq!(stats.discarded, 1);
+    assert_eq!(stats.idle, 0);
+}
+
+#[test]
+fn test_pool_replaces_discarded_engines() {
+    let pool = TesseractPool::new(builder(), 1).unwrap();
+    pool.get().unwrap().discard();
+
+    let stats = pool.stats().unwrap();
+    assert_eq!(stats.discarded, 1);
+    assert_eq!(stats.idle, 0);
+
+    let api = pool.get().expect("Failed to create replacement");
+    assert!(recognize_sample(&api).contains(SAMPLE_TEXT));
+    assert_eq!(pool.stats().unwrap().created, 2);
+}
+
+#[test]
+fn test_pool_discards_engine_of_panicking_thread() {
+    let pool = TesseractPool::new(builder(), 1).unwrap();
+    let worker = pool.clone();
+    let result = std::thread::spawn(move || {
+        let _api = worker.get().unwrap();
+        panic!("worker failed");
+    })
+    .join();
+    assert!(result.is_err());
+    assert_eq!(pool.stats().unwrap().discarded, 1);
+}
+
+#[test]
+fn test_pool_shared_between_threads() {
+    let pool = TesseractPool::new(builder(), 2).unwrap();
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let pool = pool.clone();
+            std::thread::spawn(move || {
+                let api = pool.get().unwrap();
+                recognize_sample(&api)
+            })
+        })
+        .collect();
+    for worker in workers {
+        let text = worker.join().unwrap();
+        assert!(text.contains(SAMPLE_TEXT), "Unexpected text: {}", text);
+    }
+    let stats = pool.stats().unwrap();
+    assert_eq!(stats.checkouts, 4);
+    assert_eq!(stats.created, 2);
+}
+
+#[test]
+fn test_pool_invalid_configuration() {
+    assert!(matches!(
+        TesseractPool::new(builder(), 0),
+        Err(TesseractError::InvalidParameterError)
+    ));
+    let result = TesseractPool::new(builder().language("zzz_nonexistent"), 2);
+    assert!(matches!(
+        result,
+        Err(TesseractError::MissingTraineddata { .. })
+    ));
+}
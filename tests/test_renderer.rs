@@ -1,6 +1,7 @@
 mod common;
 use common::*;
-use tesseract_rs::TessResultRenderer;
+use std::path::PathBuf;
+use tesseract_rs::{MemoryRenderer, Pix, TessResultRenderer, TesseractError};
 
 #[test]
 fn test_text_renderer_create() {
@@ -86,3 +87,381 @@ fn test_renderer_get_image_num_after_add() {
     renderer.end_document().unwrap();
     let _ = std::fs::remove_file(format!("{}.txt", tmp.display()));
 }
+
+/// Renders `sample_text.png` with `create` and returns the written file.
+fn render_to_string(
+    name: &str,
+    extension: &str,
+    create: impl FnOnce(&str) -> tesseract_rs::Result<TessResultRenderer>,
+) -> String {
+    let base = std::env::temp_dir().join(format!("tesseract_test_renderer_{}", name));
+    let renderer = create(base.to_str().unwrap()).expect("renderer creation failed");
+    assert_eq!(renderer.get_extension().unwrap(), extension);
+
+    assert!(renderer.begin_document("Sample").unwrap());
+    let api = create_api_with_image();
+    assert!(renderer.add_image(&api).unwrap());
+    assert!(renderer.end_document().unwrap());
+    // The output file is flushed when the renderer is deleted.
+    drop(renderer);
+
+    let path = format!("{}.{}", base.display(), extension);
+    let output = std::fs::read_to_string(&path).expect("rendered file missing");
+    let _ = std::fs::remove_file(&path);
+    output
+}
+
+#[test]
+fn test_hocr_renderer_with_font_info() {
+    let hocr = render_to_string("hocr_font", "hocr", |base| {
+        TessResultRenderer::new_hocr_renderer_with_font_info(base, true)
+    });
+    assert!(hocr.contains("class='ocr_page'"), "{}", hocr);
+    assert!(hocr.contains(">sample</span>"), "{}", hocr);
+    // Font information is announced as a capability and given per word.
+    assert!(hocr.contains("ocrp_font"), "{}", hocr);
+    assert!(hocr.contains("x_fsize"), "{}", hocr);
+
+    let plain = render_to_string("hocr_no_font", "hocr", |base| {
+        TessResultRenderer::new_hocr_renderer_with_font_info(base, false)
+    });
+    assert!(!plain.contains("ocrp_font"), "{}", plain);
+    assert!(!plain.contains("x_fsize"), "{}", plain);
+}
+
+#[test]
+fn test_renderer_outputbase_with_nul() {
+    assert!(matches!(
+        TessResultRenderer::new_alto_renderer("out\0put"),
+        Err(TesseractError::InvalidParameterError)
+    ));
+    assert!(matches!(
+        TessResultRenderer::new_hocr_renderer_with_font_info("out\0put", true),
+        Err(TesseractError::InvalidParameterError)
+    ));
+    assert!(matches!(
+        TessResultRenderer::new_text_renderer("out\0put"),
+        Err(TesseractError::InvalidParameterError)
+    ));
+    assert!(matches!(
+        TessResultRenderer::new_pdf_renderer("out\0put", "tessdata", false),
+        Err(TesseractError::InvalidParameterError)
+    ));
+}
+
+#[test]
+fn test_begin_document_title_with_nul() {
+    let tmp = std::env::temp_dir().join("tesseract_test_renderer_nul_title");
+    let renderer = TessResultRenderer::new_text_renderer(tmp.to_str().unwrap()).unwrap();
+    assert!(matches!(
+        renderer.begin_document("My\0Title"),
+        Err(TesseractError::InvalidParameterError)
+    ));
+}
+
+#[test]
+fn test_alto_renderer() {
+    let alto = render_to_string("alto", "xml", TessResultRenderer::new_alto_renderer);
+    assert!(alto.contains("<alto"), "{}", alto);
+    assert!(alto.contains("CONTENT=\"sample\""), "{}", alto);
+    assert!(alto.trim_end().ends_with("</alto>"));
+}
+
+#[test]
+fn test_page_renderer() {
+    let page = render_to_string("page", "page.xml", TessResultRenderer::new_page_renderer);
+    assert!(page.contains("<PcGts"), "{}", page);
+    assert!(page.contains("sample"), "{}", page);
+}
+
+#[test]
+fn test_tsv_renderer() {
+    let tsv = render_to_string("tsv", "tsv", TessResultRenderer::new_tsv_renderer);
+    let mut lines = tsv.lines();
+    assert!(lines.next().unwrap().starts_with("level\tpage_num\t"));
+    assert!(lines.any(|line| line.ends_with("\tsample")), "{}", tsv);
+}
+
+#[test]
+fn test_unlv_renderer() {
+    let unlv = render_to_string("unlv", "unlv", TessResultRenderer::new_unlv_renderer);
+    assert!(unlv.contains("sample"), "{}", unlv);
+}
+
+#[test]
+fn test_wordstr_box_renderer() {
+    let boxes = render_to_string(
+        "wordstr",
+        "box",
+        TessResultRenderer::new_wordstr_box_renderer,
+    );
+    assert!(
+        boxes
+            .lines()
+            .any(|line| line.starts_with("WordStr ") && line.contains("sample")),
+        "{}",
+        boxes
+    );
+}
+
+#[test]
+fn test_lstm_box_renderer() {
+    let boxes = render_to_string("lstmbox", "box", TessResultRenderer::new_lstm_box_renderer);
+    // One line per character: the symbol, its box and the page number.
+    let first = boxes.lines().next().expect("empty box file");
+    assert!(first.starts_with("T "), "{}", boxes);
+    assert_eq!(first.split(' ').count(), 6, "{}", first);
+}
+
+#[test]
+fn test_renderer_chain() {
+    let base = std::env::temp_dir().join("tesseract_test_renderer_chain");
+    let base = base.to_str().unwrap();
+    let renderer = TessResultRenderer::new_text_renderer(base).unwrap();
+    assert!(renderer.next().unwrap().is_none());
+    renderer
+        .insert(TessResultRenderer::new_hocr_renderer(base).unwrap())
+        .unwrap();
+    renderer
+        .insert(TessResultRenderer::new_tsv_renderer(base).unwrap())
+        .unwrap();
+
+    let mut extensions = vec![renderer.get_extension().unwrap()];
+    let mut next = renderer.next().unwrap();
+    while let Some(chained) = next {
+        extensions.push(chained.get_extension().unwrap());
+        next = chained.next().unwrap();
+    }
+    assert_eq!(extensions, ["txt", "tsv", "hocr"]);
+
+    assert!(renderer.begin_document("Chain").unwrap());
+    let api = create_api_with_image();
+    assert!(renderer.add_image(&api).unwrap());
+    assert!(renderer.end_document().unwrap());
+    let tsv = renderer.next().unwrap().unwrap();
+    assert_eq!(tsv.get_title().unwrap(), "Chain");
+    assert_eq!(tsv.get_image_num().unwrap(), 0);
+    drop(renderer);
+
+    for extension in extensions {
+        let path = format!("{}.{}", base, extension);
+        let output = std::fs::read_to_string(&path).expect("rendered file missing");
+        let _ = std::fs::remove_file(&path);
+        assert!(output.contains("sample"), "{}: {}", path, output);
+    }
+}
+
+#[test]
+fn test_process_pages_with_renderer_list() {
+    let sample = write_pgm("sample_text.png", "pages_sample");
+    let digits = write_pgm("digits.png", "pages_digits");
+    let list = write_list("pages_list", &[&sample, &digits]);
+    let base = std::env::temp_dir().join("tesseract_test_renderer_pages");
+    let renderer = TessResultRenderer::new_text_renderer(base.to_str().unwrap()).unwrap();
+
+    let api = create_initialized_api();
+    let results = api
+        .process_pages_with_renderer(list.to_str().unwrap(), &renderer, 10000)
+        .expect("process_pages_with_renderer failed");
+    assert_eq!(results.len(), 2);
+    for (index, (result, path)) in results.iter().zip([&sample, &digits]).enumerate() {
+        assert_eq!(result.page, index);
+        assert_eq!(result.source, path.display().to_string());
+        assert!(result.succeeded, "Page {} failed", index);
+        assert!(result.elapsed.as_nanos() > 0);
+    }
+    assert_eq!(renderer.get_image_num().unwrap(), 1);
+    drop(renderer);
+
+    let output_path = format!("{}.txt", base.display());
+    let output = std::fs::read_to_string(&output_path).expect("rendered file missing");
+    assert!(output.contains("sample text"), "{}", output);
+    for path in [sample, digits, list, output_path.into()] {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[test]
+fn test_process_pages_with_renderer_unreadable_page() {
+    let sample = write_pgm("sample_text.png", "unreadable_sample");
+    let missing = PathBuf::from("/nonexistent/page.pgm");
+    let list = write_list("unreadable_list", &[&sample, &missing]);
+    let base = std::env::temp_dir().join("tesseract_test_renderer_unreadable");
+    let renderer = TessResultRenderer::new_text_renderer(base.to_str().unwrap()).unwrap();
+
+    let api = create_initialized_api();
+    let results = api
+        .process_pages_with_renderer(list.to_str().unwrap(), &renderer, 0)
+        .expect("process_pages_with_renderer failed");
+    assert_eq!(results.len(), 2);
+    assert!(results[0].succeeded);
+    assert!(!results[1].succeeded);
+    assert_eq!(results[1].source, missing.display().to_string());
+    drop(renderer);
+
+    // The readable page is still rendered and the document ended.
+    let output_path = format!("{}.txt", base.display());
+    let output = std::fs::read_to_string(&output_path).expect("rendered file missing");
+    assert!(output.contains("sample text"), "{}", output);
+    for path in [sample, list, output_path.into()] {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[cfg(feature = "image-codecs")]
+#[test]
+fn test_process_pages_with_renderer_multipage_tiff() {
+    let tiff = write_tiff("multipage", &["sample_text.png", "sample_text.png"]);
+    let renderer = TessResultRenderer::text_to_vec().unwrap();
+    let api = create_initialized_api();
+    let results = api
+        .process_pages_with_renderer(tiff.to_str().unwrap(), &renderer, 10000)
+        .expect("process_pages_with_renderer failed");
+    assert_eq!(results.len(), 2);
+    for (index, result) in results.iter().enumerate() {
+        assert_eq!(result.page, index);
+        assert_eq!(result.source, tiff.display().to_string());
+        assert!(result.succeeded, "Page {} failed", index);
+    }
+    let text = String::from_utf8(renderer.into_bytes().unwrap()).unwrap();
+    assert_eq!(text.matches("sample text").count(), 2, "{}", text);
+    let _ = std::fs::remove_file(tiff);
+}
+
+#[test]
+fn test_process_pages_with_renderer_short_list() {
+    // Shorter than any image signature, so Leptonica cannot detect a format.
+    let list = std::env::temp_dir().join("tesseract_test_short_list.txt");
+    std::fs::write(&list, "x.png\n").unwrap();
+    let base = std::env::temp_dir().join("tesseract_test_renderer_short_list");
+    let renderer = TessResultRenderer::new_text_renderer(base.to_str().unwrap()).unwrap();
+
+    let api = create_initialized_api();
+    let results = api
+        .process_pages_with_renderer(list.to_str().unwrap(), &renderer, 0)
+        .expect("a short page list is still a list");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].source, "x.png");
+    assert!(!results[0].succeeded);
+    drop(renderer);
+    let _ = std::fs::remove_file(list);
+    let _ = std::fs::remove_file(format!("{}.txt", base.display()));
+}
+
+#[test]
+fn test_process_pages_with_renderer_missing_file() {
+    let base = std::env::temp_dir().join("tesseract_test_renderer_missing");
+    let renderer = TessResultRenderer::new_text_renderer(base.to_str().unwrap()).unwrap();
+    let api = create_initialized_api();
+    let result = api.process_pages_with_renderer("/nonexistent/input.tif", &renderer, 0);
+    assert!(matches!(
+        result,
+        Err(TesseractError::ProcessPagesError { page: None, .. })
+    ));
+}
+
+#[test]
+fn test_process_page() {
+    let sample = write_pgm("sample_text.png", "process_page");
+    let pix = Pix::read_from_file(&sample).expect("Failed to read PGM");
+    let api = create_initialized_api();
+    let result = api
+        .process_page(&pix, 0, sample.to_str().unwrap(), None, 10000, None)
+        .expect("process_page failed");
+    assert!(result.succeeded);
+    assert_eq!(result.page, 0);
+    let text = api.get_utf8_text().unwrap();
+    assert!(text.contains("sample text"), "{}", text);
+    let _ = std::fs::remove_file(sample);
+}
+
+#[test]
+fn test_text_to_vec() {
+    let renderer = TessResultRenderer::text_to_vec().unwrap();
+    assert_eq!(renderer.get_extension().unwrap(), "txt");
+    assert!(renderer.begin_document("Memory").unwrap());
+    let api = create_api_with_image();
+    assert!(renderer.add_image(&api).unwrap());
+    let text = String::from_utf8(renderer.end_document().unwrap()).unwrap();
+    assert!(
+        text.contains("This is a sample text for OCR testing."),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn test_hocr_to_vec_with_chain() {
+    let renderer = TessResultRenderer::hocr_to_vec().unwrap();
+    let base = std::env::temp_dir().join("tesseract_test_renderer_memory_chain");
+    renderer
+        .insert(TessResultRenderer::new_text_renderer(base.to_str().unwrap()).unwrap())
+        .unwrap();
+    renderer.begin_document("Memory").unwrap();
+    renderer.add_image(&create_api_with_image()).unwrap();
+    let hocr = String::from_utf8(renderer.end_document().unwrap()).unwrap();
+    assert!(hocr.contains("<title>Memory</title>"), "{}", hocr);
+    assert!(hocr.trim_end().ends_with("</html>"), "{}", hocr);
+
+    let text_path = format!("{}.txt", base.display());
+    let text = std::fs::read_to_string(&text_path).expect("chained file missing");
+    let _ = std::fs::remove_file(&text_path);
+    assert!(text.contains("sample"), "{}", text);
+}
+
+#[test]
+fn test_memory_renderer_to_writer() {
+    let renderer = MemoryRenderer::new(TessResultRenderer::new_tsv_renderer).unwrap();
+    renderer.begin_document("Memory").unwrap();
+    renderer.add_image(&create_api_with_image()).unwrap();
+    let mut sink = Vec::new();
+    renderer.end_document_to(&mut sink).unwrap();
+    let tsv = String::from_utf8(sink).unwrap();
+    assert!(
+        tsv.lines().any(|line| line.ends_with("\tsample")),
+        "{}",
+        tsv
+    );
+}
+
+#[test]
+fn test_memory_renderer_with_process_pages() {
+    let sample = write_pgm("sample_text.png", "memory_pages");
+    let renderer = TessResultRenderer::text_to_vec().unwrap();
+    let api = create_initialized_api();
+    let results = api
+        .process_pages_with_renderer(sample.to_str().unwrap(), &renderer, 0)
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    let text = String::from_utf8(renderer.into_bytes().unwrap()).unwrap();
+    assert!(text.contains("sample text"), "{}", text);
+    let _ = std::fs::remove_file(sample);
+}
+
+#[test]
+fn test_pdf_to_vec() {
+    let tessdata_dir = get_tessdata_dir();
+    let renderer = TessResultRenderer::pdf_to_vec(tessdata_dir.to_str().unwrap(), false).unwrap();
+    // The PDF renderer needs pdf.ttf in the data directory.
+    let began = renderer.begin_document("Memory").unwrap();
+    renderer.add_image(&create_api_with_image()).unwrap();
+    match renderer.end_document() {
+        Ok(pdf) => {
+            assert!(began);
+            assert!(pdf.starts_with(b"%PDF-"));
+            assert!(pdf.windows(5).any(|window| window == b"%%EOF"));
+        }
+        Err(error) => {
+            assert!(!began);
+            assert!(matches!(error, TesseractError::RenderError { .. }));
+        }
+    }
+}
+
+#[test]
+fn test_pdf_to_vec_datadir_with_nul() {
+    assert!(matches!(
+        TessResultRenderer::pdf_to_vec("tess\0data", false),
+        Err(TesseractError::InvalidParameterError)
+    ));
+}
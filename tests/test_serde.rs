@@ -0,0 +1,63 @@
+#[cfg(feature = "serde")]
+mod common;
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+    use super::common::*;
+    use tesseract_rs::{
+        Document, OcrEngineMode, TessPageSegMode, TessPolyBlockType, TesseractError,
+        JSON_SCHEMA_VERSION,
+    };
+
+    #[test]
+    fn test_document_json_round_trip() {
+        let api = create_api_with_image();
+        let document = api.recognize_document().expect("recognize_document failed");
+        let json = document.to_json().expect("to_json failed");
+        assert!(json.starts_with(&format!("{{\"schema_version\":{}", JSON_SCHEMA_VERSION)));
+        assert!(json.contains("\"block_type\":\"PT_FLOWING_TEXT\""));
+
+        let parsed = Document::from_json(&json).expect("from_json failed");
+        assert_eq!(parsed, document);
+        assert_eq!(
+            Document::from_json(&document.to_json_pretty().unwrap()).unwrap(),
+            document
+        );
+    }
+
+    #[test]
+    fn test_document_json_rejects_newer_schema() {
+        let json = format!(
+            "{{\"schema_version\":{},\"pages\":[]}}",
+            JSON_SCHEMA_VERSION + 1
+        );
+        assert!(matches!(
+            Document::from_json(&json),
+            Err(TesseractError::JsonError(_))
+        ));
+        let json = format!(
+            "{{\"schema_version\":{},\"pages\":[]}}",
+            JSON_SCHEMA_VERSION
+        );
+        assert_eq!(Document::from_json(&json).unwrap(), Document::default());
+    }
+
+    #[test]
+    fn test_enums_serialize_as_variant_names() {
+        assert_eq!(
+            serde_json::to_string(&TessPageSegMode::PSM_SINGLE_LINE).unwrap(),
+            "\"PSM_SINGLE_LINE\""
+        );
+        assert_eq!(
+            serde_json::from_str::<TessPolyBlockType>("\"PT_TABLE\"").unwrap(),
+            TessPolyBlockType::PT_TABLE
+        );
+        assert_eq!(
+            serde_json::from_str::<OcrEngineMode>(
+                &serde_json::to_string(&OcrEngineMode::LstmOnly).unwrap()
+            )
+            .unwrap(),
+            OcrEngineMode::LstmOnly
+        );
+    }
+}